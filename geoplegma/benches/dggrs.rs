@@ -1,3 +1,4 @@
+use api::geometry::Densification;
 use api::get;
 use api::models::common::{DggrsUid, RefinementLevel, RelativeDepth};
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
@@ -26,7 +27,7 @@ fn bench_dggrs(c: &mut Criterion) {
         children: false,
         center: false,
         neighbors: false,
-        densify: false,
+        densification: Densification::None,
         area_sqm: false,
         ..Default::default()
     };
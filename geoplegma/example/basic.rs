@@ -6,10 +6,11 @@
 // <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
 // discretion. This file may not be copied, modified, or distributed
 // except according to those terms.
+use geoplegma::api::{BoundingBox, Point};
 use geoplegma::error;
+use geoplegma::geometry::Densification;
 use geoplegma::types::{DggrsUid, RefinementLevel, RelativeDepth};
 use geoplegma::{get, registry};
-use geoplegma::api::{BoundingBox, Point};
 use std::time::Instant;
 
 /// This is just an example and basic testing function if there is output or not
@@ -43,7 +44,7 @@ pub fn main() -> Result<(), error::DggrsError> {
         children: false,
         center: false,
         neighbors: false,
-        densify: false,
+        densification: Densification::None,
         area_sqm: false,
         ..Default::default()
     };
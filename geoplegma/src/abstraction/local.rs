@@ -0,0 +1,225 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Stores on the local file system, one directory per data structure.
+//!
+//! The meta-data is written to `metadata.json`. Coverage blocks are written to `blocks/<block id>.jsonl`, the first line holds the block ZoneId and every following line a `[zone id, value]` pair.
+//! Vector segments are written to `segments/<index>.jsonl` with one feature per line. ZoneIds are serialised tagged by their format, see `ZoneId`.
+
+use crate::abstraction::{CoverageAbstraction, VectorAbstraction};
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::structures::coverage::CoverageMetadata;
+use crate::structures::vector::{DggsFeature, VectorMetadata};
+use crate::types::ZoneId;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+const METADATA_FILE: &str = "metadata.json";
+const BLOCKS_DIR: &str = "blocks";
+const SEGMENTS_DIR: &str = "segments";
+const SEGMENT_EXTENSION: &str = "jsonl";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BlockHeader {
+    block: ZoneId,
+}
+
+/// A coverage store in a local directory.
+pub struct LocalCoverageStore {
+    root: PathBuf,
+}
+
+impl LocalCoverageStore {
+    /// Create the directory of the store, blocks of a coverage written before are removed.
+    pub fn create(root: impl Into<PathBuf>) -> Result<Self, DggrsError> {
+        let root = root.into();
+        recreate_dir(&root.join(BLOCKS_DIR))?;
+        Ok(Self { root })
+    }
+
+    /// Open the directory of a coverage written before.
+    pub fn open(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn block_path(&self, block_id: &ZoneId) -> PathBuf {
+        self.root
+            .join(BLOCKS_DIR)
+            .join(format!("{block_id}.{SEGMENT_EXTENSION}"))
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> CoverageAbstraction<T> for LocalCoverageStore {
+    fn write_metadata(&mut self, metadata: &CoverageMetadata) -> Result<(), DggrsError> {
+        write_json(&self.root.join(METADATA_FILE), metadata)
+    }
+
+    fn read_metadata(&self) -> Result<CoverageMetadata, DggrsError> {
+        read_json(&self.root.join(METADATA_FILE))
+    }
+
+    fn write_block(
+        &mut self,
+        block_id: &ZoneId,
+        values: &mut dyn Iterator<Item = (&ZoneId, &T)>,
+    ) -> Result<(), DggrsError> {
+        let mut writer = BufWriter::new(create_file(&self.block_path(block_id))?);
+        write_line(
+            &mut writer,
+            &BlockHeader {
+                block: block_id.clone(),
+            },
+        )?;
+        for value in values {
+            write_line(&mut writer, &value)?;
+        }
+        writer.flush().map_err(EncodingError::from)?;
+        Ok(())
+    }
+
+    fn read_block(&self, block_id: &ZoneId) -> Result<Vec<(ZoneId, T)>, DggrsError> {
+        let mut lines = open_file(&self.block_path(block_id))?.lines();
+        lines.next().transpose().map_err(EncodingError::from)?;
+        lines
+            .map(|line| {
+                let line = line.map_err(EncodingError::from)?;
+                Ok(serde_json::from_str(&line).map_err(EncodingError::from)?)
+            })
+            .collect()
+    }
+
+    fn block_ids(&self) -> Result<Vec<ZoneId>, DggrsError> {
+        let mut block_ids = Vec::new();
+        for path in segment_paths(&self.root.join(BLOCKS_DIR))? {
+            let Some(line) = open_file(&path)?.lines().next() else {
+                continue;
+            };
+            let line = line.map_err(EncodingError::from)?;
+            let header: BlockHeader = serde_json::from_str(&line).map_err(EncodingError::from)?;
+            block_ids.push(header.block);
+        }
+        block_ids.sort();
+        Ok(block_ids)
+    }
+}
+
+/// A vector store in a local directory.
+pub struct LocalVectorStore {
+    root: PathBuf,
+}
+
+impl LocalVectorStore {
+    /// Create the directory of the store, segments of a vector written before are removed.
+    pub fn create(root: impl Into<PathBuf>) -> Result<Self, DggrsError> {
+        let root = root.into();
+        recreate_dir(&root.join(SEGMENTS_DIR))?;
+        Ok(Self { root })
+    }
+
+    /// Open the directory of a vector written before.
+    pub fn open(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn segment_path(&self, index: usize) -> PathBuf {
+        self.root
+            .join(SEGMENTS_DIR)
+            .join(format!("{index}.{SEGMENT_EXTENSION}"))
+    }
+}
+
+impl VectorAbstraction for LocalVectorStore {
+    fn write_metadata(&mut self, metadata: &VectorMetadata) -> Result<(), DggrsError> {
+        write_json(&self.root.join(METADATA_FILE), metadata)
+    }
+
+    fn read_metadata(&self) -> Result<VectorMetadata, DggrsError> {
+        read_json(&self.root.join(METADATA_FILE))
+    }
+
+    fn write_segment(&mut self, index: usize, features: &[DggsFeature]) -> Result<(), DggrsError> {
+        let mut writer = BufWriter::new(create_file(&self.segment_path(index))?);
+        for feature in features {
+            write_line(&mut writer, feature)?;
+        }
+        writer.flush().map_err(EncodingError::from)?;
+        Ok(())
+    }
+
+    fn read_segment(&self, index: usize) -> Result<Vec<DggsFeature>, DggrsError> {
+        open_file(&self.segment_path(index))?
+            .lines()
+            .map(|line| {
+                let line = line.map_err(EncodingError::from)?;
+                Ok(serde_json::from_str(&line).map_err(EncodingError::from)?)
+            })
+            .collect()
+    }
+
+    fn segment_count(&self) -> Result<usize, DggrsError> {
+        let mut count = 0;
+        while self.segment_path(count).is_file() {
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+fn recreate_dir(dir: &Path) -> Result<(), EncodingError> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::create_dir_all(dir)?;
+    Ok(())
+}
+
+fn create_file(path: &Path) -> Result<File, EncodingError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    Ok(File::create(path)?)
+}
+
+fn open_file(path: &Path) -> Result<BufReader<File>, EncodingError> {
+    Ok(BufReader::new(File::open(path)?))
+}
+
+fn write_json<S: Serialize>(path: &Path, value: &S) -> Result<(), DggrsError> {
+    let mut writer = BufWriter::new(create_file(path)?);
+    serde_json::to_writer_pretty(&mut writer, value).map_err(EncodingError::from)?;
+    writer.flush().map_err(EncodingError::from)?;
+    Ok(())
+}
+
+fn read_json<D: DeserializeOwned>(path: &Path) -> Result<D, DggrsError> {
+    Ok(serde_json::from_reader(open_file(path)?).map_err(EncodingError::from)?)
+}
+
+fn write_line<W: Write, S: Serialize>(writer: &mut W, value: &S) -> Result<(), EncodingError> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+/// The segment files in a directory, in file name order
+fn segment_paths(dir: &Path) -> Result<Vec<PathBuf>, EncodingError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == SEGMENT_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
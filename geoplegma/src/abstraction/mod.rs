@@ -0,0 +1,144 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Persistence of coverages and vectors in segments.
+//!
+//! A store holds the meta-data of one data structure and its data segments: the blocks of a coverage or fixed size runs of vector features.
+//! Segments are written and read one at a time, so that large data structures never have to be encoded at once.
+
+#[cfg(feature = "json")]
+pub mod local;
+#[cfg(feature = "json")]
+pub mod zarr;
+
+use crate::api::DggrsApi;
+use crate::error::DggrsError;
+use crate::error::structures::StructureError;
+use crate::structures::coverage::{Coverage, CoverageMetadata, CoverageStorage, CoverageValue};
+use crate::structures::vector::{DggsFeature, DggsVector, VectorMetadata};
+use crate::types::{DggrsUid, ZoneId};
+use std::sync::Arc;
+
+/// Stores the meta-data and the blocks of a coverage with values of type `T`.
+pub trait CoverageAbstraction<T> {
+    fn write_metadata(&mut self, metadata: &CoverageMetadata) -> Result<(), DggrsError>;
+
+    fn read_metadata(&self) -> Result<CoverageMetadata, DggrsError>;
+
+    /// Write the zones and values of a block, replacing a block written before.
+    fn write_block(
+        &mut self,
+        block_id: &ZoneId,
+        values: &mut dyn Iterator<Item = (&ZoneId, &T)>,
+    ) -> Result<(), DggrsError>;
+
+    fn read_block(&self, block_id: &ZoneId) -> Result<Vec<(ZoneId, T)>, DggrsError>;
+
+    /// The ZoneIds of the blocks in the store.
+    fn block_ids(&self) -> Result<Vec<ZoneId>, DggrsError>;
+}
+
+/// Stores the meta-data and the features of a vector in numbered segments.
+pub trait VectorAbstraction {
+    fn write_metadata(&mut self, metadata: &VectorMetadata) -> Result<(), DggrsError>;
+
+    fn read_metadata(&self) -> Result<VectorMetadata, DggrsError>;
+
+    /// Write the features of a segment, replacing a segment written before.
+    fn write_segment(&mut self, index: usize, features: &[DggsFeature]) -> Result<(), DggrsError>;
+
+    fn read_segment(&self, index: usize) -> Result<Vec<DggsFeature>, DggrsError>;
+
+    /// The number of segments in the store, numbered from 0.
+    fn segment_count(&self) -> Result<usize, DggrsError>;
+}
+
+/// Write the meta-data and all blocks of a coverage, which has to be partitioned with `Coverage::with_blocks`.
+pub fn write_coverage<T: CoverageValue>(
+    coverage: &Coverage<T>,
+    store: &mut dyn CoverageAbstraction<T>,
+) -> Result<(), DggrsError> {
+    if coverage.block_level().is_none() {
+        return Err(StructureError::NotPartitioned.into());
+    }
+    store.write_metadata(&coverage.metadata())?;
+    for block_id in coverage.blocks() {
+        store.write_block(block_id, &mut coverage.block(block_id))?;
+    }
+    Ok(())
+}
+
+/// Read a coverage block by block, it has to be stored for the DGGRS and with values of type `T`.
+pub fn read_coverage<T: CoverageValue>(
+    dggrs: Arc<dyn DggrsApi>,
+    store: &dyn CoverageAbstraction<T>,
+) -> Result<Coverage<T>, DggrsError> {
+    let metadata = store.read_metadata()?;
+    check_dggrs(dggrs.uid(), metadata.dggrs)?;
+    if metadata.data_type != T::DATA_TYPE {
+        return Err(StructureError::DataTypeMismatch {
+            expected: T::DATA_TYPE.to_string(),
+            found: metadata.data_type,
+        }
+        .into());
+    }
+
+    let mut coverage = match metadata.storage {
+        CoverageStorage::Dense => {
+            Coverage::dense(dggrs, metadata.refinement_level, metadata.extent)?
+        }
+        CoverageStorage::Sparse => Coverage::sparse(dggrs, metadata.refinement_level)?,
+    };
+    if let Some(block_level) = metadata.block_level {
+        coverage = coverage.with_blocks(block_level)?;
+    }
+    for block_id in store.block_ids()? {
+        for (zone_id, value) in store.read_block(&block_id)? {
+            coverage.set(zone_id, value)?;
+        }
+    }
+    Ok(coverage)
+}
+
+/// Write the meta-data and the features of a vector in segments of `segment_size` features.
+pub fn write_vector(
+    vector: &DggsVector,
+    store: &mut dyn VectorAbstraction,
+    segment_size: usize,
+) -> Result<(), DggrsError> {
+    store.write_metadata(&vector.metadata())?;
+    for (index, features) in vector.features().chunks(segment_size.max(1)).enumerate() {
+        store.write_segment(index, features)?;
+    }
+    Ok(())
+}
+
+/// Read a vector segment by segment, it has to be stored for the DGGRS.
+pub fn read_vector(
+    dggrs: Arc<dyn DggrsApi>,
+    store: &dyn VectorAbstraction,
+) -> Result<DggsVector, DggrsError> {
+    let metadata = store.read_metadata()?;
+    check_dggrs(dggrs.uid(), metadata.dggrs)?;
+
+    let mut vector = DggsVector::new(dggrs, metadata.refinement_level)?;
+    for index in 0..store.segment_count()? {
+        for feature in store.read_segment(index)? {
+            vector.push(feature)?;
+        }
+    }
+    Ok(vector)
+}
+
+fn check_dggrs(expected: DggrsUid, found: DggrsUid) -> Result<(), DggrsError> {
+    if expected != found {
+        return Err(StructureError::DggrsMismatch { expected, found }.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,415 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Zarr v3 stores of coverages on the local file system.
+//!
+//! A coverage partitioned into blocks is written as a Zarr group with two arrays:
+//! - `parents`, the integer ZoneIds of the blocks, the parent zones, as `uint64`.
+//! - `values`, a two dimensional array with one row per parent zone and one column per sub-zone index, chunked by parent zone.
+//!
+//! The sub-zones of a parent are ordered as by `DggrsApi::sub_zone_ids`, so only DGGRS adapters supporting it can be written.
+//! Sub-zones without a value hold the fill value of the array. The DGGRS, the refinement levels and the zone ordering are stored in the attributes of the `values` array, see `ZarrAttributes`.
+//! Chunks are stored uncompressed in little-endian byte order with the default chunk key encoding, e.g. `values/c/<row>/0`.
+
+use crate::api::DggrsApi;
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::error::structures::StructureError;
+use crate::structures::coverage::{Coverage, CoverageStorage};
+use crate::types::{BoundingBox, DggrsUid, RefinementLevel, RelativeDepth, ZoneId, ZoneIdFormat};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FORMAT: &str = "Zarr";
+
+const METADATA_FILE: &str = "zarr.json";
+const PARENTS_ARRAY: &str = "parents";
+const VALUES_ARRAY: &str = "values";
+
+/// The order of the sub-zone indices along the second dimension of the `values` array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ZoneOrdering {
+    /// The order of `DggrsApi::sub_zone_ids` for the relative depth between the parent and the refinement level.
+    SubZoneIds,
+}
+
+/// The attributes of the `values` array.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ZarrAttributes {
+    pub dggrs: DggrsUid,
+    pub refinement_level: RefinementLevel,
+    /// The refinement level of the parent zones, the block level of the coverage.
+    pub parent_level: RefinementLevel,
+    pub zone_ordering: ZoneOrdering,
+    /// The format of the ZoneIds of the coverage, the parent zones are always stored as integers.
+    pub zone_id_format: ZoneIdFormat,
+    pub storage: CoverageStorage,
+    pub extent: Option<BoundingBox>,
+}
+
+/// A numeric type that can be stored in a Zarr array.
+pub trait ZarrDataType: Copy {
+    /// The name of the Zarr v3 data type, e.g. `float64`.
+    const DATA_TYPE: &'static str;
+    /// The size of a value in bytes.
+    const SIZE: usize;
+
+    fn write_le(self, bytes: &mut [u8]);
+
+    fn read_le(bytes: &[u8]) -> Self;
+
+    /// The value as the `fill_value` of the array meta-data.
+    fn to_fill_value(self) -> Value;
+
+    fn from_fill_value(value: &Value) -> Option<Self>;
+}
+
+macro_rules! zarr_int {
+    ($($t:ty => $name:literal),*) => {$(
+        impl ZarrDataType for $t {
+            const DATA_TYPE: &'static str = $name;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn write_le(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                Self::from_le_bytes(bytes.try_into().expect("slice of the size of the data type"))
+            }
+
+            fn to_fill_value(self) -> Value {
+                json!(self)
+            }
+
+            fn from_fill_value(value: &Value) -> Option<Self> {
+                serde_json::from_value(value.clone()).ok()
+            }
+        }
+    )*};
+}
+
+macro_rules! zarr_float {
+    ($($t:ty => $name:literal),*) => {$(
+        impl ZarrDataType for $t {
+            const DATA_TYPE: &'static str = $name;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn write_le(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                Self::from_le_bytes(bytes.try_into().expect("slice of the size of the data type"))
+            }
+
+            fn to_fill_value(self) -> Value {
+                if self.is_nan() {
+                    json!("NaN")
+                } else if self.is_infinite() {
+                    json!(if self > 0.0 { "Infinity" } else { "-Infinity" })
+                } else {
+                    json!(self)
+                }
+            }
+
+            fn from_fill_value(value: &Value) -> Option<Self> {
+                match value {
+                    Value::String(s) => match s.as_str() {
+                        "NaN" => Some(<$t>::NAN),
+                        "Infinity" => Some(<$t>::INFINITY),
+                        "-Infinity" => Some(<$t>::NEG_INFINITY),
+                        _ => None,
+                    },
+                    _ => value.as_f64().map(|v| v as $t),
+                }
+            }
+        }
+    )*};
+}
+
+zarr_int!(
+    i8 => "int8", i16 => "int16", i32 => "int32", i64 => "int64",
+    u8 => "uint8", u16 => "uint16", u32 => "uint32", u64 => "uint64"
+);
+zarr_float!(f32 => "float32", f64 => "float64");
+
+/// A Zarr v3 coverage store in a local directory.
+pub struct ZarrCoverageStore {
+    root: PathBuf,
+}
+
+impl ZarrCoverageStore {
+    /// Create the directory of the store, arrays written before are removed.
+    pub fn create(root: impl Into<PathBuf>) -> Result<Self, DggrsError> {
+        let root = root.into();
+        for array in [PARENTS_ARRAY, VALUES_ARRAY] {
+            let dir = root.join(array);
+            if dir.exists() {
+                fs::remove_dir_all(&dir).map_err(EncodingError::from)?;
+            }
+        }
+        fs::create_dir_all(&root).map_err(EncodingError::from)?;
+        Ok(Self { root })
+    }
+
+    /// Open the directory of a coverage written before.
+    pub fn open(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Write a coverage, which has to be partitioned with `Coverage::with_blocks`. Sub-zones without a value are set to `fill_value`.
+    pub fn write<T: ZarrDataType>(
+        &mut self,
+        coverage: &Coverage<T>,
+        fill_value: T,
+    ) -> Result<(), DggrsError> {
+        let Some(parent_level) = coverage.block_level() else {
+            return Err(StructureError::NotPartitioned.into());
+        };
+        let dggrs = coverage.dggrs();
+        let depth = relative_depth(coverage.refinement_level(), parent_level)?;
+        // NOTE: rows without values are not stored, so the chunks of a coverage written before are removed
+        let chunks = self.root.join(VALUES_ARRAY).join("c");
+        if chunks.exists() {
+            fs::remove_dir_all(&chunks).map_err(EncodingError::from)?;
+        }
+        let zone_id_format = coverage.zone_id_format();
+
+        let mut parents = Vec::new();
+        let mut sub_zones = Vec::new();
+        for block_id in coverage.blocks() {
+            let parent = dggrs.format_zone_id(block_id, ZoneIdFormat::Int)?;
+            parents.push(parent.as_u64().ok_or_else(|| {
+                EncodingError::invalid(FORMAT, format!("integer ZoneId of '{block_id}'"))
+            })?);
+            sub_zones.push(sub_zone_ids(dggrs, depth, block_id, zone_id_format)?);
+        }
+        let columns = sub_zones.iter().map(Vec::len).max().unwrap_or(0).max(1);
+
+        let attributes = ZarrAttributes {
+            dggrs: dggrs.uid(),
+            refinement_level: coverage.refinement_level(),
+            parent_level,
+            zone_ordering: ZoneOrdering::SubZoneIds,
+            zone_id_format,
+            storage: coverage.storage(),
+            extent: coverage.extent(),
+        };
+        write_json(
+            &self.root.join(METADATA_FILE),
+            &json!({ "zarr_format": 3, "node_type": "group", "attributes": {} }),
+        )?;
+
+        write_json(
+            &self.root.join(PARENTS_ARRAY).join(METADATA_FILE),
+            &array_metadata::<u64>(
+                &[parents.len()],
+                &[parents.len().max(1)],
+                0,
+                json!({}),
+                &["parent_zone"],
+            ),
+        )?;
+        let mut bytes = vec![0; parents.len() * u64::SIZE];
+        for (value, chunk) in parents.iter().zip(bytes.chunks_exact_mut(u64::SIZE)) {
+            value.write_le(chunk);
+        }
+        write_bytes(&self.root.join(PARENTS_ARRAY).join("c").join("0"), &bytes)?;
+
+        write_json(
+            &self.root.join(VALUES_ARRAY).join(METADATA_FILE),
+            &array_metadata::<T>(
+                &[parents.len(), columns],
+                &[1, columns],
+                fill_value,
+                serde_json::to_value(&attributes).map_err(EncodingError::from)?,
+                &["parent_zone", "sub_zone"],
+            ),
+        )?;
+        for (row, (block_id, sub_zone_ids)) in coverage.blocks().zip(&sub_zones).enumerate() {
+            let positions: HashMap<&ZoneId, usize> = sub_zone_ids
+                .iter()
+                .enumerate()
+                .map(|(i, id)| (id, i))
+                .collect();
+            let mut bytes = vec![0; columns * T::SIZE];
+            for chunk in bytes.chunks_exact_mut(T::SIZE) {
+                fill_value.write_le(chunk);
+            }
+            let mut empty = true;
+            for (zone_id, value) in coverage.block(block_id) {
+                let position =
+                    *positions
+                        .get(zone_id)
+                        .ok_or_else(|| StructureError::OutsideBlock {
+                            zone_id: zone_id.to_string(),
+                            block_id: block_id.to_string(),
+                        })?;
+                value.write_le(&mut bytes[position * T::SIZE..(position + 1) * T::SIZE]);
+                empty = false;
+            }
+            // NOTE: Zarr readers take the fill value for chunks that are not stored
+            if !empty {
+                write_bytes(&self.chunk_path(row), &bytes)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The attributes of the `values` array.
+    pub fn attributes(&self) -> Result<ZarrAttributes, DggrsError> {
+        let metadata = read_json(&self.root.join(VALUES_ARRAY).join(METADATA_FILE))?;
+        Ok(serde_json::from_value(metadata["attributes"].clone()).map_err(EncodingError::from)?)
+    }
+
+    /// Read a coverage with values of type `T` for the DGGRS from `factory::get` named in the attributes. Sub-zones with the fill value are left unset.
+    pub fn read<T: ZarrDataType>(&self) -> Result<Coverage<T>, DggrsError> {
+        let metadata = read_json(&self.root.join(VALUES_ARRAY).join(METADATA_FILE))?;
+        let data_type = metadata["data_type"].as_str().unwrap_or_default();
+        if data_type != T::DATA_TYPE {
+            return Err(StructureError::DataTypeMismatch {
+                expected: T::DATA_TYPE.to_string(),
+                found: data_type.to_string(),
+            }
+            .into());
+        }
+        let attributes: ZarrAttributes =
+            serde_json::from_value(metadata["attributes"].clone()).map_err(EncodingError::from)?;
+        let fill_value = T::from_fill_value(&metadata["fill_value"]).ok_or_else(|| {
+            EncodingError::invalid(FORMAT, format!("fill value {}", metadata["fill_value"]))
+        })?;
+        let columns = metadata["shape"][1]
+            .as_u64()
+            .ok_or_else(|| EncodingError::invalid(FORMAT, "missing shape"))?
+            as usize;
+
+        let dggrs = crate::get(attributes.dggrs)?;
+        let depth = relative_depth(attributes.refinement_level, attributes.parent_level)?;
+        let mut coverage = match attributes.storage {
+            CoverageStorage::Dense => Coverage::dense(
+                dggrs.clone(),
+                attributes.refinement_level,
+                attributes.extent,
+            )?,
+            CoverageStorage::Sparse => {
+                Coverage::sparse(dggrs.clone(), attributes.refinement_level)?
+            }
+        }
+        .with_blocks(attributes.parent_level)?;
+
+        let mut fill_bytes = vec![0; T::SIZE];
+        fill_value.write_le(&mut fill_bytes);
+        let parents = fs::read(self.root.join(PARENTS_ARRAY).join("c").join("0"))
+            .map_err(EncodingError::from)?;
+        for (row, parent) in parents.chunks_exact(u64::SIZE).enumerate() {
+            let path = self.chunk_path(row);
+            if !path.is_file() {
+                continue;
+            }
+            let bytes = fs::read(path).map_err(EncodingError::from)?;
+            if bytes.len() != columns * T::SIZE {
+                return Err(EncodingError::invalid(
+                    FORMAT,
+                    format!("chunk {row} has {} bytes", bytes.len()),
+                )
+                .into());
+            }
+            let parent = dggrs.format_zone_id(
+                &ZoneId::new_int(u64::read_le(parent)),
+                attributes.zone_id_format,
+            )?;
+            let sub_zone_ids =
+                sub_zone_ids(dggrs.as_ref(), depth, &parent, attributes.zone_id_format)?;
+            for (zone_id, value) in sub_zone_ids.into_iter().zip(bytes.chunks_exact(T::SIZE)) {
+                if value != fill_bytes {
+                    coverage.set(zone_id, T::read_le(value))?;
+                }
+            }
+        }
+        Ok(coverage)
+    }
+
+    fn chunk_path(&self, row: usize) -> PathBuf {
+        self.root
+            .join(VALUES_ARRAY)
+            .join("c")
+            .join(row.to_string())
+            .join("0")
+    }
+}
+
+fn relative_depth(
+    refinement_level: RefinementLevel,
+    parent_level: RefinementLevel,
+) -> Result<RelativeDepth, DggrsError> {
+    RelativeDepth::new(i32::from(refinement_level) - i32::from(parent_level))
+}
+
+/// The sub-zones of a parent in the format of the coverage
+fn sub_zone_ids(
+    dggrs: &dyn DggrsApi,
+    depth: RelativeDepth,
+    parent: &ZoneId,
+    format: ZoneIdFormat,
+) -> Result<Vec<ZoneId>, DggrsError> {
+    dggrs
+        .sub_zone_ids(depth, parent.clone())?
+        .into_iter()
+        .map(|zone_id| {
+            if zone_id.format() == format {
+                Ok(zone_id)
+            } else {
+                dggrs.format_zone_id(&zone_id, format)
+            }
+        })
+        .collect()
+}
+
+fn array_metadata<T: ZarrDataType>(
+    shape: &[usize],
+    chunk_shape: &[usize],
+    fill_value: T,
+    attributes: Value,
+    dimension_names: &[&str],
+) -> Value {
+    json!({
+        "zarr_format": 3,
+        "node_type": "array",
+        "shape": shape,
+        "data_type": T::DATA_TYPE,
+        "chunk_grid": { "name": "regular", "configuration": { "chunk_shape": chunk_shape } },
+        "chunk_key_encoding": { "name": "default", "configuration": { "separator": "/" } },
+        "fill_value": fill_value.to_fill_value(),
+        "codecs": [{ "name": "bytes", "configuration": { "endian": "little" } }],
+        "attributes": attributes,
+        "dimension_names": dimension_names,
+    })
+}
+
+fn write_json(path: &Path, value: &Value) -> Result<(), DggrsError> {
+    let json = serde_json::to_vec_pretty(value).map_err(EncodingError::from)?;
+    write_bytes(path, &json)
+}
+
+fn read_json(path: &Path) -> Result<Value, DggrsError> {
+    let bytes = fs::read(path).map_err(EncodingError::from)?;
+    Ok(serde_json::from_slice(&bytes).map_err(EncodingError::from)?)
+}
+
+fn write_bytes(path: &Path, bytes: &[u8]) -> Result<(), DggrsError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(EncodingError::from)?;
+    }
+    fs::write(path, bytes).map_err(EncodingError::from)?;
+    Ok(())
+}
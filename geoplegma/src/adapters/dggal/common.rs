@@ -7,132 +7,240 @@
 // discretion. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::api::DggrsApiConfig;
+use crate::api::{DggrsApiConfig, ZoneParts, ZoneSink};
 use crate::error::DggrsError;
 use crate::error::dggal::DggalError;
-use crate::types::{BoundingBox, Point, Region, Zone, ZoneId, Zones};
+use crate::geometry::{Densification, edge_length, normalize};
+use crate::types::{
+    BoundingBox, Point, RefinementLevel, Region, Zone, ZoneId, ZoneIdFormat, Zones,
+};
 use dggal_rust::dggal::{DGGRS, DGGRSZone, GeoExtent, GeoPoint};
-use geo::GeodesicArea;
 
 pub fn to_zones(
     dggrs: DGGRS,
     dggal_zones: Vec<DGGRSZone>,
     conf: DggrsApiConfig,
-) -> Result<Zones, DggalError> {
+) -> Result<Zones, DggrsError> {
+    conf.validate()?;
     let zones: Vec<Zone> = dggal_zones
         .into_iter()
-        .map(|dggal_zone| {
-            let txt = dggrs.getZoneTextID(dggal_zone);
-
-            let id_string = ZoneId::new_str(&txt)
-                .map_err(|e: DggrsError| DggalError::InvalidZoneIdFormat(format!("{txt} ({e})")))?;
-
-            let center = if conf.center {
-                let center_point = dggrs.getZoneWGS84Centroid(dggal_zone);
-                Some(to_point(&center_point))
-            } else {
-                None
-            };
-
-            let region = if conf.region || conf.area_sqm {
-                let dggal_geo_points = if conf.densify {
-                    dggrs.getZoneRefinedWGS84Vertices(dggal_zone, 0)
-                } else {
-                    dggrs.getZoneWGS84Vertices(dggal_zone)
-                };
-                Some(to_polygon(&dggal_geo_points))
-            } else {
-                None
-            };
-
-            let area_sqm = if conf.area_sqm {
-                region.as_ref().map(|r| r.to_geo_polygon().geodesic_area_unsigned())
-            } else {
-                None
-            };
-
-            let vertex_count = if conf.vertex_count {
-                let vc = dggrs.countZoneEdges(dggal_zone).try_into().map_err(|e| {
-                    DggalError::EdgeCountConversion {
-                        zone_id: id_string.to_string(),
-                        source: e,
-                    }
-                })?;
-                Some(vc)
-            } else {
-                None
-            };
-
-            let children = if conf.children {
-                Some(
-                    dggrs
-                        .getZoneChildren(dggal_zone)
-                        .into_iter()
-                        .map(|z| to_str_zone_id(&dggrs, z))
-                        .collect::<Result<Vec<_>, DggalError>>()?,
-                )
-            } else {
-                None
-            };
-
-            let neighbors = if conf.neighbors {
-                let mut nb_types: [i32; 6] = [0; 6]; // WARN: don't replace this
-                Some(
-                    dggrs
-                        .getZoneNeighbors(dggal_zone, &mut nb_types)
-                        .into_iter()
-                        .map(|n| to_str_zone_id(&dggrs, n))
-                        .collect::<Result<Vec<_>, DggalError>>()?,
-                )
-            } else {
-                None
-            };
-
-            Ok(Zone {
-                id: id_string,
-                region,
-                center,
-                vertex_count,
-                children,
-                neighbors,
-                area_sqm,
-            })
-        })
+        .map(|dggal_zone| to_zone(&dggrs, dggal_zone, conf))
         .collect::<Result<Vec<Zone>, DggalError>>()?;
 
     Ok(Zones { zones })
 }
 
-fn to_point(pt: &GeoPoint) -> Point {
-    Point::new(pt.lat.to_degrees(), pt.lon.to_degrees())
+/// Generates a single zone with the outputs requested in the config.
+pub fn to_zone(
+    dggrs: &DGGRS,
+    dggal_zone: DGGRSZone,
+    conf: DggrsApiConfig,
+) -> Result<Zone, DggalError> {
+    let id = to_zone_id(dggrs, dggal_zone, conf.preferred_id_format)?;
+    let (region, area_sqm) = zone_region(dggrs, dggal_zone, conf);
+    let vertex_count = zone_vertex_count(dggrs, dggal_zone, &id, conf)?;
+    Ok(Zone {
+        id,
+        region,
+        center: zone_center(dggrs, dggal_zone, conf),
+        vertex_count,
+        children: zone_children(dggrs, dggal_zone, conf)?,
+        neighbors: zone_neighbors(dggrs, dggal_zone, conf)?,
+        area_sqm,
+    })
 }
 
-fn to_polygon(points: &[GeoPoint]) -> Region {
-    let mut pts: Vec<_> = points
-        .iter()
-        .map(|pt| Point::new(pt.lat.to_degrees(), pt.lon.to_degrees()))
-        .collect();
+/// Pushes the outputs requested in the config into a sink, without assembling a `Zone`.
+pub fn push_zone(
+    sink: &mut dyn ZoneSink,
+    refinement_level: RefinementLevel,
+    dggrs: &DGGRS,
+    dggal_zone: DGGRSZone,
+    conf: DggrsApiConfig,
+) -> Result<(), DggrsError> {
+    conf.validate()?;
+    let id = to_zone_id(dggrs, dggal_zone, conf.preferred_id_format)?;
+    let (region, area_sqm) = zone_region(dggrs, dggal_zone, conf);
+    let children = zone_children(dggrs, dggal_zone, conf)?;
+    let neighbors = zone_neighbors(dggrs, dggal_zone, conf)?;
+    sink.push_zone(
+        refinement_level,
+        ZoneParts {
+            id: &id,
+            region: region.as_ref(),
+            center: zone_center(dggrs, dggal_zone, conf),
+            vertex_count: zone_vertex_count(dggrs, dggal_zone, &id, conf)?,
+            area_sqm,
+            neighbors: neighbors.as_deref(),
+            children: children.as_deref(),
+        },
+    )
+}
 
-    if let Some(first) = pts.first().copied() {
-        if pts.last().copied() != Some(first) {
-            pts.push(first);
+fn zone_center(dggrs: &DGGRS, dggal_zone: DGGRSZone, conf: DggrsApiConfig) -> Option<Point> {
+    conf.center
+        .then(|| to_point(&dggrs.getZoneWGS84Centroid(dggal_zone)))
+}
+
+/// The region with its area, the area is taken before the region is normalised.
+fn zone_region(
+    dggrs: &DGGRS,
+    dggal_zone: DGGRSZone,
+    conf: DggrsApiConfig,
+) -> (Option<Region>, Option<f64>) {
+    let region = if conf.region || conf.area_sqm {
+        let corners = dggrs.getZoneWGS84Vertices(dggal_zone);
+        // NOTE: the area is taken from the corners when only the area is requested
+        let refinement = if conf.region {
+            edge_refinement(&corners, &conf.densification)
+        } else {
+            0
+        };
+        // NOTE: DGGAL takes 0 as its own default refinement, the corners are used instead
+        let dggal_geo_points = if refinement > 0 {
+            dggrs.getZoneRefinedWGS84Vertices(dggal_zone, refinement as i32)
+        } else {
+            corners
+        };
+        Some(to_polygon(&dggal_geo_points))
+    } else {
+        None
+    };
+
+    let area_sqm = if conf.area_sqm {
+        region.as_ref().map(|r| r.geodesic_area())
+    } else {
+        None
+    };
+    let region = region
+        .filter(|_| conf.region)
+        .map(|r| normalize(r, conf.geometry));
+    (region, area_sqm)
+}
+
+fn zone_vertex_count(
+    dggrs: &DGGRS,
+    dggal_zone: DGGRSZone,
+    id: &ZoneId,
+    conf: DggrsApiConfig,
+) -> Result<Option<u32>, DggalError> {
+    if !conf.vertex_count {
+        return Ok(None);
+    }
+    let vc = dggrs.countZoneEdges(dggal_zone).try_into().map_err(|e| {
+        DggalError::EdgeCountConversion {
+            zone_id: id.to_string(),
+            source: e,
         }
+    })?;
+    Ok(Some(vc))
+}
+
+fn zone_children(
+    dggrs: &DGGRS,
+    dggal_zone: DGGRSZone,
+    conf: DggrsApiConfig,
+) -> Result<Option<Vec<ZoneId>>, DggalError> {
+    if !conf.children {
+        return Ok(None);
+    }
+    Ok(Some(
+        dggrs
+            .getZoneChildren(dggal_zone)
+            .into_iter()
+            .map(|z| to_zone_id(dggrs, z, conf.preferred_id_format))
+            .collect::<Result<Vec<_>, DggalError>>()?,
+    ))
+}
+
+fn zone_neighbors(
+    dggrs: &DGGRS,
+    dggal_zone: DGGRSZone,
+    conf: DggrsApiConfig,
+) -> Result<Option<Vec<ZoneId>>, DggalError> {
+    if !conf.neighbors {
+        return Ok(None);
     }
+    let mut nb_types: [i32; 6] = [0; 6]; // WARN: don't replace this
+    Ok(Some(
+        dggrs
+            .getZoneNeighbors(dggal_zone, &mut nb_types)
+            .into_iter()
+            .map(|n| to_zone_id(dggrs, n, conf.preferred_id_format))
+            .collect::<Result<Vec<_>, DggalError>>()?,
+    ))
+}
+
+pub fn to_point(pt: &GeoPoint) -> Point {
+    Point::new(pt.lat.to_degrees(), pt.lon.to_degrees())
+}
 
-    Region::new(pts)
+/// A polygon region from the vertices of a zone, `Region::new` closes the ring
+fn to_polygon(points: &[GeoPoint]) -> Region {
+    Region::new(points.iter().map(to_point).collect())
+}
+
+/// The number of vertices DGGAL inserts into every edge, taken for the longest edge of the zone
+fn edge_refinement(corners: &[GeoPoint], densification: &Densification) -> u32 {
+    let points: Vec<Point> = corners.iter().map(to_point).collect();
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| {
+            densification.vertices_per_edge(edge_length(*a, *b), a.lat.abs().max(b.lat.abs()))
+        })
+        .max()
+        .unwrap_or(0)
 }
 
 fn to_u64_zone_id(id: DGGRSZone) -> ZoneId {
-    // NOTE: Expand this to do the conversion automatically
     ZoneId::IntId(id)
 }
 
-fn to_str_zone_id(dggrs: &DGGRS, zone: DGGRSZone) -> Result<ZoneId, DggalError> {
+/// Converts a DGGAL zone into a ZoneId of the requested format.
+pub fn to_zone_id(
+    dggrs: &DGGRS,
+    zone: DGGRSZone,
+    format: ZoneIdFormat,
+) -> Result<ZoneId, DggalError> {
+    match format {
+        ZoneIdFormat::Text => to_str_zone_id(dggrs, zone),
+        ZoneIdFormat::Int => Ok(to_u64_zone_id(zone)),
+    }
+}
+
+pub fn to_str_zone_id(dggrs: &DGGRS, zone: DGGRSZone) -> Result<ZoneId, DggalError> {
     let txt = dggrs.getZoneTextID(zone);
     ZoneId::new_str(&txt)
         .map_err(|e: DggrsError| DggalError::InvalidZoneIdFormat(format!("{txt} ({e})")))
 }
 
+/// DGGAL returns this value for zones that do not exist, e.g. an unknown textual ZoneID.
+pub const NULL_ZONE: DGGRSZone = u64::MAX;
+
+/// Resolve any ZoneId to the 64-bit DGGAL zone, textual IDs are looked up by DGGAL.
+pub fn to_dggal_zone(dggrs: &DGGRS, zone_id: &ZoneId) -> DGGRSZone {
+    match zone_id {
+        ZoneId::IntId(id) => *id,
+        ZoneId::StrId(s) => dggrs.getZoneFromTextID(s),
+        ZoneId::HexId(h) => dggrs.getZoneFromTextID(h.as_str()),
+    }
+}
+
+/// Same as `to_dggal_zone`, but rejects zones that DGGAL does not know.
+///
+/// A zone is valid if it has a refinement level and its textual ID resolves back to the same zone, no geometry is computed.
+pub fn to_valid_dggal_zone(dggrs: &DGGRS, zone_id: &ZoneId) -> Result<DGGRSZone, DggalError> {
+    let zone = to_dggal_zone(dggrs, zone_id);
+    if zone == NULL_ZONE
+        || dggrs.getZoneLevel(zone) < 0
+        || dggrs.getZoneFromTextID(&dggrs.getZoneTextID(zone)) != zone
+    {
+        return Err(DggalError::InvalidDggalZoneId);
+    }
+    Ok(zone)
+}
+
 pub fn to_geo_point(pt: Point) -> GeoPoint {
     GeoPoint {
         lat: pt.lat.to_radians(),
@@ -7,14 +7,21 @@
 // discretion. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::adapters::dggal::common::{bbox_to_geoextent, to_geo_point, to_zones};
+use crate::adapters::dggal::common::{
+    NULL_ZONE, bbox_to_geoextent, push_zone, to_geo_point, to_point, to_str_zone_id,
+    to_valid_dggal_zone, to_zone_id, to_zones,
+};
 use crate::adapters::dggal::context::GLOBAL_DGGAL;
-use crate::api::{DggrsApiConfig, DggrsApi};
+use crate::api::{DggrsApi, DggrsApiConfig, ZoneSink};
 use crate::error::DggrsError;
 use crate::error::dggal::DggalError;
-use crate::types::{DggrsName, DggrsUid, RefinementLevel, RelativeDepth, ZoneId, Zones, BoundingBox, Point};
-use dggal::DGGRS;
+use crate::types::{
+    BoundingBox, DggrsName, DggrsUid, Point, RefinementLevel, RelativeDepth, ZoneId, ZoneIdFormat,
+    ZoneInfo, Zones,
+};
+use dggal::{DGGRS, DGGRSZone};
 use dggal_rust::dggal;
+use std::collections::HashSet;
 
 pub struct DggalImpl {
     pub id: DggrsUid,
@@ -36,6 +43,130 @@ impl DggalImpl {
             grid_name: self.grid_name().to_string(),
         })
     }
+
+    /// Checks that the relative depth and the resulting refinement level below a parent zone are within the limits of the grid.
+    fn check_relative_depth(
+        &self,
+        dggrs: &DGGRS,
+        parent_zone: DGGRSZone,
+        relative_depth: RelativeDepth,
+    ) -> Result<(), DggrsError> {
+        if relative_depth > self.max_relative_depth()? {
+            return Err(DggrsError::RelativeDepthLimitReached {
+                grid_name: self.grid_name().to_string(),
+                requested: relative_depth,
+                maximum: self.max_relative_depth()?,
+            });
+        };
+
+        let target_level =
+            RefinementLevel::new(dggrs.getZoneLevel(parent_zone))?.add(relative_depth)?;
+
+        if target_level > self.max_refinement_level()? {
+            return Err(DggrsError::RefinementLevelPlusRelativeDepthLimitReached {
+                grid_name: self.grid_name().to_string(),
+                requested: relative_depth,
+                maximum: self.max_refinement_level()?,
+            });
+        };
+        Ok(())
+    }
+
+    /// The primary parent of a zone, `None` for root-level zones.
+    ///
+    /// Zones of aperture 3 grids have several parents, the primary one is the parent the zone is a centroid child of.
+    fn primary_parent(
+        &self,
+        dggrs: &DGGRS,
+        zone: DGGRSZone,
+    ) -> Result<Option<DGGRSZone>, DggrsError> {
+        let parents = dggrs.getZoneParents(zone);
+        let parent = match parents.len() {
+            0 => return Ok(None),
+            1 => parents[0],
+            _ => {
+                if self.id.spec().aperture == 7 {
+                    parents[0]
+                } else {
+                    parents
+                        .into_iter()
+                        .find(|p| dggrs.isZoneCentroidChild(*p))
+                        .ok_or_else(|| {
+                            DggrsError::Dggal(DggalError::InvalidZoneIdFormat(
+                                "Could not determine a primary parent for this zone".to_string(),
+                            ))
+                        })?
+                }
+            }
+        };
+        Ok(Some(parent))
+    }
+
+    /// Validates a zone and collects its `ZoneInfo`, the centroid is only computed if requested.
+    fn zone_info_with(
+        &self,
+        dggrs: &DGGRS,
+        zone_id: &ZoneId,
+        center: bool,
+    ) -> Result<ZoneInfo, DggrsError> {
+        let zone_u64 = to_valid_dggal_zone(dggrs, zone_id)?;
+        let refinement_level = RefinementLevel::new(dggrs.getZoneLevel(zone_u64))?;
+
+        if refinement_level > self.max_refinement_level()? {
+            return Err(DggrsError::RefinementLevelLimitReached {
+                grid_name: self.grid_name().to_string(),
+                requested: refinement_level,
+                maximum: self.max_refinement_level()?,
+            });
+        }
+
+        let format = zone_id.format();
+        let primary_parent = self.primary_parent(dggrs, zone_u64)?;
+
+        let mut root_zone = zone_u64;
+        while let Some(parent) = self.primary_parent(dggrs, root_zone)? {
+            root_zone = parent;
+        }
+
+        let vertex_count: u32 = dggrs.countZoneEdges(zone_u64).try_into().map_err(|e| {
+            DggalError::EdgeCountConversion {
+                zone_id: zone_id.to_string(),
+                source: e,
+            }
+        })?;
+
+        Ok(ZoneInfo {
+            id: zone_id.clone(),
+            refinement_level,
+            root_zone: to_zone_id(dggrs, root_zone, format)?,
+            vertex_count,
+            is_pentagon: vertex_count == 5,
+            primary_parent: primary_parent
+                .map(|p| to_zone_id(dggrs, p, format))
+                .transpose()?,
+            center: center.then(|| to_point(&dggrs.getZoneWGS84Centroid(zone_u64))),
+        })
+    }
+}
+
+/// The zones in the bounding box, or all zones if no bbox is supplied
+///
+/// A box across the antimeridian is listed as two extents, zones in both are returned once.
+fn list_zones(
+    dggrs: &DGGRS,
+    refinement_level: RefinementLevel,
+    bbox: Option<BoundingBox>,
+) -> Result<Vec<DGGRSZone>, DggrsError> {
+    let bbox = bbox.unwrap_or(BoundingBox::WORLD);
+    bbox.validate()?;
+
+    let mut seen = HashSet::new();
+    Ok(bbox
+        .split_antimeridian()
+        .iter()
+        .flat_map(|part| dggrs.listZones(i32::from(refinement_level), &bbox_to_geoextent(part)))
+        .filter(|zone| seen.insert(*zone))
+        .collect())
 }
 
 impl DggrsApi for DggalImpl {
@@ -54,17 +185,36 @@ impl DggrsApi for DggalImpl {
             });
         };
 
-        let geo_extent = if let Some(b) = bbox {
-            bbox_to_geoextent(&b)
-        } else {
-            bbox_to_geoextent(&BoundingBox::WORLD)
+        let dggrs = self.get_dggrs()?;
+
+        let zones = list_zones(&dggrs, refinement_level, bbox)?;
+        to_zones(dggrs, zones, cfg)
+    }
+
+    fn zones_from_bbox_into(
+        &self,
+        refinement_level: RefinementLevel,
+        bbox: Option<BoundingBox>,
+        config: Option<DggrsApiConfig>,
+        sink: &mut dyn ZoneSink,
+    ) -> Result<(), DggrsError> {
+        let cfg = config.unwrap_or_default();
+        if refinement_level > self.max_refinement_level()? {
+            return Err(DggrsError::RefinementLevelLimitReached {
+                grid_name: self.grid_name().to_string(),
+                requested: refinement_level,
+                maximum: self.max_refinement_level()?,
+            });
         };
 
         let dggrs = self.get_dggrs()?;
 
-        let zones = dggrs.listZones(i32::from(refinement_level), &geo_extent);
-        Ok(to_zones(dggrs, zones, cfg)?)
+        for dggal_zone in list_zones(&dggrs, refinement_level, bbox)? {
+            push_zone(sink, refinement_level, &dggrs, dggal_zone, cfg)?;
+        }
+        Ok(())
     }
+
     fn zone_from_point(
         &self,
         refinement_level: RefinementLevel,
@@ -75,8 +225,29 @@ impl DggrsApi for DggalImpl {
         let dggrs = self.get_dggrs()?;
         let zone = dggrs.getZoneFromWGS84Centroid(refinement_level.get(), &to_geo_point(point));
         let zones = vec![zone];
-        Ok(to_zones(dggrs, zones, cfg)?)
+        to_zones(dggrs, zones, cfg)
+    }
+
+    fn zone_ids_from_points(
+        &self,
+        refinement_level: RefinementLevel,
+        points: &[Point],
+        format: ZoneIdFormat,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+        points
+            .iter()
+            .map(|&point| {
+                let zone =
+                    dggrs.getZoneFromWGS84Centroid(refinement_level.get(), &to_geo_point(point));
+                if zone == NULL_ZONE {
+                    return Err(DggrsError::NoZoneAtPoint(point));
+                }
+                Ok(to_zone_id(&dggrs, zone, format)?)
+            })
+            .collect()
     }
+
     fn zones_from_parent(
         &self,
         relative_depth: RelativeDepth,
@@ -87,39 +258,12 @@ impl DggrsApi for DggalImpl {
 
         let dggrs = self.get_dggrs()?;
 
-        // Check if ParentZoneId is Int
-        let parent_zone_u64 = match &parent_zone_id {
-            ZoneId::IntId(id) => *id,
-            ZoneId::StrId(s) => dggrs.getZoneFromTextID(s),
-            ZoneId::HexId(h) => dggrs.getZoneFromTextID(&h.to_string()),
-        };
-
-        if dggrs.getZoneArea(parent_zone_u64).is_infinite() {
-            return Err(DggrsError::Dggal(DggalError::InvalidDggalZoneId));
-        }
-
-        if relative_depth > self.max_relative_depth()? {
-            return Err(DggrsError::RelativeDepthLimitReached {
-                grid_name: self.grid_name().to_string(),
-                requested: relative_depth,
-                maximum: self.max_relative_depth()?,
-            });
-        };
-
-        let target_level =
-            RefinementLevel::new(dggrs.getZoneLevel(parent_zone_u64))?.add(relative_depth)?;
-
-        if target_level > self.max_refinement_level()? {
-            return Err(DggrsError::RefinementLevelPlusRelativeDepthLimitReached {
-                grid_name: self.grid_name().to_string(),
-                requested: relative_depth,
-                maximum: self.max_refinement_level()?,
-            });
-        };
+        let parent_zone_u64 = to_valid_dggal_zone(&dggrs, &parent_zone_id)?;
+        self.check_relative_depth(&dggrs, parent_zone_u64, relative_depth)?;
 
         let zones = dggrs.getSubZones(parent_zone_u64, i32::from(relative_depth));
 
-        Ok(to_zones(dggrs, zones, cfg)?)
+        to_zones(dggrs, zones, cfg)
     }
 
     fn primary_parent_from_zone(
@@ -130,41 +274,15 @@ impl DggrsApi for DggalImpl {
         let cfg = config.unwrap_or_default();
         let dggrs = self.get_dggrs()?;
 
-        let zone_u64 = match &zone_id {
-            ZoneId::IntId(id) => *id,
-            ZoneId::StrId(s) => dggrs.getZoneFromTextID(s),
-            ZoneId::HexId(h) => dggrs.getZoneFromTextID(&h.to_string()),
-        };
+        let zone_u64 = to_valid_dggal_zone(&dggrs, &zone_id)?;
 
-        if dggrs.getZoneArea(zone_u64).is_infinite() {
-            return Err(DggrsError::Dggal(DggalError::InvalidDggalZoneId));
-        }
+        let parent = self.primary_parent(&dggrs, zone_u64)?.ok_or_else(|| {
+            DggrsError::Dggal(DggalError::InvalidZoneIdFormat(
+                "Root-level zones do not have a parent".to_string(),
+            ))
+        })?;
 
-        let parents = dggrs.getZoneParents(zone_u64);
-        let parent = match parents.len() {
-            0 => {
-                return Err(DggrsError::Dggal(DggalError::InvalidZoneIdFormat(
-                    "Root-level zones do not have a parent".to_string(),
-                )));
-            }
-            1 => parents[0],
-            _ => {
-                if self.id.spec().aperture == 7 {
-                    parents[0]
-                } else {
-                    parents
-                        .into_iter()
-                        .find(|p| dggrs.isZoneCentroidChild(*p))
-                        .ok_or_else(|| {
-                            DggrsError::Dggal(DggalError::InvalidZoneIdFormat(
-                                "Could not determine a primary parent for this zone".to_string(),
-                            ))
-                        })?
-                }
-            }
-        };
-
-        Ok(to_zones(dggrs, vec![parent], cfg)?)
+        to_zones(dggrs, vec![parent], cfg)
     }
 
     fn zone_from_id(
@@ -176,16 +294,101 @@ impl DggrsApi for DggalImpl {
 
         let dggrs = self.get_dggrs()?;
 
-        // Check if ZoneId is Int
-        let zone_u64 = match &zone_id {
-            ZoneId::IntId(id) => *id,
-            ZoneId::StrId(s) => dggrs.getZoneFromTextID(s),
-            ZoneId::HexId(h) => dggrs.getZoneFromTextID(&h.to_string()),
-        };
+        let zone_u64 = to_valid_dggal_zone(&dggrs, &zone_id)?;
 
         let zones = vec![zone_u64];
 
-        Ok(to_zones(dggrs, zones, cfg)?)
+        to_zones(dggrs, zones, cfg)
+    }
+
+    fn parse_zone_id(&self, zone_id: &str) -> Result<ZoneId, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+
+        let zone_u64 = to_valid_dggal_zone(&dggrs, &ZoneId::new_str(zone_id.trim())?)?;
+        Ok(to_str_zone_id(&dggrs, zone_u64)?)
+    }
+
+    fn format_zone_id(&self, zone_id: &ZoneId, format: ZoneIdFormat) -> Result<ZoneId, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+
+        let zone_u64 = to_valid_dggal_zone(&dggrs, zone_id)?;
+        Ok(to_zone_id(&dggrs, zone_u64, format)?)
+    }
+
+    fn validate_zone(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+        self.zone_info_with(&dggrs, zone_id, false)
+    }
+
+    fn zone_info(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+        self.zone_info_with(&dggrs, zone_id, true)
+    }
+
+    fn sub_zone_ids(
+        &self,
+        relative_depth: RelativeDepth,
+        parent_zone_id: ZoneId,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+
+        let parent_zone_u64 = to_valid_dggal_zone(&dggrs, &parent_zone_id)?;
+        self.check_relative_depth(&dggrs, parent_zone_u64, relative_depth)?;
+
+        // NOTE: getSubZones() already returns the sub-zones in the DGGAL default sub-zone order
+        Ok(dggrs
+            .getSubZones(parent_zone_u64, i32::from(relative_depth))
+            .into_iter()
+            .map(|z| to_str_zone_id(&dggrs, z))
+            .collect::<Result<Vec<_>, DggalError>>()?)
+    }
+
+    fn sub_zone_from_index(
+        &self,
+        relative_depth: RelativeDepth,
+        parent_zone_id: ZoneId,
+        index: u64,
+    ) -> Result<ZoneId, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+
+        let parent_zone_u64 = to_valid_dggal_zone(&dggrs, &parent_zone_id)?;
+        self.check_relative_depth(&dggrs, parent_zone_u64, relative_depth)?;
+
+        let count = dggrs.countSubZones(parent_zone_u64, i32::from(relative_depth));
+        let sub_zone = if index < count {
+            dggrs.getSubZoneAtIndex(parent_zone_u64, i32::from(relative_depth), index as i64)
+        } else {
+            NULL_ZONE
+        };
+
+        if sub_zone == NULL_ZONE {
+            return Err(DggrsError::Dggal(DggalError::SubZoneIndexOutOfRange {
+                parent_id: parent_zone_id.to_string(),
+                index,
+                count,
+            }));
+        }
+
+        Ok(to_str_zone_id(&dggrs, sub_zone)?)
+    }
+
+    fn sub_zone_index(&self, ancestor_zone_id: ZoneId, zone_id: ZoneId) -> Result<u64, DggrsError> {
+        let dggrs = self.get_dggrs()?;
+
+        let ancestor_u64 = to_valid_dggal_zone(&dggrs, &ancestor_zone_id)?;
+        let zone_u64 = to_valid_dggal_zone(&dggrs, &zone_id)?;
+
+        // NOTE: getSubZoneIndex() returns -1 if the zone is not a sub-zone of the ancestor
+        u64::try_from(dggrs.getSubZoneIndex(ancestor_u64, zone_u64)).map_err(|_| {
+            DggrsError::Dggal(DggalError::NotASubZone {
+                zone_id: zone_id.to_string(),
+                ancestor_id: ancestor_zone_id.to_string(),
+            })
+        })
+    }
+
+    fn uid(&self) -> DggrsUid {
+        self.id
     }
 
     fn zone_count(&self, refinement_level: RefinementLevel) -> Result<u64, DggrsError> {
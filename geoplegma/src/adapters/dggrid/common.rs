@@ -36,17 +36,22 @@ pub mod dggrid {
 
 pub mod write {
     use crate::api::DggrsApiConfig;
-    use crate::types::{BoundingBox, RefinementLevel};
+    use crate::geometry::{Densification, mean_edge_length};
+    use crate::types::{BoundingBox, Point, RefinementLevel};
     use std::fs;
     use std::io::{self, Write};
     use std::path::Path;
     use tracing::debug;
 
-    pub const DENSIFICATION: u8 = 50; // DGGRID option
+    /// DGGRID takes one densification for all zones of a run, it is estimated from the mean edge length at the level and polar latitudes.
+    pub fn densification(densification: &Densification, zone_count: u64) -> u32 {
+        densification.vertices_per_edge(mean_edge_length(zone_count), 90.0)
+    }
 
     pub fn metafile(
         metafile: &Path,
         refinement_level: &RefinementLevel,
+        zone_count: u64,
         cell_output_file_name: &Path,
         children_output_file_name: &Path,
         neighbor_output_file_name: &Path,
@@ -87,42 +92,78 @@ pub mod write {
             )?;
         }
 
-        if conf.densify {
-            writeln!(file, "densification {}", DENSIFICATION)?;
+        let densification = densification(&conf.densification, zone_count);
+        if densification > 0 {
+            writeln!(file, "densification {}", densification)?;
         }
 
         Ok(())
     }
 
+    /// Writes the bbox as AIGEN clip region, a bbox across the antimeridian becomes two polygons
     pub fn bbox(bbox: &BoundingBox, bboxfile: &Path) -> io::Result<()> {
-        let (minx, miny) = (bbox.min_lon, bbox.min_lat);
-        let (maxx, maxy) = (bbox.max_lon, bbox.max_lat);
-
-        // define the 5 vertices (closing the polygon)
-        let vertices = vec![
-            (minx, miny), // lower-left
-            (maxx, miny), // lower-right
-            (maxx, maxy), // upper-right
-            (minx, maxy), // upper-left
-            (minx, miny), // close
-        ];
         let mut file = fs::File::create(bboxfile)?;
 
-        // First line: ID and center of the bbox (NOT part of the ring)
-        let center_x = (minx + maxx) / 2.0;
-        let center_y = (miny + maxy) / 2.0;
-        writeln!(file, "1 {:.7} {:.7}", center_x, center_y)?;
+        for (id, part) in bbox.split_antimeridian().iter().enumerate() {
+            let (minx, miny) = (part.min_lon, part.min_lat);
+            let (maxx, maxy) = (part.max_lon, part.max_lat);
+
+            // define the 5 vertices (closing the polygon)
+            let vertices = vec![
+                (minx, miny), // lower-left
+                (maxx, miny), // lower-right
+                (maxx, maxy), // upper-right
+                (minx, maxy), // upper-left
+                (minx, miny), // close
+            ];
+
+            // First line: ID and center of the bbox (NOT part of the ring)
+            let center_x = (minx + maxx) / 2.0;
+            let center_y = (miny + maxy) / 2.0;
+            writeln!(file, "{} {:.7} {:.7}", id + 1, center_x, center_y)?;
+
+            for (x, y) in &vertices {
+                writeln!(file, "{:.7} {:.7}", x, y)?;
+            }
 
-        for (x, y) in &vertices {
-            writeln!(file, "{:.7} {:.7}", x, y)?;
+            writeln!(file, "END")?;
         }
-
-        writeln!(file, "END")?;
         writeln!(file, "END")?;
 
         Ok(())
     }
 
+    /// Appends the TRANSFORM_POINTS operation to the metafile and writes the points as input file.
+    ///
+    /// DGGRID writes the ZoneID of every point to the output file, one line per point in the order of the input file.
+    pub fn points(
+        metafile: &Path,
+        input_file_name: &Path,
+        output_file_name: &Path,
+        points: &[Point],
+    ) -> io::Result<()> {
+        let mut file = fs::OpenOptions::new().append(true).open(metafile)?;
+        writeln!(file, "dggrid_operation TRANSFORM_POINTS")?;
+        writeln!(file, "input_address_type GEO")?;
+        writeln!(
+            file,
+            "input_file_name {}",
+            input_file_name.to_string_lossy().into_owned()
+        )?;
+        writeln!(file, "output_file_type TEXT")?;
+        writeln!(
+            file,
+            "output_file_name {}",
+            output_file_name.to_string_lossy().into_owned()
+        )?;
+
+        let mut input = io::BufWriter::new(fs::File::create(input_file_name)?);
+        for point in points {
+            writeln!(input, "{} {}", point.lon, point.lat)?;
+        }
+        input.flush()
+    }
+
     pub fn file(file: &Path) {
         if let Ok(lines) = super::read::lines(file) {
             // Consumes the iterator, returns an (Optional) String
@@ -133,11 +174,351 @@ pub mod write {
     }
 }
 
+pub mod zone_id {
+    use crate::api::{DggrsApi, DggrsApiConfig};
+    use crate::error::DggrsError;
+    use crate::error::dggrid::DggridError;
+    use crate::types::{
+        HierarchyKey, RefinementLevel, RelativeDepth, ZoneId, ZoneIdFormat, ZoneInfo,
+    };
+
+    /// Adds the centroid to the `ZoneInfo` of a zone.
+    ///
+    /// DGGRID has to be run for the centroid, all other outputs are switched off.
+    pub fn with_center(dggrs: &dyn DggrsApi, mut info: ZoneInfo) -> Result<ZoneInfo, DggrsError> {
+        let config = DggrsApiConfig {
+            center: true,
+            ..DggrsApiConfig::ids_only()
+        };
+        info.center = dggrs
+            .zone_from_id(info.id.clone(), Some(config))?
+            .zones
+            .first()
+            .and_then(|zone| zone.center);
+        Ok(info)
+    }
+
+    /// The refinement level of the sub-zones of a zone at a relative depth, checked against the limits of the DGGRS.
+    pub fn sub_zone_level(
+        dggrs: &dyn DggrsApi,
+        parent: &ZoneInfo,
+        relative_depth: RelativeDepth,
+    ) -> Result<RefinementLevel, DggrsError> {
+        let target_level = parent.refinement_level.add(relative_depth)?;
+        if target_level > dggrs.max_refinement_level()? {
+            return Err(DggrsError::RefinementLevelPlusRelativeDepthLimitReached {
+                grid_name: dggrs.uid().spec().name.to_string(),
+                requested: relative_depth,
+                maximum: dggrs.max_refinement_level()?,
+            });
+        }
+        Ok(target_level)
+    }
+
+    /// DGGRID Z3 and Z7 ZoneIds are 64-bit integers, their textual form is 16 hexadecimal characters.
+    pub fn from_u64(v: u64) -> Result<ZoneId, DggrsError> {
+        ZoneId::new_hex(&format!("{v:016x}"))
+    }
+
+    /// Reads the 64-bit integer of a Z3 or Z7 ZoneId, textual ZoneIds are read as hexadecimal.
+    pub fn to_u64(zone_id: &ZoneId) -> Result<u64, DggrsError> {
+        let v = match zone_id {
+            ZoneId::IntId(i) => Ok(*i),
+            ZoneId::HexId(h) => u64::from_str_radix(h.as_str(), 16),
+            ZoneId::StrId(s) => u64::from_str_radix(s, 16),
+        };
+        v.map_err(|_| DggrsError::Dggrid(DggridError::InvalidZoneIdFormat(zone_id.to_string())))
+    }
+
+    /// Converts a Z3 or Z7 ZoneId into the requested format.
+    pub fn to_format(zone_id: &ZoneId, format: ZoneIdFormat) -> Result<ZoneId, DggrsError> {
+        let v = to_u64(zone_id)?;
+        match format {
+            ZoneIdFormat::Text => from_u64(v),
+            ZoneIdFormat::Int => Ok(ZoneId::new_int(v)),
+        }
+    }
+
+    /// Parses a hexadecimal ZoneId written by DGGRID into its canonical textual form.
+    pub fn from_hex_str(s: &str) -> Result<ZoneId, DggrsError> {
+        to_format(&ZoneId::new_hex(s)?, ZoneIdFormat::Text)
+    }
+
+    /// Number of base cells of the icosahedral DGGRID grids.
+    pub const BASE_CELL_COUNT: u8 = 12;
+
+    /// Layout of the hierarchical Z3 and Z7 ZoneIds.
+    ///
+    /// The first four bits hold the base cell, the remaining 60 bits are fixed-width digits. Digits beyond the refinement level of the zone are padded with the all-ones digit (`3` for Z3 and `7` for Z7).
+    pub struct ZOrder {
+        pub digit_bits: u32,
+        pub digit_count: u32,
+        /// The digit that does not exist below a pentagon, i.e. after a base cell and only `0` digits.
+        pub pentagon_deleted_digit: Option<u8>,
+        pub invalid: fn(String) -> DggridError,
+    }
+
+    pub const Z3: ZOrder = ZOrder {
+        digit_bits: 2,
+        digit_count: 30,
+        // NOTE: all three digits are taken to exist below pentagons, this follows the Z3 layout and has not been checked against DGGRID output
+        pentagon_deleted_digit: None,
+        invalid: DggridError::InvalidZ3Format,
+    };
+
+    pub const Z7: ZOrder = ZOrder {
+        digit_bits: 3,
+        digit_count: 20,
+        // NOTE: the pentagons of aperture 7 have six sub-zones, the one in the direction of digit `1` is deleted
+        pentagon_deleted_digit: Some(1),
+        invalid: DggridError::InvalidZ7Format,
+    };
+
+    /// Base cell and the digits up to the refinement level of a Z3 or Z7 ZoneId.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ZDigits {
+        pub base_cell: u8,
+        pub digits: Vec<u8>,
+    }
+
+    impl ZDigits {
+        /// The base cells are pentagons and so are their centroid children with digit `0` at every refinement level.
+        pub fn is_pentagon(&self) -> bool {
+            self.digits.iter().all(|d| *d == 0)
+        }
+    }
+
+    impl ZOrder {
+        pub const fn padding_digit(&self) -> u64 {
+            (1 << self.digit_bits) - 1
+        }
+
+        fn digit(&self, v: u64, position: u32) -> u64 {
+            (v >> (60 - self.digit_bits * (position + 1))) & self.padding_digit()
+        }
+
+        /// Decodes and validates the base cell and the digits, all digits after the first padding digit must be padding as well and the deleted digit must not follow a pentagon.
+        pub fn decode(&self, v: u64) -> Result<ZDigits, DggrsError> {
+            let base_cell = (v >> 60) as u8;
+            if base_cell >= BASE_CELL_COUNT {
+                return Err(DggrsError::Dggrid((self.invalid)(format!(
+                    "Invalid base cell {base_cell}"
+                ))));
+            }
+
+            let mut digits = Vec::new();
+            let mut padded = false;
+            for i in 0..self.digit_count {
+                let digit = self.digit(v, i);
+                if digit == self.padding_digit() {
+                    padded = true;
+                } else if padded {
+                    return Err(DggrsError::Dggrid((self.invalid)(format!(
+                        "Digit {} at position {} follows a padding digit",
+                        digit,
+                        i + 1
+                    ))));
+                } else if self.pentagon_deleted_digit == Some(digit as u8)
+                    && digits.iter().all(|d| *d == 0)
+                {
+                    return Err(DggrsError::Dggrid((self.invalid)(format!(
+                        "Digit {} at position {} is deleted below a pentagon",
+                        digit,
+                        i + 1
+                    ))));
+                } else {
+                    digits.push(digit as u8);
+                }
+            }
+
+            Ok(ZDigits { base_cell, digits })
+        }
+
+        /// Encodes the base cell and the digits, padding the remaining digits.
+        pub fn encode(&self, z: &ZDigits) -> u64 {
+            (0..self.digit_count).fold((z.base_cell as u64) << 60, |v, i| {
+                let digit = z
+                    .digits
+                    .get(i as usize)
+                    .map_or(self.padding_digit(), |d| *d as u64);
+                v | (digit << (60 - self.digit_bits * (i + 1)))
+            })
+        }
+
+        /// The ancestor of a zone at a coarser refinement level, i.e. the digits truncated to the level.
+        pub fn ancestor(
+            &self,
+            zone_id: &ZoneId,
+            refinement_level: RefinementLevel,
+        ) -> Result<ZoneId, DggrsError> {
+            let mut decoded = self.decode(to_u64(zone_id)?)?;
+            let level = RefinementLevel::new(decoded.digits.len() as i32)?;
+            if refinement_level > level {
+                return Err(DggrsError::AncestorLevelTooFine {
+                    zone_id: zone_id.to_string(),
+                    requested: refinement_level,
+                    level,
+                });
+            }
+
+            decoded.digits.truncate(refinement_level.get() as usize);
+            to_format(&ZoneId::new_int(self.encode(&decoded)), zone_id.format())
+        }
+
+        /// The base cell as root zone and the digits of a zone.
+        pub fn hierarchy_key(&self, zone_id: &ZoneId) -> Result<HierarchyKey, DggrsError> {
+            let decoded = self.decode(to_u64(zone_id)?)?;
+            let root_zone = ZDigits {
+                base_cell: decoded.base_cell,
+                digits: Vec::new(),
+            };
+            Ok(HierarchyKey {
+                root_zone: to_format(&ZoneId::new_int(self.encode(&root_zone)), zone_id.format())?,
+                digits: decoded.digits.into_iter().map(u64::from).collect(),
+            })
+        }
+
+        /// The digits of the sub-zones of a zone in ascending order, without the deleted digit below pentagons.
+        fn sub_zone_digits(&self, pentagon: bool) -> impl Iterator<Item = u8> {
+            let deleted = self.pentagon_deleted_digit.filter(|_| pentagon);
+            (0..self.padding_digit() as u8).filter(move |d| Some(*d) != deleted)
+        }
+
+        /// The number of sub-zones of a zone at a relative depth.
+        fn sub_zone_count(&self, pentagon: bool, depth: usize) -> u64 {
+            let aperture = self.padding_digit();
+            match self.pentagon_deleted_digit {
+                // NOTE: the sub-zones of a pentagon are one pentagon and the hexagons of the remaining digits but the deleted one
+                Some(_) if pentagon => {
+                    (0..depth as u32).fold(1, |count, d| count + (aperture - 2) * aperture.pow(d))
+                }
+                _ => aperture.pow(depth as u32),
+            }
+        }
+
+        /// The sub-zones of a zone at a relative depth, ordered by their digits.
+        pub fn sub_zones(&self, parent: &ZoneId, depth: usize) -> Result<Vec<ZoneId>, DggrsError> {
+            let mut sub_zones = vec![self.decode(to_u64(parent)?)?];
+            for _ in 0..depth {
+                sub_zones = sub_zones
+                    .into_iter()
+                    .flat_map(|zone| {
+                        self.sub_zone_digits(zone.is_pentagon()).map(move |digit| {
+                            let mut digits = zone.digits.clone();
+                            digits.push(digit);
+                            ZDigits {
+                                base_cell: zone.base_cell,
+                                digits,
+                            }
+                        })
+                    })
+                    .collect();
+            }
+            sub_zones.iter().map(|z| from_u64(self.encode(z))).collect()
+        }
+
+        /// The sub-zone at an index of the ordered sub-zones of a zone, see `sub_zones`.
+        pub fn sub_zone_from_index(
+            &self,
+            parent: &ZoneId,
+            depth: usize,
+            index: u64,
+        ) -> Result<ZoneId, DggrsError> {
+            let mut zone = self.decode(to_u64(parent)?)?;
+            let count = self.sub_zone_count(zone.is_pentagon(), depth);
+            if index >= count {
+                return Err(DggrsError::Dggrid(DggridError::SubZoneIndexOutOfRange {
+                    parent_id: parent.to_string(),
+                    index,
+                    count,
+                }));
+            }
+
+            // NOTE: every digit skips the sub-zones of the smaller digits
+            let mut index = index;
+            for remaining in (0..depth).rev() {
+                let pentagon = zone.is_pentagon();
+                for digit in self.sub_zone_digits(pentagon) {
+                    let count = self.sub_zone_count(pentagon && digit == 0, remaining);
+                    if index < count {
+                        zone.digits.push(digit);
+                        break;
+                    }
+                    index -= count;
+                }
+            }
+            from_u64(self.encode(&zone))
+        }
+
+        /// The index of a zone in the ordered sub-zones of one of its ancestors, see `sub_zones`.
+        pub fn sub_zone_index(
+            &self,
+            ancestor: &ZoneId,
+            zone_id: &ZoneId,
+        ) -> Result<u64, DggrsError> {
+            let mut prefix = self.decode(to_u64(ancestor)?)?;
+            let zone = self.decode(to_u64(zone_id)?)?;
+            if zone.base_cell != prefix.base_cell || !zone.digits.starts_with(&prefix.digits) {
+                return Err(DggrsError::Dggrid(DggridError::NotASubZone {
+                    zone_id: zone_id.to_string(),
+                    ancestor_id: ancestor.to_string(),
+                }));
+            }
+
+            let mut index = 0;
+            let sub_digits = &zone.digits[prefix.digits.len()..];
+            for (i, digit) in sub_digits.iter().enumerate() {
+                let remaining = sub_digits.len() - i - 1;
+                let pentagon = prefix.is_pentagon();
+                index += self
+                    .sub_zone_digits(pentagon)
+                    .take_while(|d| d < digit)
+                    .map(|d| self.sub_zone_count(pentagon && d == 0, remaining))
+                    .sum::<u64>();
+                prefix.digits.push(*digit);
+            }
+            Ok(index)
+        }
+
+        /// Collects the `ZoneInfo` of a zone from its digits, without a centroid.
+        pub fn zone_info(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+            let format = zone_id.format();
+            let decoded = self.decode(to_u64(zone_id)?)?;
+
+            let root_zone = ZDigits {
+                base_cell: decoded.base_cell,
+                digits: Vec::new(),
+            };
+            let primary_parent = match decoded.digits.split_last() {
+                Some((_, parent_digits)) => Some(to_format(
+                    &ZoneId::new_int(self.encode(&ZDigits {
+                        base_cell: decoded.base_cell,
+                        digits: parent_digits.to_vec(),
+                    })),
+                    format,
+                )?),
+                None => None,
+            };
+            let is_pentagon = decoded.is_pentagon();
+
+            Ok(ZoneInfo {
+                id: zone_id.clone(),
+                refinement_level: RefinementLevel::new(decoded.digits.len() as i32)?,
+                root_zone: to_format(&ZoneId::new_int(self.encode(&root_zone)), format)?,
+                vertex_count: if is_pentagon { 5 } else { 6 },
+                is_pentagon,
+                primary_parent,
+                center: None,
+            })
+        }
+    }
+}
+
 pub mod read {
     use crate::error::DggrsError;
     use crate::error::dggrid::DggridError;
     use crate::types::Point;
-    use crate::types::{Region, Zone, ZoneId};
+    use crate::types::{Region, Zone, ZoneId, ZoneIdFormat};
     use core::f64;
     use std::collections::{BTreeMap, HashMap};
     use std::fs;
@@ -168,7 +549,7 @@ pub mod read {
                 // header: <ID> <cx> <cy>  thats the center coordinates after the ID
                 [id_str, cx, cy] => {
                     cur = Some(AigenZoneRegionCenter {
-                        id: ZoneId::new_hex(id_str)?,
+                        id: super::zone_id::from_hex_str(id_str)?,
                         xy: (cx.parse()?, cy.parse()?),
                         vec_xy: Vec::new(),
                     });
@@ -193,11 +574,8 @@ pub mod read {
                         let pnt = Some(Point::new(z.xy.1, z.xy.0));
 
                         let poly = if z.vec_xy.len() >= 2 {
-                            let region_points: Vec<Point> = z
-                                .vec_xy
-                                .iter()
-                                .map(|(x, y)| Point::new(*y, *x))
-                                .collect();
+                            let region_points: Vec<Point> =
+                                z.vec_xy.iter().map(|(x, y)| Point::new(*y, *x)).collect();
                             Some(Region::new(region_points))
                         } else {
                             None
@@ -237,12 +615,12 @@ pub mod read {
             if parts.is_empty() {
                 continue;
             }
-            let key = ZoneId::new_hex(parts[0])?;
+            let key = super::zone_id::from_hex_str(parts[0])?;
 
             let vals = parts
                 .iter()
                 .skip(1)
-                .map(|t| ZoneId::new_hex(t))
+                .map(|t| super::zone_id::from_hex_str(t))
                 .collect::<Result<Vec<_>, _>>()?;
 
             if map.insert(key, vals).is_some() {
@@ -254,6 +632,25 @@ pub mod read {
         Ok(map)
     }
 
+    /// Used to parse the output file of TRANSFORM_POINTS, the ZoneID is the first field of every line
+    pub fn transformed_points(
+        s: &str,
+        points: &[Point],
+        format: ZoneIdFormat,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let mut lines = s.lines().filter(|line| !line.trim().is_empty());
+        points
+            .iter()
+            .map(|&point| {
+                let id = lines
+                    .next()
+                    .and_then(|line| line.split_whitespace().next())
+                    .ok_or(DggrsError::NoZoneAtPoint(point))?;
+                super::zone_id::to_format(&super::zone_id::from_hex_str(id)?, format)
+            })
+            .collect()
+    }
+
     // Read aigen file produced by DGGRID
     // Todo: this is inefficient, use the read_lines function as in print_file
     // https://doc.rust-lang.org/rust-by-example/std_misc/file/read_lines.html
@@ -276,8 +673,8 @@ pub mod read {
 pub mod output {
     use crate::api::DggrsApiConfig;
     use crate::error::DggrsError;
-    use crate::types::{ZoneId, Zones};
-    use geo::GeodesicArea;
+    use crate::geometry::normalize;
+    use crate::types::{Zone, ZoneId, ZoneIdFormat, Zones};
     use itertools::Itertools;
     use std::collections::HashMap;
     use std::path::Path;
@@ -288,6 +685,7 @@ pub mod output {
         neighbors_path: &Path,
         conf: &DggrsApiConfig,
     ) -> Result<Zones, DggrsError> {
+        conf.validate()?;
         // the default output
         let aigen_text = super::read::file(&aigen_path)?;
         let mut zones_map = super::read::parse_aigen_to_zones_map(&aigen_text)?;
@@ -332,29 +730,42 @@ pub mod output {
             // compute area
             if conf.area_sqm {
                 if let Some(ref poly) = z.region {
-                    // NOTE:
-                    // It may be a good idea to wrap geodesic_area_unsigned into
-                    // a separate extension trait, so that we don't use a different
-                    // calculation elsewhere by accident.
-                    z.area_sqm = Some(poly.to_geo_polygon().geodesic_area_unsigned());
+                    z.area_sqm = Some(poly.geodesic_area());
                 }
             }
 
-            // drop geometry not requested
+            // drop geometry not requested, the area and corners are taken from the raw region
             if !conf.region {
                 z.region = None;
+            } else {
+                z.region = z.region.take().map(|r| normalize(r, conf.geometry));
             }
 
             if !conf.center {
                 z.center = None;
             }
         }
-        Ok(Zones {
-            zones: zones_map
-                .into_values()
-                .sorted_by(|a, b| b.id.cmp(&a.id))
-                .collect(),
-        })
+        let mut zones: Vec<Zone> = zones_map
+            .into_values()
+            .sorted_by(|a, b| b.id.cmp(&a.id))
+            .collect();
+
+        if conf.preferred_id_format != ZoneIdFormat::Text {
+            let format = conf.preferred_id_format;
+            for z in zones.iter_mut() {
+                z.id = super::zone_id::to_format(&z.id, format)?;
+                for ids in [z.children.as_mut(), z.neighbors.as_mut()]
+                    .into_iter()
+                    .flatten()
+                {
+                    for id in ids.iter_mut() {
+                        *id = super::zone_id::to_format(id, format)?;
+                    }
+                }
+            }
+        }
+
+        Ok(Zones { zones })
     }
 }
 
@@ -363,7 +774,7 @@ pub mod helper {
     use geo::CoordsIter;
     use geo::prelude::ConvexHull;
     pub fn corner_count_convex(poly: &Region) -> u32 {
-        let hull = poly.to_geo_polygon().convex_hull();
+        let hull = poly.to_geo_multi_polygon().convex_hull();
         // coords_count() includes the closing vertex => subtract 1
         (hull.exterior().coords_count() as u32).saturating_sub(1)
     }
@@ -12,7 +12,10 @@ use crate::adapters::dggrid::dggrid::DggridAdapter;
 use crate::api::{DggrsApi, DggrsApiConfig};
 use crate::error::DggrsError;
 use crate::error::dggrid::DggridError;
-use crate::types::{BoundingBox, DggrsUid, Point, RefinementLevel, RelativeDepth, ZoneId, Zones};
+use crate::types::{
+    BoundingBox, DggrsUid, HierarchyKey, Point, RefinementLevel, RelativeDepth, ZoneId,
+    ZoneIdFormat, ZoneInfo, Zones,
+};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::{self, Write};
@@ -58,6 +61,7 @@ impl DggrsApi for Isea3hImpl {
         let _ = common::write::metafile(
             &meta_path,
             &refinement_level,
+            self.zone_count(refinement_level)?,
             &aigen_path.with_extension(""),
             &children_path.with_extension(""),
             &neighbor_path.with_extension(""),
@@ -67,6 +71,7 @@ impl DggrsApi for Isea3hImpl {
         let _ = isea3h_metafile(&meta_path);
 
         if let Some(bbox) = &bbox {
+            bbox.validate()?;
             let _ = common::write::bbox(bbox, &bbox_path);
 
             // Append to metafile
@@ -111,6 +116,7 @@ impl DggrsApi for Isea3hImpl {
         let _ = common::write::metafile(
             &meta_path,
             &refinement_level,
+            self.zone_count(refinement_level)?,
             &aigen_path.with_extension(""),
             &children_path.with_extension(""),
             &neighbor_path.with_extension(""),
@@ -158,6 +164,52 @@ impl DggrsApi for Isea3hImpl {
         let _ = fs::remove_file(&input_path);
         Ok(result)
     }
+
+    fn zone_ids_from_points(
+        &self,
+        refinement_level: RefinementLevel,
+        points: &[Point],
+        format: ZoneIdFormat,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        if points.is_empty() {
+            return Ok(Vec::new());
+        }
+        let cfg = DggrsApiConfig::ids_only();
+        let (meta_path, aigen_path, children_path, neighbor_path, bbox_path, input_path) =
+            common::dggrid::setup(&self.adapter.workdir);
+        let output_path = input_path.with_extension("out");
+
+        let _ = common::write::metafile(
+            &meta_path,
+            &refinement_level,
+            self.zone_count(refinement_level)?,
+            &aigen_path.with_extension(""),
+            &children_path.with_extension(""),
+            &neighbor_path.with_extension(""),
+            &cfg,
+        );
+        let _ = isea3h_metafile(&meta_path);
+        // NOTE: all points are located in one DGGRID run
+        common::write::points(&meta_path, &input_path, &output_path, points)
+            .map_err(DggridError::from)?;
+
+        common::write::file(&meta_path);
+        common::dggrid::execute(&self.adapter.executable, &meta_path);
+        let result = common::read::file(&output_path)
+            .map_err(DggrsError::from)
+            .and_then(|text| common::read::transformed_points(&text, points, format));
+        common::cleanup(
+            &meta_path,
+            &aigen_path,
+            &children_path,
+            &neighbor_path,
+            &bbox_path,
+            &input_path,
+        );
+        let _ = fs::remove_file(&output_path);
+        result
+    }
+
     fn zones_from_parent(
         &self,
         relative_depth: RelativeDepth,
@@ -165,6 +217,7 @@ impl DggrsApi for Isea3hImpl {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError> {
         let cfg = config.unwrap_or_default();
+        let parent_zone_id = common::zone_id::to_format(&parent_zone_id, ZoneIdFormat::Text)?;
         let (meta_path, aigen_path, children_path, neighbor_path, bbox_path, input_path) =
             common::dggrid::setup(&self.adapter.workdir);
 
@@ -174,6 +227,7 @@ impl DggrsApi for Isea3hImpl {
         let _ = common::write::metafile(
             &meta_path,
             &target_level,
+            self.zone_count(target_level)?,
             &aigen_path.with_extension(""),
             &children_path.with_extension(""),
             &neighbor_path.with_extension(""),
@@ -218,6 +272,7 @@ impl DggrsApi for Isea3hImpl {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError> {
         let cfg = config.unwrap_or_default();
+        let zone_id = common::zone_id::to_format(&zone_id, ZoneIdFormat::Text)?;
         let (meta_path, aigen_path, children_path, neighbor_path, bbox_path, input_path) =
             common::dggrid::setup(&self.adapter.workdir);
 
@@ -232,6 +287,7 @@ impl DggrsApi for Isea3hImpl {
         let _ = common::write::metafile(
             &meta_path,
             &parent_level,
+            self.zone_count(parent_level)?,
             &aigen_path.with_extension(""),
             &children_path.with_extension(""),
             &neighbor_path.with_extension(""),
@@ -282,6 +338,7 @@ impl DggrsApi for Isea3hImpl {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError> {
         let cfg = config.unwrap_or_default();
+        let zone_id = common::zone_id::to_format(&zone_id, ZoneIdFormat::Text)?;
         let (meta_path, aigen_path, children_path, neighbor_path, bbox_path, input_path) =
             common::dggrid::setup(&self.adapter.workdir);
 
@@ -289,6 +346,7 @@ impl DggrsApi for Isea3hImpl {
         let _ = common::write::metafile(
             &meta_path,
             &refinement_level,
+            self.zone_count(refinement_level)?,
             &aigen_path.with_extension(""),
             &children_path.with_extension(""),
             &neighbor_path.with_extension(""),
@@ -335,6 +393,85 @@ impl DggrsApi for Isea3hImpl {
         Ok(result)
     }
 
+    fn parse_zone_id(&self, zone_id: &str) -> Result<ZoneId, DggrsError> {
+        let zone_id = common::zone_id::from_hex_str(zone_id.trim())?;
+        get_refinement_level_from_z3_zone_id(&zone_id)?;
+        Ok(zone_id)
+    }
+
+    fn format_zone_id(&self, zone_id: &ZoneId, format: ZoneIdFormat) -> Result<ZoneId, DggrsError> {
+        let text_id = common::zone_id::to_format(zone_id, ZoneIdFormat::Text)?;
+        get_refinement_level_from_z3_zone_id(&text_id)?;
+        common::zone_id::to_format(&text_id, format)
+    }
+
+    fn validate_zone(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+        let info = common::zone_id::Z3.zone_info(zone_id)?;
+
+        if info.refinement_level > self.max_refinement_level()? {
+            return Err(DggrsError::RefinementLevelLimitReached {
+                grid_name: self.id.spec().name.to_string(),
+                requested: info.refinement_level,
+                maximum: self.max_refinement_level()?,
+            });
+        }
+
+        Ok(info)
+    }
+
+    fn ancestor_at_level(
+        &self,
+        zone_id: &ZoneId,
+        refinement_level: RefinementLevel,
+    ) -> Result<ZoneId, DggrsError> {
+        self.validate_zone(zone_id)?;
+        common::zone_id::Z3.ancestor(zone_id, refinement_level)
+    }
+
+    fn hierarchy_key(&self, zone_id: &ZoneId) -> Result<HierarchyKey, DggrsError> {
+        self.validate_zone(zone_id)?;
+        common::zone_id::Z3.hierarchy_key(zone_id)
+    }
+
+    fn sub_zone_ids(
+        &self,
+        relative_depth: RelativeDepth,
+        parent_zone_id: ZoneId,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let parent = self.validate_zone(&parent_zone_id)?;
+        common::zone_id::sub_zone_level(self, &parent, relative_depth)?;
+        common::zone_id::Z3.sub_zones(&parent_zone_id, relative_depth.get() as usize)
+    }
+
+    fn sub_zone_from_index(
+        &self,
+        relative_depth: RelativeDepth,
+        parent_zone_id: ZoneId,
+        index: u64,
+    ) -> Result<ZoneId, DggrsError> {
+        let parent = self.validate_zone(&parent_zone_id)?;
+        common::zone_id::sub_zone_level(self, &parent, relative_depth)?;
+        common::zone_id::Z3.sub_zone_from_index(
+            &parent_zone_id,
+            relative_depth.get() as usize,
+            index,
+        )
+    }
+
+    fn sub_zone_index(&self, ancestor_zone_id: ZoneId, zone_id: ZoneId) -> Result<u64, DggrsError> {
+        self.validate_zone(&ancestor_zone_id)?;
+        self.validate_zone(&zone_id)?;
+        common::zone_id::Z3.sub_zone_index(&ancestor_zone_id, &zone_id)
+    }
+
+    fn zone_info(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+        common::zone_id::with_center(self, self.validate_zone(zone_id)?)
+    }
+
+    fn uid(&self) -> DggrsUid {
+        self.id
+    }
+
     fn zone_count(&self, refinement_level: RefinementLevel) -> Result<u64, DggrsError> {
         let r = refinement_level.get();
         let aperture: u64 = self.id.spec().aperture.into();
@@ -388,10 +525,7 @@ pub fn isea3h_metafile(meta_path: &Path) -> io::Result<()> {
 ///
 /// # Returns
 /// - `Ok(RefinementLevel)`: The detected refinement level.
-/// - `Err(GeoPlegmaError)`: If the identifier is not a `HexId`, contains invalid digits, or fails to create a valid `RefinementLevel`.
-///
-/// # Panics
-/// This function will panic if the provided hex string cannot be parsed into a `u64`, though this is not expected when IDs are generated by DGGRID with the proper configuration.
+/// - `Err(GeoPlegmaError)`: If the identifier is not a `HexId`, has an invalid base cell, has valid digits after a padding digit, or fails to create a valid `RefinementLevel`.
 ///
 /// # Requirements
 /// Zone identifiers must be generated using DGGRID version 8.41 or later, with `z3_invalid_digit` explicitly set to `3`.
@@ -399,33 +533,14 @@ pub fn get_refinement_level_from_z3_zone_id(
     dggrid_z3_id: &ZoneId,
 ) -> Result<RefinementLevel, DggrsError> {
     // make sure to generate zones with DGGRID version 8.41 and z3_invalid_digit 3
-    let hex = match dggrid_z3_id {
-        ZoneId::HexId(h) => h.as_str(),
-        _ => {
-            return Err(DggrsError::Dggrid(DggridError::InvalidZ3Format(
-                "Expected ZoneId::HexId".to_string(),
-            )))?;
-        }
-    };
-
-    let v = u64::from_str_radix(hex, 16).unwrap(); // NOTE: This should be safe if the hex string is coming from DGGRID.
-    let mut resolution = RefinementLevel::new(30)?; // default if no padding found
-    for i in 0..30 {
-        let shift = 60 - 2 * (i + 1);
-        let digit = ((v >> shift) & 0b11) as u64;
-
-        if digit > 3 {
-            return Err(DggrsError::Dggrid(DggridError::InvalidZ3Format(format!(
-                "Invalid Z3 digit {} at position {}",
-                digit,
-                i + 1
-            ))));
-        }
-        if digit == 3 {
-            resolution = RefinementLevel::new(i)?;
-            break;
-        }
+    if !matches!(dggrid_z3_id, ZoneId::HexId(_)) {
+        return Err(DggrsError::Dggrid(DggridError::InvalidZ3Format(
+            "Expected ZoneId::HexId".to_string(),
+        )));
     }
 
-    Ok(resolution)
+    let v = common::zone_id::to_u64(dggrid_z3_id)?;
+    let decoded = common::zone_id::Z3.decode(v)?;
+
+    RefinementLevel::new(decoded.digits.len() as i32)
 }
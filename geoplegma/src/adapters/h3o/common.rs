@@ -8,12 +8,13 @@
 // except according to those terms.
 
 use crate::{
-    api::DggrsApiConfig,
+    api::{DggrsApiConfig, ZoneParts, ZoneSink},
     error::{DggrsError, h3o::H3oError},
-    types::{Point, RefinementLevel, Region, Zone, ZoneId, Zones},
+    geometry::{densify, normalize},
+    types::{Point, RefinementLevel, Region, Zone, ZoneId, ZoneIdFormat, ZoneInfo, Zones},
 };
-use geo::GeodesicArea;
 use h3o::{Boundary, CellIndex, LatLng, Resolution};
+use std::str::FromStr;
 
 /// Translates integer resolution to H3 string resolution
 pub fn refinement_level_to_h3_resolution(
@@ -27,6 +28,59 @@ pub fn refinement_level_to_h3_resolution(
     })
 }
 
+/// Parses a ZoneId into an H3 cell index, textual ZoneIds are read as H3 hexadecimal strings
+pub fn to_cell_index(zone_id: &ZoneId) -> Result<CellIndex, DggrsError> {
+    let cell = match zone_id {
+        ZoneId::IntId(i) => CellIndex::try_from(*i),
+        ZoneId::HexId(h) => CellIndex::from_str(h.as_str()),
+        ZoneId::StrId(s) => CellIndex::from_str(s),
+    };
+
+    cell.map_err(|e| {
+        DggrsError::H3o(H3oError::InvalidZoneID {
+            zone_id: zone_id.to_string(),
+            source: e,
+        })
+    })
+}
+
+/// Converts a H3 cell index into a ZoneId of the requested format
+pub fn to_zone_id(cell: CellIndex, format: ZoneIdFormat) -> Result<ZoneId, DggrsError> {
+    match format {
+        ZoneIdFormat::Text => ZoneId::new_hex(&cell.to_string()),
+        ZoneIdFormat::Int => Ok(ZoneId::new_int(u64::from(cell))),
+    }
+}
+
+/// Collects the `ZoneInfo` of a cell from its index bits, the centroid is only computed if requested
+pub fn to_zone_info(
+    cell: CellIndex,
+    zone_id: &ZoneId,
+    center: bool,
+) -> Result<ZoneInfo, DggrsError> {
+    let format = zone_id.format();
+    let resolution = cell.resolution();
+
+    let root_zone = cell
+        .parent(Resolution::Zero)
+        .map_or(Ok(zone_id.clone()), |c| to_zone_id(c, format))?;
+    let primary_parent = resolution
+        .pred()
+        .and_then(|r| cell.parent(r))
+        .map(|c| to_zone_id(c, format))
+        .transpose()?;
+
+    Ok(ZoneInfo {
+        id: zone_id.clone(),
+        refinement_level: RefinementLevel::new(resolution as i32)?,
+        root_zone,
+        vertex_count: if cell.is_pentagon() { 5 } else { 6 },
+        is_pentagon: cell.is_pentagon(),
+        primary_parent,
+        center: center.then(|| latlng_to_point(LatLng::from(cell))),
+    })
+}
+
 pub fn boundary_to_polygon(boundary: &Boundary) -> Region {
     let mut points: Vec<Point> = boundary
         .iter()
@@ -57,77 +111,125 @@ pub fn latlng_to_point(latlng: LatLng) -> Point {
 }
 
 pub fn to_zones(h3o_zones: Vec<CellIndex>, conf: DggrsApiConfig) -> Result<Zones, DggrsError> {
+    conf.validate()?;
     let zones: Vec<Zone> = h3o_zones
         .into_iter()
-        .map(|h3o_zone| {
-            let id = ZoneId::new_hex(&h3o_zone.to_string())?;
-
-            let center = if conf.center {
-                let ll = LatLng::from(h3o_zone);
-                Some(latlng_to_point(ll))
-            } else {
-                None
-            };
-
-            let region = if conf.region || conf.area_sqm || conf.vertex_count {
-                let boundary = h3o_zone.boundary();
-                Some(boundary_to_polygon(&boundary))
-            } else {
-                None
-            };
-
-            let area_sqm = if conf.area_sqm {
-                region.as_ref().map(|r| r.to_geo_polygon().geodesic_area_unsigned()) // NOTE: It is also an option to use the build in area function of H3o
-            } else {
-                None
-            };
-
-            let vertex_count = if conf.vertex_count {
-                region.as_ref().map(|r| r.coords_count() as u32) // NOTE: It is also an option to use the build-in vertex function of H3o
-            } else {
-                None
-            };
-
-            let children = if conf.children {
-                //FIX: don't prodcuce any children if max_refinement_level has been reached
-                let chr_res = h3o_zone
-                    .resolution()
-                    .succ() // NOTE: succ() returns an Option, therefore we can use ok_or_else in the next line and not map_err
-                    .ok_or_else(|| H3oError::ResolutionLimitReached {
-                        zone_id: h3o_zone.to_string(),
-                    })?;
-
-                let chr_vec: Vec<ZoneId> = h3o_zone
-                    .children(chr_res)
-                    .map(|c| ZoneId::new_hex(&c.to_string()))
-                    .collect::<Result<_, _>>()?; // NOTE: In Result<_ , _>> the _ means that the T and E are inferred. 
-                Some(chr_vec)
-            } else {
-                None
-            };
-
-            let neighbors = if conf.neighbors {
-                let nbr: Vec<ZoneId> = h3o_zone
-                    .grid_disk::<Vec<CellIndex>>(1)
-                    .into_iter()
-                    .map(|c| ZoneId::new_hex(&c.to_string()))
-                    .collect::<Result<_, _>>()?; // NOTE: In Result<_ , _>> the _ means that the T and E are inferred. 
-                Some(nbr)
-            } else {
-                None
-            };
-
-            Ok(Zone {
-                id,
-                region,
-                center,
-                vertex_count,
-                children,
-                neighbors,
-                area_sqm,
-            })
-        })
+        .map(|h3o_zone| to_zone(h3o_zone, conf))
         .collect::<Result<Vec<Zone>, DggrsError>>()?;
 
     Ok(Zones { zones })
 }
+
+/// Generates a single zone with the outputs requested in the config
+pub fn to_zone(h3o_zone: CellIndex, conf: DggrsApiConfig) -> Result<Zone, DggrsError> {
+    let (region, area_sqm, vertex_count) = zone_region(h3o_zone, conf)?;
+    Ok(Zone {
+        id: to_zone_id(h3o_zone, conf.preferred_id_format)?,
+        region,
+        center: zone_center(h3o_zone, conf),
+        vertex_count,
+        children: zone_children(h3o_zone, conf)?,
+        neighbors: zone_neighbors(h3o_zone, conf)?,
+        area_sqm,
+    })
+}
+
+/// Pushes the outputs requested in the config into a sink, without assembling a `Zone`
+pub fn push_zone(
+    sink: &mut dyn ZoneSink,
+    refinement_level: RefinementLevel,
+    h3o_zone: CellIndex,
+    conf: DggrsApiConfig,
+) -> Result<(), DggrsError> {
+    conf.validate()?;
+    let id = to_zone_id(h3o_zone, conf.preferred_id_format)?;
+    let (region, area_sqm, vertex_count) = zone_region(h3o_zone, conf)?;
+    let children = zone_children(h3o_zone, conf)?;
+    let neighbors = zone_neighbors(h3o_zone, conf)?;
+    sink.push_zone(
+        refinement_level,
+        ZoneParts {
+            id: &id,
+            region: region.as_ref(),
+            center: zone_center(h3o_zone, conf),
+            vertex_count,
+            area_sqm,
+            neighbors: neighbors.as_deref(),
+            children: children.as_deref(),
+        },
+    )
+}
+
+fn zone_center(h3o_zone: CellIndex, conf: DggrsApiConfig) -> Option<Point> {
+    conf.center.then(|| latlng_to_point(LatLng::from(h3o_zone)))
+}
+
+/// A region with its area and vertex count
+type RegionParts = (Option<Region>, Option<f64>, Option<u32>);
+
+/// The region with its area and vertex count, both taken before densification
+fn zone_region(h3o_zone: CellIndex, conf: DggrsApiConfig) -> Result<RegionParts, DggrsError> {
+    let region = if conf.region || conf.area_sqm || conf.vertex_count {
+        let boundary = h3o_zone.boundary();
+        Some(boundary_to_polygon(&boundary))
+    } else {
+        None
+    };
+
+    let area_sqm = if conf.area_sqm {
+        region.as_ref().map(|r| r.geodesic_area()) // NOTE: It is also an option to use the build in area function of H3o
+    } else {
+        None
+    };
+
+    let vertex_count = if conf.vertex_count {
+        region.as_ref().map(|r| r.coords_count() as u32) // NOTE: It is also an option to use the build-in vertex function of H3o
+    } else {
+        None
+    };
+
+    // NOTE: H3 edges are great circles, the densified vertices lie on the true edges
+    let region = region
+        .filter(|_| conf.region)
+        .map(|r| densify(r, conf.densification))
+        .transpose()?
+        .map(|r| normalize(r, conf.geometry));
+    Ok((region, area_sqm, vertex_count))
+}
+
+fn zone_children(
+    h3o_zone: CellIndex,
+    conf: DggrsApiConfig,
+) -> Result<Option<Vec<ZoneId>>, DggrsError> {
+    if !conf.children {
+        return Ok(None);
+    }
+    //FIX: don't prodcuce any children if max_refinement_level has been reached
+    let chr_res = h3o_zone
+        .resolution()
+        .succ() // NOTE: succ() returns an Option, therefore we can use ok_or_else in the next line and not map_err
+        .ok_or_else(|| H3oError::ResolutionLimitReached {
+            zone_id: h3o_zone.to_string(),
+        })?;
+
+    let chr_vec: Vec<ZoneId> = h3o_zone
+        .children(chr_res)
+        .map(|c| to_zone_id(c, conf.preferred_id_format))
+        .collect::<Result<_, _>>()?; // NOTE: In Result<_ , _>> the _ means that the T and E are inferred. 
+    Ok(Some(chr_vec))
+}
+
+fn zone_neighbors(
+    h3o_zone: CellIndex,
+    conf: DggrsApiConfig,
+) -> Result<Option<Vec<ZoneId>>, DggrsError> {
+    if !conf.neighbors {
+        return Ok(None);
+    }
+    let nbr: Vec<ZoneId> = h3o_zone
+        .grid_disk::<Vec<CellIndex>>(1)
+        .into_iter()
+        .map(|c| to_zone_id(c, conf.preferred_id_format))
+        .collect::<Result<_, _>>()?; // NOTE: In Result<_ , _>> the _ means that the T and E are inferred. 
+    Ok(Some(nbr))
+}
@@ -7,16 +7,21 @@
 // discretion. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::adapters::h3o::common::{refinement_level_to_h3_resolution, to_zones};
+use crate::adapters::h3o::common::{
+    push_zone, refinement_level_to_h3_resolution, to_cell_index, to_zone_id, to_zone_info, to_zones,
+};
 use crate::adapters::h3o::h3o::H3oAdapter;
-use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::api::{DggrsApi, DggrsApiConfig, ZoneSink};
 use crate::error::DggrsError;
 use crate::error::h3o::H3oError;
-use crate::types::{BoundingBox, DggrsUid, Point, RefinementLevel, RelativeDepth, ZoneId, Zones};
+use crate::types::{
+    BoundingBox, DggrsUid, HierarchyKey, Point, RefinementLevel, RelativeDepth, ZoneId,
+    ZoneIdFormat, ZoneInfo, Zones,
+};
 use geo::{Rect, coord};
 use h3o::geom::{ContainmentMode, TilerBuilder};
-use h3o::{CellIndex, LatLng};
-use std::str::FromStr;
+use h3o::{CellIndex, LatLng, Resolution};
+use std::collections::HashSet;
 
 pub struct H3Impl {
     id: DggrsUid,
@@ -41,14 +46,33 @@ impl Default for H3Impl {
     }
 }
 
-impl DggrsApi for H3Impl {
-    fn zones_from_bbox(
+impl H3Impl {
+    /// Refinement level of the sub-zones of `parent` at `relative_depth`, checked against the H3 limits.
+    fn sub_zone_level(
+        &self,
+        parent: CellIndex,
+        relative_depth: RelativeDepth,
+    ) -> Result<RefinementLevel, DggrsError> {
+        let target_level = RefinementLevel::new(parent.resolution() as i32)?.add(relative_depth)?;
+
+        if target_level > self.max_refinement_level()? {
+            return Err(DggrsError::RefinementLevelPlusRelativeDepthLimitReached {
+                grid_name: self.id.spec().name.to_string(),
+                requested: relative_depth,
+                maximum: self.max_refinement_level()?,
+            });
+        }
+        Ok(target_level)
+    }
+
+    /// The cells covering the bounding box, or all cells if no bbox is supplied
+    ///
+    /// A box across the antimeridian is tiled as two boxes, cells covering both are returned once.
+    fn cells_from_bbox(
         &self,
         refinement_level: RefinementLevel,
         bbox: Option<BoundingBox>,
-        config: Option<DggrsApiConfig>,
-    ) -> Result<Zones, DggrsError> {
-        let cfg = config.unwrap_or_default();
+    ) -> Result<Vec<CellIndex>, DggrsError> {
         let h3o_zones: Vec<CellIndex>;
 
         let mut tiler = TilerBuilder::new(refinement_level_to_h3_resolution(refinement_level)?)
@@ -56,13 +80,29 @@ impl DggrsApi for H3Impl {
             .build();
 
         if let Some(b) = bbox {
+            b.validate()?;
             // NOTE: adapt resolution dynamically based on bbox size & depth
-            let rect = Rect::new(
-                coord! { x: b.min_lon, y: b.min_lat },
-                coord! { x: b.max_lon, y: b.max_lat },
-            );
-            let _ = tiler.add(rect.to_polygon());
-            h3o_zones = tiler.into_coverage().collect::<Vec<_>>();
+            for part in b.split_antimeridian() {
+                // NOTE: h3o takes polygons wider than 180 degrees for transmeridian ones, so wide boxes are tiled in halves
+                let mid_lon = (part.min_lon + part.max_lon) / 2.0;
+                let halves = if part.max_lon - part.min_lon > 180.0 {
+                    vec![(part.min_lon, mid_lon), (mid_lon, part.max_lon)]
+                } else {
+                    vec![(part.min_lon, part.max_lon)]
+                };
+                for (min_lon, max_lon) in halves {
+                    let rect = Rect::new(
+                        coord! { x: min_lon, y: part.min_lat },
+                        coord! { x: max_lon, y: part.max_lat },
+                    );
+                    let _ = tiler.add(rect.to_polygon());
+                }
+            }
+            let mut seen = HashSet::new();
+            h3o_zones = tiler
+                .into_coverage()
+                .filter(|cell| seen.insert(*cell))
+                .collect::<Vec<_>>();
         } else {
             if refinement_level > self.max_refinement_level()? {
                 return Err(DggrsError::RefinementLevelTooHigh(refinement_level));
@@ -76,8 +116,36 @@ impl DggrsApi for H3Impl {
                 })
                 .collect::<Vec<_>>();
         }
+        Ok(h3o_zones)
+    }
+}
+
+impl DggrsApi for H3Impl {
+    fn zones_from_bbox(
+        &self,
+        refinement_level: RefinementLevel,
+        bbox: Option<BoundingBox>,
+        config: Option<DggrsApiConfig>,
+    ) -> Result<Zones, DggrsError> {
+        let cfg = config.unwrap_or_default();
+        let h3o_zones = self.cells_from_bbox(refinement_level, bbox)?;
         Ok(to_zones(h3o_zones, cfg)?)
     }
+
+    fn zones_from_bbox_into(
+        &self,
+        refinement_level: RefinementLevel,
+        bbox: Option<BoundingBox>,
+        config: Option<DggrsApiConfig>,
+        sink: &mut dyn ZoneSink,
+    ) -> Result<(), DggrsError> {
+        let cfg = config.unwrap_or_default();
+        for h3o_zone in self.cells_from_bbox(refinement_level, bbox)? {
+            push_zone(sink, refinement_level, h3o_zone, cfg)?;
+        }
+        Ok(())
+    }
+
     fn zone_from_point(
         &self,
         refinement_level: RefinementLevel,
@@ -91,6 +159,27 @@ impl DggrsApi for H3Impl {
 
         Ok(to_zones(vec![h3o_zone], cfg)?)
     }
+
+    fn zone_ids_from_points(
+        &self,
+        refinement_level: RefinementLevel,
+        points: &[Point],
+        format: ZoneIdFormat,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let resolution = refinement_level_to_h3_resolution(refinement_level)?;
+        points
+            .iter()
+            .map(|point| {
+                let coord =
+                    LatLng::new(point.lat, point.lon).map_err(|_| H3oError::InvalidCoordinate {
+                        lat: point.lat,
+                        lon: point.lon,
+                    })?;
+                to_zone_id(coord.to_cell(resolution), format)
+            })
+            .collect()
+    }
+
     fn zones_from_parent(
         &self,
         relative_depth: RelativeDepth,
@@ -98,22 +187,8 @@ impl DggrsApi for H3Impl {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError> {
         let cfg = config.unwrap_or_default();
-        let parent = CellIndex::from_str(&parent_zone_id.to_string()).map_err(|e| {
-            DggrsError::H3o(H3oError::InvalidZoneID {
-                zone_id: parent_zone_id.to_string(),
-                source: e,
-            })
-        })?;
-
-        let target_level = RefinementLevel::new(parent.resolution() as i32)?.add(relative_depth)?;
-
-        if target_level > self.max_refinement_level()? {
-            return Err(DggrsError::RefinementLevelPlusRelativeDepthLimitReached {
-                grid_name: self.id.spec().name.to_string(),
-                requested: relative_depth,
-                maximum: self.max_refinement_level()?,
-            });
-        }
+        let parent = to_cell_index(&parent_zone_id)?;
+        let target_level = self.sub_zone_level(parent, relative_depth)?;
 
         let h3o_sub_zones: Vec<CellIndex> = parent
             .children(refinement_level_to_h3_resolution(target_level)?)
@@ -128,12 +203,7 @@ impl DggrsApi for H3Impl {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError> {
         let cfg = config.unwrap_or_default();
-        let h3o_zone = CellIndex::from_str(&zone_id.to_string()).map_err(|e| {
-            DggrsError::H3o(H3oError::InvalidZoneID {
-                zone_id: zone_id.to_string(),
-                source: e,
-            })
-        })?;
+        let h3o_zone = to_cell_index(&zone_id)?;
 
         let refinement_level = RefinementLevel::new(h3o_zone.resolution() as i32)?;
         if refinement_level <= self.min_refinement_level()? {
@@ -156,18 +226,123 @@ impl DggrsApi for H3Impl {
 
     fn zone_from_id(
         &self,
-        zone_id: ZoneId, // ToDo: needs validation function
+        zone_id: ZoneId,
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError> {
         let cfg = config.unwrap_or_default();
-        let h3o_zone = CellIndex::from_str(&zone_id.to_string()).map_err(|e| {
-            DggrsError::H3o(H3oError::InvalidZoneID {
+        let h3o_zone = to_cell_index(&zone_id)?;
+
+        Ok(to_zones(vec![h3o_zone], cfg)?)
+    }
+
+    fn parse_zone_id(&self, zone_id: &str) -> Result<ZoneId, DggrsError> {
+        let cell = to_cell_index(&ZoneId::new_str(zone_id.trim())?)?;
+        to_zone_id(cell, ZoneIdFormat::Text)
+    }
+
+    fn format_zone_id(&self, zone_id: &ZoneId, format: ZoneIdFormat) -> Result<ZoneId, DggrsError> {
+        to_zone_id(to_cell_index(zone_id)?, format)
+    }
+
+    fn validate_zone(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+        // NOTE: h3o validates the index bits, including the deleted sub-sequences of pentagons
+        let cell = to_cell_index(zone_id)?;
+        to_zone_info(cell, zone_id, false)
+    }
+
+    fn ancestor_at_level(
+        &self,
+        zone_id: &ZoneId,
+        refinement_level: RefinementLevel,
+    ) -> Result<ZoneId, DggrsError> {
+        let cell = to_cell_index(zone_id)?;
+        let ancestor = cell
+            .parent(refinement_level_to_h3_resolution(refinement_level)?)
+            .ok_or_else(|| DggrsError::AncestorLevelTooFine {
                 zone_id: zone_id.to_string(),
-                source: e,
+                requested: refinement_level,
+                level: RefinementLevel::from(u8::from(cell.resolution())),
+            })?;
+        to_zone_id(ancestor, zone_id.format())
+    }
+
+    fn zone_info(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError> {
+        let cell = to_cell_index(zone_id)?;
+        to_zone_info(cell, zone_id, true)
+    }
+
+    fn sub_zone_ids(
+        &self,
+        relative_depth: RelativeDepth,
+        parent_zone_id: ZoneId,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let parent = to_cell_index(&parent_zone_id)?;
+        let target_level = self.sub_zone_level(parent, relative_depth)?;
+
+        // NOTE: h3o yields the children in ascending index order, which is the order used by child_position()
+        parent
+            .children(refinement_level_to_h3_resolution(target_level)?)
+            .map(|c| to_zone_id(c, ZoneIdFormat::Text))
+            .collect()
+    }
+
+    fn sub_zone_from_index(
+        &self,
+        relative_depth: RelativeDepth,
+        parent_zone_id: ZoneId,
+        index: u64,
+    ) -> Result<ZoneId, DggrsError> {
+        let parent = to_cell_index(&parent_zone_id)?;
+        let target_level = self.sub_zone_level(parent, relative_depth)?;
+
+        let child = parent
+            .child_at(index, refinement_level_to_h3_resolution(target_level)?)
+            .ok_or_else(|| {
+                DggrsError::H3o(H3oError::SubZoneIndexOutOfRange {
+                    parent_id: parent_zone_id.to_string(),
+                    index,
+                })
+            })?;
+
+        to_zone_id(child, ZoneIdFormat::Text)
+    }
+
+    fn sub_zone_index(&self, ancestor_zone_id: ZoneId, zone_id: ZoneId) -> Result<u64, DggrsError> {
+        let ancestor = to_cell_index(&ancestor_zone_id)?;
+        let zone = to_cell_index(&zone_id)?;
+
+        let not_a_sub_zone = || {
+            DggrsError::H3o(H3oError::NotASubZone {
+                zone_id: zone_id.to_string(),
+                ancestor_id: ancestor_zone_id.to_string(),
             })
-        })?;
+        };
 
-        Ok(to_zones(vec![h3o_zone], cfg)?)
+        if zone.parent(ancestor.resolution()) != Some(ancestor) {
+            return Err(not_a_sub_zone());
+        }
+
+        zone.child_position(ancestor.resolution())
+            .ok_or_else(not_a_sub_zone)
+    }
+
+    fn hierarchy_key(&self, zone_id: &ZoneId) -> Result<HierarchyKey, DggrsError> {
+        let cell = to_cell_index(zone_id)?;
+
+        // NOTE: the digits of the index bits ascend in the same order as the children, the deleted digit of pentagons is skipped
+        let digits = Resolution::range(Resolution::One, cell.resolution())
+            .filter_map(|resolution| cell.direction_at(resolution))
+            .map(|direction| u64::from(u8::from(direction)))
+            .collect();
+        let root = cell.parent(Resolution::Zero).unwrap_or(cell);
+        Ok(HierarchyKey {
+            root_zone: to_zone_id(root, zone_id.format())?,
+            digits,
+        })
+    }
+
+    fn uid(&self) -> DggrsUid {
+        self.id
     }
 
     fn zone_count(&self, level: RefinementLevel) -> Result<u64, DggrsError> {
@@ -8,7 +8,11 @@
 // except according to those terms.
 
 use crate::error::DggrsError;
-use crate::types::{BoundingBox, Point, RefinementLevel, RelativeDepth, ZoneId, Zones};
+use crate::geometry::{Densification, GeometryMode};
+use crate::types::{
+    BoundingBox, DggrsUid, HierarchyKey, Point, RefinementLevel, Region, RelativeDepth, Zone,
+    ZoneId, ZoneIdFormat, ZoneInfo, Zones,
+};
 
 /// Addresses all the configuration options that apply to all port functions
 ///
@@ -21,9 +25,17 @@ use crate::types::{BoundingBox, Point, RefinementLevel, RelativeDepth, ZoneId, Z
 /// - children (list of ZoneIds)
 /// - neighbors (list of ZoneIds)
 /// - area_sqm (the area in squaremeter as calculated by `geo`'s geodesic_area_unsigned() function
-/// - densify (region geometry densification)
+/// - densification (how many vertices are inserted into the region edges, see `geometry::Densification`)
+/// - geometry (how regions at the antimeridian and the poles are represented, see `geometry::GeometryMode`)
+/// - preferred_id_format (textual or 64-bit integer ZoneIds for the zone, its children and neighbors)
 ///
+/// With the `serde` feature, missing fields are deserialised with their default value.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct DggrsApiConfig {
     pub region: bool,
     pub center: bool,
@@ -31,7 +43,9 @@ pub struct DggrsApiConfig {
     pub children: bool,
     pub neighbors: bool,
     pub area_sqm: bool,
-    pub densify: bool, // TODO:: this is the switch to generate densified gemetry, which is actually not needed for H3 due to the Gnomic projection.
+    pub densification: Densification,
+    pub geometry: GeometryMode,
+    pub preferred_id_format: ZoneIdFormat,
 }
 
 impl Default for DggrsApiConfig {
@@ -43,11 +57,70 @@ impl Default for DggrsApiConfig {
             children: true,
             neighbors: true,
             area_sqm: true,
-            densify: true,
+            densification: Densification::default(),
+            geometry: GeometryMode::Split,
+            preferred_id_format: ZoneIdFormat::Text,
         }
     }
 }
 
+impl DggrsApiConfig {
+    /// Only the textual ZoneIds are returned, without geometry, area or related zones.
+    pub fn ids_only() -> Self {
+        Self {
+            region: false,
+            center: false,
+            vertex_count: false,
+            children: false,
+            neighbors: false,
+            area_sqm: false,
+            densification: Densification::None,
+            geometry: GeometryMode::Split,
+            preferred_id_format: ZoneIdFormat::Text,
+        }
+    }
+
+    /// Rejects configs the adapters cannot honour, checked by the shared helpers that build the zones.
+    pub fn validate(&self) -> Result<(), DggrsError> {
+        self.densification.validate()
+    }
+}
+
+/// The outputs of one zone as pushed into a `ZoneSink`, borrowed from the adapter so that no `Zone` has to be assembled.
+#[derive(Debug, Clone, Copy)]
+pub struct ZoneParts<'a> {
+    pub id: &'a ZoneId,
+    pub region: Option<&'a Region>,
+    pub center: Option<Point>,
+    pub vertex_count: Option<u32>,
+    pub area_sqm: Option<f64>,
+    pub neighbors: Option<&'a [ZoneId]>,
+    pub children: Option<&'a [ZoneId]>,
+}
+
+impl<'a> From<&'a Zone> for ZoneParts<'a> {
+    fn from(zone: &'a Zone) -> Self {
+        Self {
+            id: &zone.id,
+            region: zone.region.as_ref(),
+            center: zone.center,
+            vertex_count: zone.vertex_count,
+            area_sqm: zone.area_sqm,
+            neighbors: zone.neighbors.as_deref(),
+            children: zone.children.as_deref(),
+        }
+    }
+}
+
+/// Receives zones one by one while an adapter generates them, e.g. to fill columnar builders without collecting `Zones` first.
+pub trait ZoneSink {
+    fn push_zone(
+        &mut self,
+        refinement_level: RefinementLevel,
+        zone: ZoneParts<'_>,
+    ) -> Result<(), DggrsError>;
+}
+
 /// The DGGRS port trait. Each adapter can only implement the functions defined here.
 pub trait DggrsApi: Send + Sync {
     /// Get zones in the bounding box. If no bbox is supplied the whole world is taken.
@@ -58,6 +131,22 @@ pub trait DggrsApi: Send + Sync {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError>;
 
+    /// Same as `zones_from_bbox`, but the zones are pushed into a sink as they are generated.
+    ///
+    /// By default the zones are generated with `zones_from_bbox` first, adapters that generate zones one by one override this.
+    fn zones_from_bbox_into(
+        &self,
+        refinement_level: RefinementLevel,
+        bbox: Option<BoundingBox>,
+        config: Option<DggrsApiConfig>,
+        sink: &mut dyn ZoneSink,
+    ) -> Result<(), DggrsError> {
+        for zone in &self.zones_from_bbox(refinement_level, bbox, config)?.zones {
+            sink.push_zone(refinement_level, zone.into())?;
+        }
+        Ok(())
+    }
+
     /// Get zones for a Point.
     fn zone_from_point(
         &self,
@@ -66,6 +155,32 @@ pub trait DggrsApi: Send + Sync {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError>;
 
+    /// Get the ZoneIDs of the zones containing many points, in the order of the points.
+    ///
+    /// No zone geometry is computed. By default `zone_from_point` is called for every point, adapters that locate points in-process override this to skip the per-point overhead.
+    fn zone_ids_from_points(
+        &self,
+        refinement_level: RefinementLevel,
+        points: &[Point],
+        format: ZoneIdFormat,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        let config = DggrsApiConfig {
+            preferred_id_format: format,
+            ..DggrsApiConfig::ids_only()
+        };
+        points
+            .iter()
+            .map(|&point| {
+                self.zone_from_point(refinement_level, point, Some(config))?
+                    .zones
+                    .into_iter()
+                    .next()
+                    .map(|zone| zone.id)
+                    .ok_or(DggrsError::NoZoneAtPoint(point))
+            })
+            .collect()
+    }
+
     /// Get zones based on a parent ZoneID.
     fn zones_from_parent(
         &self,
@@ -90,6 +205,113 @@ pub trait DggrsApi: Send + Sync {
         config: Option<DggrsApiConfig>,
     ) -> Result<Zones, DggrsError>; // NOTE: Consider accepting a vector of ZoneIDs
 
+    /// Parse the canonical textual ZoneID of the DGGRS.
+    ///
+    /// In contrast to `ZoneId::from_str` the syntax of the DGGRS is used, so the result is always a textual ZoneId (e.g. a `ZoneId::HexId` for H3). Use `format_zone_id` to get the 64-bit integer form.
+    fn parse_zone_id(&self, zone_id: &str) -> Result<ZoneId, DggrsError>;
+
+    /// Convert a ZoneID of this DGGRS into the requested format.
+    ///
+    /// Both the textual and the integer form are accepted as input and converting back and forth is lossless.
+    fn format_zone_id(&self, zone_id: &ZoneId, format: ZoneIdFormat) -> Result<ZoneId, DggrsError>;
+
+    /// Validate a ZoneID without computing any geometry.
+    ///
+    /// The syntax, the refinement level bounds and the existence of the zone in the DGGRS are checked, e.g. the Z7 padding digits of IGEO7 or the deleted sub-sequences of H3 pentagons. The returned `ZoneInfo` has no `center`.
+    fn validate_zone(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError>;
+
+    /// Get the refinement level, root zone, vertex count, pentagon flag, primary parent and centroid of a zone.
+    ///
+    /// This is computed from the ZoneID wherever the DGGRS allows it and is much cheaper than `zone_from_id`.
+    fn zone_info(&self, zone_id: &ZoneId) -> Result<ZoneInfo, DggrsError>;
+
+    /// Get the ancestor of a zone at a coarser refinement level by following the primary parents, the zone itself is returned at its own level.
+    fn ancestor_at_level(
+        &self,
+        zone_id: &ZoneId,
+        refinement_level: RefinementLevel,
+    ) -> Result<ZoneId, DggrsError> {
+        let mut info = self.validate_zone(zone_id)?;
+        if refinement_level > info.refinement_level {
+            return Err(DggrsError::AncestorLevelTooFine {
+                zone_id: zone_id.to_string(),
+                requested: refinement_level,
+                level: info.refinement_level,
+            });
+        }
+
+        while info.refinement_level > refinement_level {
+            match info.primary_parent {
+                Some(parent) => info = self.validate_zone(&parent)?,
+                None => break,
+            }
+        }
+        Ok(info.id)
+    }
+
+    /// Check whether a ZoneID is valid, see `validate_zone`.
+    fn is_valid_zone(&self, zone_id: &ZoneId) -> bool {
+        self.validate_zone(zone_id).is_ok()
+    }
+
+    /// Get the ZoneIDs of the sub-zones of a parent zone in the canonical order of the DGGRS.
+    ///
+    /// The position of a ZoneID in the returned vector is its sub-zone index, which is what OGC DGGS-JSON uses to encode one value array per parent zone. Unlike `zones_from_parent` no geometry is computed.
+    ///
+    /// The sub-zones are those of the primary-parent hierarchy, e.g. the Z3 and Z7 digits of DGGRID. In aperture 3 DGGRSs they differ from `zones_from_parent`, which also returns the sub-zones shared with the neighbouring parents.
+    fn sub_zone_ids(
+        &self,
+        _relative_depth: RelativeDepth,
+        _parent_zone_id: ZoneId,
+    ) -> Result<Vec<ZoneId>, DggrsError> {
+        Err(DggrsError::UnsupportedOperation("sub_zone_ids".to_string()))
+    }
+
+    /// Get the sub-zone at a given index within the ordered sub-zones of a parent zone.
+    fn sub_zone_from_index(
+        &self,
+        _relative_depth: RelativeDepth,
+        _parent_zone_id: ZoneId,
+        _index: u64,
+    ) -> Result<ZoneId, DggrsError> {
+        Err(DggrsError::UnsupportedOperation(
+            "sub_zone_from_index".to_string(),
+        ))
+    }
+
+    /// Get the index of a zone within the ordered sub-zones of one of its ancestors.
+    ///
+    /// The relative depth is the difference between the refinement level of the zone and that of the ancestor.
+    fn sub_zone_index(
+        &self,
+        _ancestor_zone_id: ZoneId,
+        _zone_id: ZoneId,
+    ) -> Result<u64, DggrsError> {
+        Err(DggrsError::UnsupportedOperation(
+            "sub_zone_index".to_string(),
+        ))
+    }
+
+    /// Get the position of a zone in the hierarchy of the DGGRS, see `HierarchyKey`.
+    ///
+    /// By default the primary parents are followed to the root zone with `validate_zone` and the digits are the `sub_zone_index` of every zone within its primary parent. Adapters with hierarchical ZoneIds read the digits from the ZoneId.
+    fn hierarchy_key(&self, zone_id: &ZoneId) -> Result<HierarchyKey, DggrsError> {
+        let mut info = self.validate_zone(zone_id)?;
+        let mut digits = Vec::new();
+        while let Some(parent) = info.primary_parent.take() {
+            digits.push(self.sub_zone_index(parent.clone(), info.id)?);
+            info = self.validate_zone(&parent)?;
+        }
+        digits.reverse();
+        Ok(HierarchyKey {
+            root_zone: info.id,
+            digits,
+        })
+    }
+
+    /// The ID of the DGGRS of this adapter, its specification is available through `DggrsUid::spec`.
+    fn uid(&self) -> DggrsUid;
+
     /// Get the total number of zones at a refinement level.
     fn zone_count(&self, refinement_level: RefinementLevel) -> Result<u64, DggrsError>; // TODO: Consider hard coding zone count statistics instead of calculating them on the fly
 
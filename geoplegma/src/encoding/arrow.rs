@@ -0,0 +1,466 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Apache Arrow encoding of zones, with GeoArrow geometry columns.
+//!
+//! A record batch of zones has the columns
+//! - `id`: `Utf8` for textual ZoneIds or `UInt64` for integer ZoneIds
+//! - `level`: `Int32` refinement level
+//! - `region`: `geoarrow.multipolygon` with separated `x`/`y` coordinates, a polygon region is a multipolygon with one part
+//! - `center`: `geoarrow.point` with separated `x`/`y` coordinates
+//! - `vertex_count`: `UInt32`
+//! - `area_sqm`: `Float64`
+//! - `neighbors` and `children`: lists of ZoneIds of the same type as `id`
+//!
+//! Outputs that were not requested in the `DggrsApiConfig` are null.
+
+use crate::api::{DggrsApi, ZoneParts, ZoneSink};
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::types::{Point, Polygon, RefinementLevel, Region, Zone, ZoneId, ZoneIdFormat, Zones};
+use arrow_array::builder::{
+    ArrayBuilder, Float64Builder, Int32Builder, ListBuilder, StringBuilder, StructBuilder,
+    UInt32Builder, UInt64Builder,
+};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, UInt32Type, UInt64Type};
+use arrow_array::{Array, ArrayRef, RecordBatch, StructArray};
+use arrow_schema::{DataType, Field, Fields, Schema};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const FORMAT: &str = "Arrow";
+
+const GEOARROW_CRS: &str = r#"{"crs":"OGC:CRS84","crs_type":"authority_code"}"#;
+
+fn coord_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("x", DataType::Float64, false),
+        Field::new("y", DataType::Float64, false),
+    ])
+}
+
+fn coord_builder() -> StructBuilder {
+    StructBuilder::new(
+        coord_fields(),
+        vec![
+            Box::new(Float64Builder::new()) as Box<dyn ArrayBuilder>,
+            Box::new(Float64Builder::new()),
+        ],
+    )
+}
+
+/// Appends a coordinate, a missing point is appended as null with placeholder values
+fn append_coord(builder: &mut StructBuilder, point: Option<Point>) {
+    let (x, y) = point.map_or((0.0, 0.0), |p| (p.lon, p.lat));
+    builder
+        .field_builder::<Float64Builder>(0)
+        .expect("x is the first coordinate field")
+        .append_value(x);
+    builder
+        .field_builder::<Float64Builder>(1)
+        .expect("y is the second coordinate field")
+        .append_value(y);
+    builder.append(point.is_some());
+}
+
+enum IdBuilder {
+    Text(StringBuilder),
+    Int(UInt64Builder),
+}
+
+impl IdBuilder {
+    fn new(format: ZoneIdFormat) -> Self {
+        match format {
+            ZoneIdFormat::Text => IdBuilder::Text(StringBuilder::new()),
+            ZoneIdFormat::Int => IdBuilder::Int(UInt64Builder::new()),
+        }
+    }
+
+    fn append(&mut self, zone_id: &ZoneId) -> Result<(), DggrsError> {
+        match self {
+            IdBuilder::Text(b) => b.append_value(zone_id.to_string()),
+            IdBuilder::Int(b) => b.append_value(zone_id.as_u64().ok_or_else(|| {
+                EncodingError::invalid(FORMAT, format!("{zone_id} is not an integer ZoneId"))
+            })?),
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            IdBuilder::Text(b) => Arc::new(b.finish()),
+            IdBuilder::Int(b) => Arc::new(b.finish()),
+        }
+    }
+}
+
+enum IdListBuilder {
+    Text(ListBuilder<StringBuilder>),
+    Int(ListBuilder<UInt64Builder>),
+}
+
+impl IdListBuilder {
+    fn new(format: ZoneIdFormat) -> Self {
+        match format {
+            ZoneIdFormat::Text => IdListBuilder::Text(ListBuilder::new(StringBuilder::new())),
+            ZoneIdFormat::Int => IdListBuilder::Int(ListBuilder::new(UInt64Builder::new())),
+        }
+    }
+
+    fn append(&mut self, zone_ids: Option<&[ZoneId]>) -> Result<(), DggrsError> {
+        match self {
+            IdListBuilder::Text(b) => {
+                for zone_id in zone_ids.into_iter().flatten() {
+                    b.values().append_value(zone_id.to_string());
+                }
+                b.append(zone_ids.is_some());
+            }
+            IdListBuilder::Int(b) => {
+                for zone_id in zone_ids.into_iter().flatten() {
+                    b.values().append_value(zone_id.as_u64().ok_or_else(|| {
+                        EncodingError::invalid(
+                            FORMAT,
+                            format!("{zone_id} is not an integer ZoneId"),
+                        )
+                    })?);
+                }
+                b.append(zone_ids.is_some());
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            IdListBuilder::Text(b) => Arc::new(b.finish()),
+            IdListBuilder::Int(b) => Arc::new(b.finish()),
+        }
+    }
+}
+
+/// Builds a record batch of zones column by column.
+///
+/// The builder is a `ZoneSink`, so it can be filled directly by `DggrsApi::zones_from_bbox_into`. Its ZoneId format must match the `preferred_id_format` of the config.
+pub struct ZoneBatchBuilder {
+    ids: IdBuilder,
+    levels: Int32Builder,
+    regions: ListBuilder<ListBuilder<ListBuilder<StructBuilder>>>,
+    centers: StructBuilder,
+    vertex_counts: UInt32Builder,
+    areas: Float64Builder,
+    neighbors: IdListBuilder,
+    children: IdListBuilder,
+}
+
+impl ZoneBatchBuilder {
+    pub fn new(format: ZoneIdFormat) -> Self {
+        let vertices = Field::new("vertices", DataType::Struct(coord_fields()), false);
+        let rings = Field::new("rings", DataType::List(Arc::new(vertices.clone())), false);
+        let polygons = Field::new("polygons", DataType::List(Arc::new(rings.clone())), false);
+
+        Self {
+            ids: IdBuilder::new(format),
+            levels: Int32Builder::new(),
+            regions: ListBuilder::new(
+                ListBuilder::new(ListBuilder::new(coord_builder()).with_field(vertices))
+                    .with_field(rings),
+            )
+            .with_field(polygons),
+            centers: coord_builder(),
+            vertex_counts: UInt32Builder::new(),
+            areas: Float64Builder::new(),
+            neighbors: IdListBuilder::new(format),
+            children: IdListBuilder::new(format),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn append(
+        &mut self,
+        refinement_level: RefinementLevel,
+        zone: &Zone,
+    ) -> Result<(), DggrsError> {
+        self.append_parts(refinement_level, zone.into())
+    }
+
+    /// Same as `append`, for the borrowed outputs of a zone.
+    pub fn append_parts(
+        &mut self,
+        refinement_level: RefinementLevel,
+        zone: ZoneParts<'_>,
+    ) -> Result<(), DggrsError> {
+        self.ids.append(zone.id)?;
+        self.levels.append_value(refinement_level.get());
+
+        match zone.region {
+            Some(region) => {
+                let polygons = self.regions.values();
+                for polygon in region.polygons() {
+                    let rings = polygons.values();
+                    for ring in std::iter::once(&polygon.exterior).chain(&polygon.interiors) {
+                        for point in ring {
+                            append_coord(rings.values(), Some(*point));
+                        }
+                        rings.append(true);
+                    }
+                    polygons.append(true);
+                }
+                self.regions.append(true);
+            }
+            None => self.regions.append(false),
+        }
+
+        append_coord(&mut self.centers, zone.center);
+        self.vertex_counts.append_option(zone.vertex_count);
+        self.areas.append_option(zone.area_sqm);
+        self.neighbors.append(zone.neighbors)?;
+        self.children.append(zone.children)?;
+        Ok(())
+    }
+
+    /// Finish the columns into a record batch, the builder is empty afterwards.
+    pub fn finish(&mut self) -> Result<RecordBatch, DggrsError> {
+        let geoarrow = |name: &str| {
+            HashMap::from([
+                ("ARROW:extension:name".to_string(), name.to_string()),
+                (
+                    "ARROW:extension:metadata".to_string(),
+                    GEOARROW_CRS.to_string(),
+                ),
+            ])
+        };
+
+        let column = |name: &str, array: ArrayRef, nullable: bool| {
+            (Field::new(name, array.data_type().clone(), nullable), array)
+        };
+
+        let (fields, arrays): (Vec<Field>, Vec<ArrayRef>) = [
+            column("id", self.ids.finish(), false),
+            column("level", Arc::new(self.levels.finish()), false),
+            column("region", Arc::new(self.regions.finish()), true),
+            column("center", Arc::new(self.centers.finish()), true),
+            column("vertex_count", Arc::new(self.vertex_counts.finish()), true),
+            column("area_sqm", Arc::new(self.areas.finish()), true),
+            column("neighbors", self.neighbors.finish(), true),
+            column("children", self.children.finish(), true),
+        ]
+        .into_iter()
+        .map(|(field, array)| match field.name().as_str() {
+            "region" => (
+                field.with_metadata(geoarrow("geoarrow.multipolygon")),
+                array,
+            ),
+            "center" => (field.with_metadata(geoarrow("geoarrow.point")), array),
+            _ => (field, array),
+        })
+        .unzip();
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+            .map_err(|e| EncodingError::from(e).into())
+    }
+}
+
+impl ZoneSink for ZoneBatchBuilder {
+    fn push_zone(
+        &mut self,
+        refinement_level: RefinementLevel,
+        zone: ZoneParts<'_>,
+    ) -> Result<(), DggrsError> {
+        self.append_parts(refinement_level, zone)
+    }
+}
+
+/// Encode zones as a record batch, the refinement level of each zone is determined by the DGGRS of the zones.
+pub fn zones_to_record_batch(
+    zones: &Zones,
+    dggrs: &dyn DggrsApi,
+    format: ZoneIdFormat,
+) -> Result<RecordBatch, DggrsError> {
+    let mut builder = ZoneBatchBuilder::new(format);
+    for zone in &zones.zones {
+        let zone_id = dggrs.format_zone_id(&zone.id, format)?;
+        let level = dggrs.validate_zone(&zone_id)?.refinement_level;
+        if zone_id == zone.id {
+            builder.append(level, zone)?;
+        } else {
+            let zone = Zone {
+                id: zone_id,
+                neighbors: convert_ids(dggrs, zone.neighbors.as_ref(), format)?,
+                children: convert_ids(dggrs, zone.children.as_ref(), format)?,
+                ..zone.clone()
+            };
+            builder.append(level, &zone)?;
+        }
+    }
+    builder.finish()
+}
+
+fn convert_ids(
+    dggrs: &dyn DggrsApi,
+    zone_ids: Option<&Vec<ZoneId>>,
+    format: ZoneIdFormat,
+) -> Result<Option<Vec<ZoneId>>, DggrsError> {
+    zone_ids
+        .map(|ids| {
+            ids.iter()
+                .map(|id| dggrs.format_zone_id(id, format))
+                .collect()
+        })
+        .transpose()
+}
+
+/// Decode zones from a record batch written by `ZoneBatchBuilder`, textual ZoneIds are parsed by the DGGRS of the zones.
+pub fn record_batch_to_zones(
+    batch: &RecordBatch,
+    dggrs: &dyn DggrsApi,
+) -> Result<Zones, DggrsError> {
+    let column = |name: &str| {
+        batch
+            .column_by_name(name)
+            .ok_or_else(|| EncodingError::invalid(FORMAT, format!("missing column {name}")))
+    };
+
+    let ids = read_ids(column("id")?.as_ref(), dggrs)?;
+    let regions = column("region")?
+        .as_list_opt::<i32>()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "region is not a list"))?;
+    let centers = column("center")?
+        .as_struct_opt()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "center is not a struct"))?;
+    let vertex_counts = column("vertex_count")?
+        .as_primitive_opt::<UInt32Type>()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "vertex_count is not UInt32"))?;
+    let areas = column("area_sqm")?
+        .as_primitive_opt::<Float64Type>()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "area_sqm is not Float64"))?;
+    let neighbors = column("neighbors")?;
+    let children = column("children")?;
+
+    let center_points = read_coords(centers)?;
+
+    let zones = ids
+        .into_iter()
+        .enumerate()
+        .map(|(row, id)| {
+            let region = if regions.is_null(row) {
+                None
+            } else {
+                read_region(regions.value(row).as_ref())?
+            };
+
+            Ok(Zone {
+                id,
+                region,
+                center: center_points[row],
+                vertex_count: (!vertex_counts.is_null(row)).then(|| vertex_counts.value(row)),
+                children: read_id_list(children.as_ref(), row, dggrs)?,
+                neighbors: read_id_list(neighbors.as_ref(), row, dggrs)?,
+                area_sqm: (!areas.is_null(row)).then(|| areas.value(row)),
+            })
+        })
+        .collect::<Result<Vec<_>, DggrsError>>()?;
+
+    Ok(Zones { zones })
+}
+
+/// Reads the polygons of a region, a single polygon becomes `Region::Polygon`
+fn read_region(polygons: &dyn Array) -> Result<Option<Region>, DggrsError> {
+    let polygons = polygons
+        .as_list_opt::<i32>()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "region polygons are not lists"))?;
+
+    let mut parts = Vec::with_capacity(polygons.len());
+    for p in 0..polygons.len() {
+        let rings = polygons.value(p);
+        let rings = rings
+            .as_list_opt::<i32>()
+            .ok_or_else(|| EncodingError::invalid(FORMAT, "region rings are not lists"))?;
+
+        let mut points = (0..rings.len()).map(|r| {
+            let vertices = rings.value(r);
+            let vertices = vertices
+                .as_struct_opt()
+                .ok_or_else(|| EncodingError::invalid(FORMAT, "region vertices are not structs"))?;
+            Ok(read_coords(vertices)?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>())
+        });
+        if let Some(exterior) = points.next() {
+            parts.push(Polygon::new(
+                exterior?,
+                points.collect::<Result<_, DggrsError>>()?,
+            ));
+        }
+    }
+
+    Ok(match parts.len() {
+        0 => None,
+        1 => parts.pop().map(Region::Polygon),
+        _ => Some(Region::MultiPolygon(parts)),
+    })
+}
+
+fn read_coords(coords: &StructArray) -> Result<Vec<Option<Point>>, DggrsError> {
+    let axis = |name: &str| {
+        coords
+            .column_by_name(name)
+            .and_then(|c| c.as_primitive_opt::<Float64Type>())
+            .ok_or_else(|| {
+                EncodingError::invalid(FORMAT, format!("coordinates have no Float64 {name}"))
+            })
+    };
+    let (x, y) = (axis("x")?, axis("y")?);
+
+    Ok((0..coords.len())
+        .map(|i| (!coords.is_null(i)).then(|| Point::new(y.value(i), x.value(i))))
+        .collect())
+}
+
+pub(crate) fn read_ids(ids: &dyn Array, dggrs: &dyn DggrsApi) -> Result<Vec<ZoneId>, DggrsError> {
+    if let Some(ids) = ids.as_string_opt::<i32>() {
+        ids.iter()
+            .map(|id| {
+                let id = id.ok_or_else(|| EncodingError::invalid(FORMAT, "null ZoneId"))?;
+                dggrs.parse_zone_id(id)
+            })
+            .collect()
+    } else if let Some(ids) = ids.as_primitive_opt::<UInt64Type>() {
+        ids.iter()
+            .map(|id| {
+                id.map(ZoneId::new_int)
+                    .ok_or_else(|| EncodingError::invalid(FORMAT, "null ZoneId").into())
+            })
+            .collect()
+    } else {
+        Err(EncodingError::invalid(FORMAT, "ZoneIds must be Utf8 or UInt64").into())
+    }
+}
+
+fn read_id_list(
+    lists: &dyn Array,
+    row: usize,
+    dggrs: &dyn DggrsApi,
+) -> Result<Option<Vec<ZoneId>>, DggrsError> {
+    let lists = lists
+        .as_list_opt::<i32>()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "ZoneId lists are not lists"))?;
+    if lists.is_null(row) {
+        return Ok(None);
+    }
+    read_ids(lists.value(row).as_ref(), dggrs).map(Some)
+}
@@ -0,0 +1,60 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CSV export of zones for bulk loading, e.g. with `COPY` in PostGIS or DuckDB.
+//!
+//! The columns are `id,level,wkt,area`, the WKT is quoted and the area is left empty for zones generated without `area_sqm`.
+//! Lines end with `\n` and the first line is the header.
+
+use crate::api::DggrsApi;
+use crate::encoding::wkt::{ZoneGeometry, zone_to_wkt};
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::types::Zones;
+use std::io::Write;
+
+pub const CSV_HEADER: &str = "id,level,wkt,area";
+
+/// Write zones as CSV with their region or centroid as WKT, the refinement level of each zone is determined by the DGGRS of the zones.
+pub fn write_zones<W: Write>(
+    mut writer: W,
+    zones: &Zones,
+    dggrs: &dyn DggrsApi,
+    geometry: ZoneGeometry,
+) -> Result<(), DggrsError> {
+    writeln!(writer, "{CSV_HEADER}").map_err(EncodingError::from)?;
+
+    for zone in &zones.zones {
+        let level = dggrs.validate_zone(&zone.id)?.refinement_level;
+        let wkt = zone_to_wkt(zone, geometry)?;
+        let area = zone.area_sqm.map(|a| a.to_string()).unwrap_or_default();
+
+        writeln!(
+            writer,
+            "{},{},\"{}\",{}",
+            quote(&zone.id.to_string()),
+            level,
+            wkt,
+            area
+        )
+        .map_err(EncodingError::from)?;
+    }
+
+    writer.flush().map_err(EncodingError::from)?;
+    Ok(())
+}
+
+/// Quote a field if it contains a separator, a quote or a line break
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
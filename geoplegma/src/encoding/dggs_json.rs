@@ -0,0 +1,176 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! OGC DGGS-JSON and DGGS-UBJSON encoding of values quantised on the sub-zones of a parent zone.
+//!
+//! The data of each field and depth lists one value per sub-zone, in the order of `DggrsApi::sub_zone_ids`. Missing values are encoded as `null`.
+
+use crate::api::DggrsApi;
+use crate::encoding::ubjson::{from_ubjson, to_ubjson};
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::types::{DggrsUid, RelativeDepth, ZoneId, ZoneIdFormat};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+
+const FORMAT: &str = "DGGS-JSON";
+
+/// A DGGS-JSON document, the values of one or more fields at one or more depths below a parent zone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DggsJson {
+    /// The URI of the DGGRS, see `dggrs_uri`.
+    pub dggrs: String,
+    /// The textual ZoneId of the parent zone.
+    pub zone_id: String,
+    pub depths: Vec<RelativeDepth>,
+    pub values: BTreeMap<String, Vec<DepthValues>>,
+}
+
+/// The sub-zones of a field and their values, as decoded from a DGGS-JSON document.
+pub type ZoneValues = Vec<(ZoneId, Option<f64>)>;
+
+/// The values of a field at one relative depth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DepthValues {
+    pub depth: RelativeDepth,
+    pub shape: Shape,
+    pub data: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Shape {
+    /// The number of values.
+    pub count: u64,
+    /// The number of sub-zones at the depth.
+    pub sub_zones: u64,
+}
+
+/// The URI of a DGGRS, the OGC definition URI unless the registry has its own.
+pub fn dggrs_uri(uid: DggrsUid) -> String {
+    let spec = uid.spec();
+    if spec.uri.is_empty() {
+        format!("https://www.opengis.net/def/dggrs/OGC/1.0/{}", spec.name)
+    } else {
+        spec.uri.to_string()
+    }
+}
+
+impl DggsJson {
+    /// Quantise the values of the given fields on the sub-zones of a parent zone at a relative depth.
+    ///
+    /// `value` is called for every field and sub-zone, `None` marks a missing value.
+    pub fn encode<F>(
+        dggrs: &dyn DggrsApi,
+        parent_zone_id: &ZoneId,
+        relative_depth: RelativeDepth,
+        fields: &[&str],
+        value: F,
+    ) -> Result<Self, DggrsError>
+    where
+        F: Fn(&str, &ZoneId) -> Option<f64>,
+    {
+        let sub_zones = dggrs.sub_zone_ids(relative_depth, parent_zone_id.clone())?;
+
+        let values = fields
+            .iter()
+            .map(|field| {
+                let data: Vec<Option<f64>> = sub_zones.iter().map(|z| value(field, z)).collect();
+                let depth_values = DepthValues {
+                    depth: relative_depth,
+                    shape: Shape {
+                        count: data.len() as u64,
+                        sub_zones: sub_zones.len() as u64,
+                    },
+                    data,
+                };
+                (field.to_string(), vec![depth_values])
+            })
+            .collect();
+
+        Ok(Self {
+            dggrs: dggrs_uri(dggrs.uid()),
+            zone_id: dggrs
+                .format_zone_id(parent_zone_id, ZoneIdFormat::Text)?
+                .to_string(),
+            depths: vec![relative_depth],
+            values,
+        })
+    }
+
+    /// Assign the values of every field and depth to their sub-zones.
+    ///
+    /// The document must belong to the DGGRS of `dggrs` and hold one value per sub-zone.
+    pub fn decode(&self, dggrs: &dyn DggrsApi) -> Result<BTreeMap<String, ZoneValues>, DggrsError> {
+        if self.dggrs != dggrs_uri(dggrs.uid()) {
+            return Err(EncodingError::invalid(
+                FORMAT,
+                format!(
+                    "the document belongs to {}, not {}",
+                    self.dggrs,
+                    dggrs_uri(dggrs.uid())
+                ),
+            )
+            .into());
+        }
+
+        let parent_zone_id = dggrs.parse_zone_id(&self.zone_id)?;
+        let mut sub_zones: BTreeMap<RelativeDepth, Vec<ZoneId>> = BTreeMap::new();
+        let mut decoded = BTreeMap::new();
+
+        for (field, depth_values) in &self.values {
+            let mut field_values = Vec::new();
+            for dv in depth_values {
+                let ids = match sub_zones.entry(dv.depth) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(dggrs.sub_zone_ids(dv.depth, parent_zone_id.clone())?)
+                    }
+                };
+
+                if dv.data.len() != ids.len() {
+                    return Err(EncodingError::invalid(
+                        FORMAT,
+                        format!(
+                            "field '{field}' has {} values at depth {}, expected {}",
+                            dv.data.len(),
+                            dv.depth,
+                            ids.len()
+                        ),
+                    )
+                    .into());
+                }
+                field_values.extend(ids.iter().cloned().zip(dv.data.iter().copied()));
+            }
+            decoded.insert(field.clone(), field_values);
+        }
+
+        Ok(decoded)
+    }
+
+    pub fn to_json(&self) -> Result<String, DggrsError> {
+        serde_json::to_string(self).map_err(|e| EncodingError::from(e).into())
+    }
+
+    pub fn from_json(s: &str) -> Result<Self, DggrsError> {
+        serde_json::from_str(s).map_err(|e| EncodingError::from(e).into())
+    }
+
+    /// Encode as DGGS-UBJSON, the UBJSON form of the DGGS-JSON document.
+    pub fn to_ubjson(&self) -> Result<Vec<u8>, DggrsError> {
+        let value = serde_json::to_value(self).map_err(EncodingError::from)?;
+        Ok(to_ubjson(&value))
+    }
+
+    pub fn from_ubjson(bytes: &[u8]) -> Result<Self, DggrsError> {
+        serde_json::from_value(from_ubjson(bytes)?).map_err(|e| EncodingError::from(e).into())
+    }
+}
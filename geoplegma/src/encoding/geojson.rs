@@ -0,0 +1,288 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! GeoJSON (RFC 7946) encoding of `Zones`.
+//!
+//! Each zone becomes a Feature of a FeatureCollection. The geometry is the region of the zone normalised with `GeometryMode::Split`, so regions crossing the antimeridian are MultiPolygons within ±180° and exterior rings are wound counter-clockwise.
+//! Zones without a region get their centroid as Point geometry. The properties are `id`, `level`, `center` (`[lon, lat]`), `area_sqm`, `vertex_count`, `neighbors` and `children`, absent outputs are left out.
+//! ZoneIds are written as JSON strings, except `ZoneId::IntId` which is written as a JSON number.
+
+use crate::api::DggrsApi;
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::geometry::{GeometryMode, normalize};
+use crate::types::{Point, Polygon as RegionPolygon, Region, Zone, ZoneId, Zones};
+use geo::{LineString, Polygon};
+use serde_json::{Map, Value, json};
+use std::io::{Read, Write};
+
+const FORMAT: &str = "GeoJSON";
+
+/// Encode zones as a GeoJSON FeatureCollection, the refinement level of each zone is determined by the DGGRS of the zones.
+pub fn zones_to_geojson(zones: &Zones, dggrs: &dyn DggrsApi) -> Result<Value, DggrsError> {
+    let features = zones
+        .zones
+        .iter()
+        .map(|zone| zone_to_feature(zone, dggrs))
+        .collect::<Result<Vec<_>, DggrsError>>()?;
+
+    Ok(json!({
+        "type": "FeatureCollection",
+        "features": features,
+    }))
+}
+
+/// Write zones as a GeoJSON FeatureCollection, see `zones_to_geojson`.
+pub fn write_zones<W: Write>(
+    writer: W,
+    zones: &Zones,
+    dggrs: &dyn DggrsApi,
+) -> Result<(), DggrsError> {
+    let value = zones_to_geojson(zones, dggrs)?;
+    serde_json::to_writer(writer, &value).map_err(EncodingError::from)?;
+    Ok(())
+}
+
+/// Decode zones from a GeoJSON FeatureCollection written by `zones_to_geojson`.
+///
+/// Textual ZoneIds are parsed by the DGGRS of the zones. Regions split at the antimeridian are joined again, the `level` property is ignored.
+pub fn zones_from_geojson(value: &Value, dggrs: &dyn DggrsApi) -> Result<Zones, DggrsError> {
+    if value.get("type").and_then(Value::as_str) != Some("FeatureCollection") {
+        return Err(EncodingError::invalid(FORMAT, "expected a FeatureCollection").into());
+    }
+
+    let zones = value
+        .get("features")
+        .and_then(Value::as_array)
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "missing features"))?
+        .iter()
+        .map(|feature| feature_to_zone(feature, dggrs))
+        .collect::<Result<Vec<_>, DggrsError>>()?;
+
+    Ok(Zones { zones })
+}
+
+/// Read zones from a GeoJSON FeatureCollection, see `zones_from_geojson`.
+pub fn read_zones<R: Read>(reader: R, dggrs: &dyn DggrsApi) -> Result<Zones, DggrsError> {
+    let value: Value = serde_json::from_reader(reader).map_err(EncodingError::from)?;
+    zones_from_geojson(&value, dggrs)
+}
+
+fn zone_to_feature(zone: &Zone, dggrs: &dyn DggrsApi) -> Result<Value, DggrsError> {
+    let level = dggrs.validate_zone(&zone.id)?.refinement_level;
+
+    let mut properties = Map::new();
+    properties.insert("id".to_string(), zone_id_to_json(&zone.id));
+    properties.insert("level".to_string(), json!(level.get()));
+    if let Some(center) = zone.center {
+        properties.insert("center".to_string(), json!([center.lon, center.lat]));
+    }
+    if let Some(area_sqm) = zone.area_sqm {
+        properties.insert("area_sqm".to_string(), json!(area_sqm));
+    }
+    if let Some(vertex_count) = zone.vertex_count {
+        properties.insert("vertex_count".to_string(), json!(vertex_count));
+    }
+    if let Some(neighbors) = &zone.neighbors {
+        properties.insert(
+            "neighbors".to_string(),
+            neighbors.iter().map(zone_id_to_json).collect(),
+        );
+    }
+    if let Some(children) = &zone.children {
+        properties.insert(
+            "children".to_string(),
+            children.iter().map(zone_id_to_json).collect(),
+        );
+    }
+
+    let geometry = match (&zone.region, zone.center) {
+        (Some(region), _) => region_to_geometry(region),
+        (None, Some(center)) => json!({
+            "type": "Point",
+            "coordinates": [center.lon, center.lat],
+        }),
+        (None, None) => Value::Null,
+    };
+
+    Ok(json!({
+        "type": "Feature",
+        "geometry": geometry,
+        "properties": properties,
+    }))
+}
+
+fn feature_to_zone(feature: &Value, dggrs: &dyn DggrsApi) -> Result<Zone, DggrsError> {
+    let properties = feature
+        .get("properties")
+        .and_then(Value::as_object)
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "missing feature properties"))?;
+
+    let id = zone_id_from_json(
+        properties
+            .get("id")
+            .ok_or_else(|| EncodingError::invalid(FORMAT, "missing id property"))?,
+        dggrs,
+    )?;
+
+    let center = match properties.get("center") {
+        Some(c) => Some(point_from_json(c)?),
+        None => None,
+    };
+
+    let region = match feature.get("geometry") {
+        Some(geometry) if !geometry.is_null() => geometry_to_region(geometry)?,
+        _ => None,
+    };
+
+    let zone_ids = |key: &str| -> Result<Option<Vec<ZoneId>>, DggrsError> {
+        properties
+            .get(key)
+            .map(|ids| {
+                ids.as_array()
+                    .ok_or_else(|| {
+                        EncodingError::invalid(FORMAT, format!("{key} is not an array"))
+                    })?
+                    .iter()
+                    .map(|id| zone_id_from_json(id, dggrs))
+                    .collect()
+            })
+            .transpose()
+    };
+
+    Ok(Zone {
+        id,
+        region,
+        center,
+        vertex_count: properties
+            .get("vertex_count")
+            .and_then(Value::as_u64)
+            .map(|v| v as u32),
+        children: zone_ids("children")?,
+        neighbors: zone_ids("neighbors")?,
+        area_sqm: properties.get("area_sqm").and_then(Value::as_f64),
+    })
+}
+
+fn zone_id_to_json(zone_id: &ZoneId) -> Value {
+    match zone_id {
+        ZoneId::IntId(i) => json!(i),
+        _ => json!(zone_id.to_string()),
+    }
+}
+
+fn zone_id_from_json(value: &Value, dggrs: &dyn DggrsApi) -> Result<ZoneId, DggrsError> {
+    match value {
+        Value::Number(n) => n.as_u64().map(ZoneId::new_int).ok_or_else(|| {
+            EncodingError::invalid(FORMAT, format!("{n} is not a 64-bit ZoneId")).into()
+        }),
+        Value::String(s) => dggrs.parse_zone_id(s),
+        _ => Err(EncodingError::invalid(FORMAT, "ZoneIds must be strings or numbers").into()),
+    }
+}
+
+fn point_from_json(value: &Value) -> Result<Point, DggrsError> {
+    match value.as_array().map(Vec::as_slice) {
+        Some([lon, lat, ..]) => match (lon.as_f64(), lat.as_f64()) {
+            (Some(lon), Some(lat)) => Ok(Point::new(lat, lon)),
+            _ => Err(EncodingError::invalid(FORMAT, "positions must be numbers").into()),
+        },
+        _ => Err(EncodingError::invalid(
+            "GeoJSON",
+            "positions must have a longitude and a latitude",
+        )
+        .into()),
+    }
+}
+
+fn ring_from_json(value: &Value) -> Result<LineString<f64>, DggrsError> {
+    value
+        .as_array()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "rings must be arrays of positions"))?
+        .iter()
+        .map(|p| point_from_json(p).map(|p| p.to_coord()))
+        .collect::<Result<Vec<_>, _>>()
+        .map(LineString::new)
+}
+
+fn polygon_from_json(value: &Value) -> Result<Polygon<f64>, DggrsError> {
+    let rings = value
+        .as_array()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "polygons must be arrays of rings"))?
+        .iter()
+        .map(ring_from_json)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut rings = rings.into_iter();
+    let exterior = rings
+        .next()
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "polygons must have an exterior ring"))?;
+    Ok(Polygon::new(exterior, rings.collect()))
+}
+
+fn polygon_to_json(polygon: &Polygon<f64>) -> Value {
+    let ring = |ls: &LineString<f64>| -> Value { ls.coords().map(|c| json!([c.x, c.y])).collect() };
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(ring)
+        .collect()
+}
+
+fn region_to_geometry(region: &Region) -> Value {
+    let region = normalize(region.clone(), GeometryMode::Split);
+    let polygons: Vec<Polygon<f64>> = region
+        .polygons()
+        .iter()
+        .map(RegionPolygon::to_geo_polygon)
+        .collect();
+
+    match polygons.as_slice() {
+        [polygon] => json!({
+            "type": "Polygon",
+            "coordinates": polygon_to_json(polygon),
+        }),
+        _ => json!({
+            "type": "MultiPolygon",
+            "coordinates": polygons.iter().map(polygon_to_json).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn geometry_to_region(geometry: &Value) -> Result<Option<Region>, DggrsError> {
+    let coordinates = geometry
+        .get("coordinates")
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "missing coordinates"))?;
+
+    match geometry.get("type").and_then(Value::as_str) {
+        Some("Polygon") => Ok(Some(Region::Polygon(from_geo_polygon(&polygon_from_json(
+            coordinates,
+        )?)))),
+        Some("MultiPolygon") => {
+            let parts = coordinates
+                .as_array()
+                .ok_or_else(|| {
+                    EncodingError::invalid(FORMAT, "MultiPolygons must be arrays of polygons")
+                })?
+                .iter()
+                .map(|part| polygon_from_json(part).map(|p| from_geo_polygon(&p)))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Some(Region::MultiPolygon(parts)))
+        }
+        Some("Point") => Ok(None),
+        _ => Err(EncodingError::invalid(FORMAT, "unsupported geometry type").into()),
+    }
+}
+
+fn from_geo_polygon(polygon: &Polygon<f64>) -> RegionPolygon {
+    let ring = |ls: &LineString<f64>| ls.coords().map(|c| Point::new(c.y, c.x)).collect();
+    RegionPolygon::new(
+        ring(polygon.exterior()),
+        polygon.interiors().iter().map(ring).collect(),
+    )
+}
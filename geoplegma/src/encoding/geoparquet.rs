@@ -0,0 +1,332 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! GeoParquet encoding of zones and of value columns keyed by ZoneId.
+//!
+//! Zone sets are written with the columns of `encoding::arrow` and GeoParquet 1.1 metadata, `region` is the primary geometry column in the native `multipolygon` encoding and `center` is a `point` column.
+//! Zone tables have a `zone_id` key column followed by arbitrary value columns and no geometry.
+//!
+//! Both carry the DGGRS (`dggrs`, `dggrs_uri`) and, if all zones share it, the `refinement_level` in the key-value metadata of the file.
+//! With a `partition_level`, the rows are sorted by their ancestor zone at that level and the rows of an ancestor are kept in one row group, so readers can prune row groups by zone.
+//! Consecutive ancestors are packed into a row group up to `GeoParquetOptions::row_group_rows`.
+
+use crate::api::DggrsApi;
+use crate::encoding::arrow::{read_ids, record_batch_to_zones, zones_to_record_batch};
+use crate::encoding::dggs_json::dggrs_uri;
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::types::{DggrsUid, RefinementLevel, ZoneId, ZoneIdFormat, Zones};
+use arrow_array::builder::{StringBuilder, UInt64Builder};
+use arrow_array::{ArrayRef, RecordBatch, UInt32Array};
+use arrow_schema::{Field, Schema};
+use arrow_select::concat::concat_batches;
+use arrow_select::take::take_record_batch;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::ChunkReader;
+use serde_json::json;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+
+const FORMAT: &str = "GeoParquet";
+
+pub const GEOPARQUET_VERSION: &str = "1.1.0";
+
+/// The number of rows that the row groups of a partitioned file are filled up to by default.
+pub const DEFAULT_ROW_GROUP_ROWS: usize = 1024 * 1024;
+
+/// Options for writing zone sets and zone tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeoParquetOptions {
+    /// The format of the written ZoneIds.
+    pub format: ZoneIdFormat,
+    /// Sort the rows by their ancestor zone at this refinement level and keep the rows of an ancestor in one row group, zones at a coarser level are their own ancestor.
+    pub partition_level: Option<RefinementLevel>,
+    /// The number of rows that the row groups of a partitioned file are filled up to, `DEFAULT_ROW_GROUP_ROWS` if not set.
+    ///
+    /// The rows of an ancestor are never split, so a row group holding a single ancestor can be larger.
+    pub row_group_rows: Option<usize>,
+}
+
+/// The DGGRS information in the key-value metadata of a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneFileMetadata {
+    pub dggrs: DggrsUid,
+    /// The refinement level of the zones, if all zones share one.
+    pub refinement_level: Option<RefinementLevel>,
+    pub partition_level: Option<RefinementLevel>,
+}
+
+fn parquet_error(e: parquet::errors::ParquetError) -> DggrsError {
+    EncodingError::from(e).into()
+}
+
+/// Write a zone set as GeoParquet.
+pub fn write_zones<W: Write + Send>(
+    writer: W,
+    zones: &Zones,
+    dggrs: &dyn DggrsApi,
+    options: GeoParquetOptions,
+) -> Result<(), DggrsError> {
+    let batch = zones_to_record_batch(zones, dggrs, options.format)?;
+    let zone_ids: Vec<ZoneId> = zones.zones.iter().map(|z| z.id.clone()).collect();
+
+    let geo = json!({
+        "version": GEOPARQUET_VERSION,
+        "primary_column": "region",
+        "columns": {
+            "region": { "encoding": "multipolygon", "geometry_types": ["Polygon", "MultiPolygon"] },
+            "center": { "encoding": "point", "geometry_types": ["Point"] },
+        },
+    });
+
+    write_batch(
+        writer,
+        batch,
+        &zone_ids,
+        dggrs,
+        options,
+        Some(KeyValue::new("geo".to_string(), geo.to_string())),
+    )
+}
+
+/// Read a zone set written by `write_zones`, the file must belong to the DGGRS of `dggrs`.
+pub fn read_zones<R: ChunkReader + 'static>(
+    reader: R,
+    dggrs: &dyn DggrsApi,
+) -> Result<Zones, DggrsError> {
+    let (_, batch) = read_batch(reader, dggrs)?;
+    record_batch_to_zones(&batch, dggrs)
+}
+
+/// Write value columns keyed by ZoneId, every column must have one value per ZoneId.
+pub fn write_zone_table<W: Write + Send>(
+    writer: W,
+    dggrs: &dyn DggrsApi,
+    zone_ids: &[ZoneId],
+    columns: Vec<(String, ArrayRef)>,
+    options: GeoParquetOptions,
+) -> Result<(), DggrsError> {
+    let key: ArrayRef = match options.format {
+        ZoneIdFormat::Text => {
+            let mut builder = StringBuilder::new();
+            for zone_id in zone_ids {
+                builder.append_value(
+                    dggrs
+                        .format_zone_id(zone_id, ZoneIdFormat::Text)?
+                        .to_string(),
+                );
+            }
+            Arc::new(builder.finish())
+        }
+        ZoneIdFormat::Int => {
+            let mut builder = UInt64Builder::new();
+            for zone_id in zone_ids {
+                builder.append_option(dggrs.format_zone_id(zone_id, ZoneIdFormat::Int)?.as_u64());
+            }
+            Arc::new(builder.finish())
+        }
+    };
+
+    let mut fields = vec![Field::new("zone_id", key.data_type().clone(), false)];
+    let mut arrays = vec![key];
+    for (name, array) in columns {
+        if array.len() != zone_ids.len() {
+            return Err(EncodingError::invalid(
+                FORMAT,
+                format!(
+                    "column {name} has {} values for {} zones",
+                    array.len(),
+                    zone_ids.len()
+                ),
+            )
+            .into());
+        }
+        fields.push(Field::new(name, array.data_type().clone(), true));
+        arrays.push(array);
+    }
+
+    let batch =
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).map_err(EncodingError::from)?;
+    write_batch(writer, batch, zone_ids, dggrs, options, None)
+}
+
+/// Read a zone table written by `write_zone_table`, the ZoneIds are parsed by the DGGRS and returned next to all columns.
+pub fn read_zone_table<R: ChunkReader + 'static>(
+    reader: R,
+    dggrs: &dyn DggrsApi,
+) -> Result<(Vec<ZoneId>, RecordBatch), DggrsError> {
+    let (_, batch) = read_batch(reader, dggrs)?;
+    let key = batch
+        .column_by_name("zone_id")
+        .ok_or_else(|| EncodingError::invalid(FORMAT, "missing zone_id column"))?;
+
+    let zone_ids = read_ids(key, dggrs)?;
+
+    Ok((zone_ids, batch))
+}
+
+/// Read the DGGRS information from the key-value metadata of a file.
+pub fn read_metadata<R: ChunkReader + 'static>(reader: R) -> Result<ZoneFileMetadata, DggrsError> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(reader).map_err(parquet_error)?;
+    file_metadata(&builder)
+}
+
+fn file_metadata<T>(
+    builder: &parquet::arrow::arrow_reader::ArrowReaderBuilder<T>,
+) -> Result<ZoneFileMetadata, DggrsError> {
+    let key_values = builder
+        .metadata()
+        .file_metadata()
+        .key_value_metadata()
+        .cloned()
+        .unwrap_or_default();
+    let value = |key: &str| {
+        key_values
+            .iter()
+            .find(|kv| kv.key == key)
+            .and_then(|kv| kv.value.clone())
+    };
+    let level = |key: &str| -> Result<Option<RefinementLevel>, DggrsError> {
+        value(key)
+            .map(|v| {
+                v.parse::<i32>()
+                    .map_err(|_| {
+                        EncodingError::invalid(FORMAT, format!("{key} is not an integer")).into()
+                    })
+                    .and_then(RefinementLevel::new)
+            })
+            .transpose()
+    };
+
+    let dggrs =
+        value("dggrs").ok_or_else(|| EncodingError::invalid(FORMAT, "missing dggrs metadata"))?;
+    Ok(ZoneFileMetadata {
+        dggrs: DggrsUid::from_str(&dggrs)
+            .map_err(|_| EncodingError::invalid(FORMAT, format!("unknown DGGRS {dggrs}")))?,
+        refinement_level: level("refinement_level")?,
+        partition_level: level("partition_level")?,
+    })
+}
+
+fn write_batch<W: Write + Send>(
+    writer: W,
+    batch: RecordBatch,
+    zone_ids: &[ZoneId],
+    dggrs: &dyn DggrsApi,
+    options: GeoParquetOptions,
+    geo: Option<KeyValue>,
+) -> Result<(), DggrsError> {
+    let mut levels = zone_ids
+        .iter()
+        .map(|zone_id| {
+            dggrs
+                .validate_zone(zone_id)
+                .map(|info| info.refinement_level)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    levels.dedup();
+
+    let mut metadata = vec![
+        KeyValue::new("dggrs".to_string(), dggrs.uid().to_string()),
+        KeyValue::new("dggrs_uri".to_string(), dggrs_uri(dggrs.uid())),
+    ];
+    if let [level] = levels.as_slice() {
+        metadata.push(KeyValue::new(
+            "refinement_level".to_string(),
+            level.to_string(),
+        ));
+    }
+    if let Some(partition_level) = options.partition_level {
+        metadata.push(KeyValue::new(
+            "partition_level".to_string(),
+            partition_level.to_string(),
+        ));
+    }
+    metadata.extend(geo);
+
+    let mut properties = WriterProperties::builder().set_key_value_metadata(Some(metadata));
+    if options.partition_level.is_some() {
+        // NOTE: the row groups are closed by flushing, the writer must not split an ancestor on its own
+        properties = properties.set_max_row_group_size(usize::MAX);
+    }
+    let properties = properties.build();
+    let mut writer =
+        ArrowWriter::try_new(writer, batch.schema(), Some(properties)).map_err(parquet_error)?;
+
+    match options.partition_level {
+        Some(partition_level) => {
+            let ancestors = zone_ids
+                .iter()
+                .map(
+                    |zone_id| match dggrs.ancestor_at_level(zone_id, partition_level) {
+                        Err(DggrsError::AncestorLevelTooFine { .. }) => Ok(zone_id.clone()),
+                        ancestor => ancestor,
+                    },
+                )
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let mut order: Vec<u32> = (0..zone_ids.len() as u32).collect();
+            order.sort_by(|a, b| ancestors[*a as usize].cmp(&ancestors[*b as usize]));
+            let sorted = take_record_batch(&batch, &UInt32Array::from(order.clone()))
+                .map_err(EncodingError::from)?;
+
+            let row_group_rows = options.row_group_rows.unwrap_or(DEFAULT_ROW_GROUP_ROWS);
+            let (mut start, mut rows) = (0, 0);
+            for group in order.chunk_by(|a, b| ancestors[*a as usize] == ancestors[*b as usize]) {
+                // NOTE: flushing closes the row group before an ancestor that does not fit, so no ancestor is split
+                if rows > 0 && rows + group.len() > row_group_rows {
+                    writer.flush().map_err(parquet_error)?;
+                    rows = 0;
+                }
+                writer
+                    .write(&sorted.slice(start, group.len()))
+                    .map_err(parquet_error)?;
+                start += group.len();
+                rows += group.len();
+            }
+        }
+        None => writer.write(&batch).map_err(parquet_error)?,
+    }
+
+    writer.close().map_err(parquet_error)?;
+    Ok(())
+}
+
+fn read_batch<R: ChunkReader + 'static>(
+    reader: R,
+    dggrs: &dyn DggrsApi,
+) -> Result<(ZoneFileMetadata, RecordBatch), DggrsError> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(reader).map_err(parquet_error)?;
+    let metadata = file_metadata(&builder)?;
+    if metadata.dggrs != dggrs.uid() {
+        return Err(EncodingError::invalid(
+            FORMAT,
+            format!(
+                "the file belongs to {}, not {}",
+                metadata.dggrs,
+                dggrs.uid()
+            ),
+        )
+        .into());
+    }
+
+    let schema = builder.schema().clone();
+    let batches = builder
+        .build()
+        .map_err(parquet_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(EncodingError::from)?;
+    let batch = concat_batches(&schema, &batches).map_err(EncodingError::from)?;
+
+    Ok((metadata, batch))
+}
@@ -0,0 +1,25 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Encoding and decoding of zones into exchange formats.
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod csv;
+#[cfg(feature = "json")]
+pub mod dggs_json;
+#[cfg(feature = "json")]
+pub mod geojson;
+#[cfg(feature = "geoparquet")]
+pub mod geoparquet;
+pub mod raster;
+#[cfg(feature = "json")]
+pub mod ubjson;
+pub mod wkb;
+pub mod wkt;
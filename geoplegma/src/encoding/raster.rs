@@ -0,0 +1,390 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rendering of zone values onto a regular grid in EPSG:4326 or EPSG:3857.
+//!
+//! Every pixel takes the value of the zone containing its centre, pixels whose zone has no value are NaN.
+//! The rendered raster is written as an uncompressed single band `float64` GeoTIFF, or as a raw array of little-endian `f64` values row by row from the north with a world file.
+
+use crate::api::DggrsApi;
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use crate::structures::coverage::Coverage;
+use crate::types::{Point, RefinementLevel, ZoneId, ZoneIdFormat};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const FORMAT: &str = "raster";
+
+/// The radius of the sphere of the Web Mercator projection.
+const WEB_MERCATOR_RADIUS: f64 = 6_378_137.0;
+
+/// The width and height in pixels of the tiles whose pixel centres are located with one `DggrsApi::zone_ids_from_points` call
+const TILE_SIZE: usize = 256;
+
+/// The projection of a rendered raster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Projection {
+    /// EPSG:4326, longitude and latitude in degrees.
+    Wgs84,
+    /// EPSG:3857, Web Mercator in metres.
+    WebMercator,
+}
+
+impl Projection {
+    pub fn from_epsg(epsg: u32) -> Result<Self, EncodingError> {
+        match epsg {
+            4326 => Ok(Self::Wgs84),
+            3857 => Ok(Self::WebMercator),
+            _ => Err(EncodingError::invalid(
+                FORMAT,
+                format!("unsupported projection EPSG:{epsg}"),
+            )),
+        }
+    }
+
+    pub fn epsg(&self) -> u32 {
+        match self {
+            Self::Wgs84 => 4326,
+            Self::WebMercator => 3857,
+        }
+    }
+
+    /// The longitude and latitude of projected coordinates.
+    ///
+    /// Longitudes are wrapped into [-180, 180) and latitudes clamped to the poles, as the last column and row of a grid can extend beyond them.
+    pub fn to_point(&self, x: f64, y: f64) -> Point {
+        let (lat, lon) = match self {
+            Self::Wgs84 => (y, x),
+            Self::WebMercator => (
+                (2.0 * (y / WEB_MERCATOR_RADIUS).exp().atan() - std::f64::consts::FRAC_PI_2)
+                    .to_degrees(),
+                (x / WEB_MERCATOR_RADIUS).to_degrees(),
+            ),
+        };
+        Point::new(
+            lat.clamp(-90.0, 90.0),
+            (lon + 180.0).rem_euclid(360.0) - 180.0,
+        )
+    }
+}
+
+/// A regular grid of square pixels, aligned to the north-west corner of its extent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterGrid {
+    pub projection: Projection,
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+    /// The width and height of a pixel in the units of the projection.
+    pub resolution: f64,
+}
+
+impl RasterGrid {
+    /// A grid covering an extent in the units of the projection.
+    ///
+    /// The last column and row are complete pixels, so the grid can extend beyond `max_x` and below `min_y` by less than a pixel.
+    pub fn new(
+        projection: Projection,
+        min_x: f64,
+        min_y: f64,
+        max_x: f64,
+        max_y: f64,
+        resolution: f64,
+    ) -> Result<Self, EncodingError> {
+        if !(resolution > 0.0 && resolution.is_finite()) {
+            return Err(EncodingError::invalid(
+                FORMAT,
+                format!("resolution must be positive, got {resolution}"),
+            ));
+        }
+        if !(min_x < max_x && min_y < max_y)
+            || [min_x, min_y, max_x, max_y].iter().any(|v| !v.is_finite())
+        {
+            return Err(EncodingError::invalid(
+                FORMAT,
+                format!("empty extent ({min_x}, {min_y}, {max_x}, {max_y})"),
+            ));
+        }
+        Ok(Self {
+            projection,
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+            resolution,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        ((self.max_x - self.min_x) / self.resolution).ceil() as usize
+    }
+
+    pub fn height(&self) -> usize {
+        ((self.max_y - self.min_y) / self.resolution).ceil() as usize
+    }
+
+    /// The projected coordinates of the centre of a pixel.
+    pub fn pixel_center(&self, col: usize, row: usize) -> (f64, f64) {
+        (
+            self.min_x + (col as f64 + 0.5) * self.resolution,
+            self.max_y - (row as f64 + 0.5) * self.resolution,
+        )
+    }
+}
+
+/// Zone values rendered onto a grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedRaster {
+    pub grid: RasterGrid,
+    /// The values row by row from the north, NaN where no zone value is available.
+    pub values: Vec<f64>,
+}
+
+impl RenderedRaster {
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    /// The value of a pixel, `None` outside the raster or for pixels without a value.
+    pub fn get(&self, col: usize, row: usize) -> Option<f64> {
+        if col >= self.width() || row >= self.height() {
+            return None;
+        }
+        Some(self.values[row * self.width() + col]).filter(|v| !v.is_nan())
+    }
+
+    /// The six lines of an ESRI world file, the pixel size, the rotations and the centre of the upper left pixel.
+    pub fn world_file(&self) -> String {
+        let (x, y) = self.grid.pixel_center(0, 0);
+        let r = self.grid.resolution;
+        format!("{r}\n0\n0\n{}\n{x}\n{y}\n", -r)
+    }
+
+    /// Write the values as raw little-endian `f64` to `path` and the world file next to it with the extension `wld`.
+    pub fn write_raw(&self, path: &Path) -> Result<(), EncodingError> {
+        let bytes: Vec<u8> = self.values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        fs::write(path, bytes)?;
+        fs::write(path.with_extension("wld"), self.world_file())?;
+        Ok(())
+    }
+
+    /// Encode the values as a GeoTIFF.
+    pub fn to_geotiff(&self) -> Vec<u8> {
+        let (width, height) = (self.width() as u32, self.height() as u32);
+        let grid = &self.grid;
+        let (model_type, crs_key) = match grid.projection {
+            // GTModelTypeGeoKey geographic, GeographicTypeGeoKey
+            Projection::Wgs84 => (2, 2048),
+            // GTModelTypeGeoKey projected, ProjectedCSTypeGeoKey
+            Projection::WebMercator => (1, 3072),
+        };
+        // The header of the GeoKey directory with the number of keys, followed by the keys
+        let geo_keys: Vec<u16> = [
+            [1, 1, 0, 3],
+            [1024, 0, 1, model_type],
+            // GTRasterTypeGeoKey, pixel is area
+            [1025, 0, 1, 1],
+            [crs_key, 0, 1, grid.projection.epsg() as u16],
+        ]
+        .concat();
+
+        let data_len = self.values.len() * 8;
+        let mut entries = vec![
+            IfdEntry::long(256, width),
+            IfdEntry::long(257, height),
+            IfdEntry::short(258, &[64]),
+            IfdEntry::short(259, &[1]),
+            IfdEntry::short(262, &[1]),
+            IfdEntry::long(273, 8),
+            IfdEntry::short(277, &[1]),
+            IfdEntry::long(278, height),
+            IfdEntry::long(279, data_len as u32),
+            IfdEntry::short(284, &[1]),
+            IfdEntry::short(339, &[3]),
+            IfdEntry::double(33550, &[grid.resolution, grid.resolution, 0.0]),
+            IfdEntry::double(33922, &[0.0, 0.0, 0.0, grid.min_x, grid.max_y, 0.0]),
+            IfdEntry::short(34735, &geo_keys),
+            IfdEntry::ascii(42113, "nan"),
+        ];
+        entries.sort_by_key(|entry| entry.tag);
+
+        // NOTE: the header is followed by the single strip of values, the IFD and the values of the entries that do not fit into them
+        let ifd_offset = 8 + data_len + data_len % 2;
+        let mut extra_offset = ifd_offset + 2 + entries.len() * 12 + 4;
+        let mut tiff = Vec::with_capacity(extra_offset + 256);
+        tiff.extend_from_slice(b"II*\0");
+        tiff.extend_from_slice(&(ifd_offset as u32).to_le_bytes());
+        for value in &self.values {
+            tiff.extend_from_slice(&value.to_le_bytes());
+        }
+        tiff.resize(ifd_offset, 0);
+
+        let mut extra = Vec::new();
+        tiff.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        for entry in &entries {
+            tiff.extend_from_slice(&entry.tag.to_le_bytes());
+            tiff.extend_from_slice(&entry.field_type.to_le_bytes());
+            tiff.extend_from_slice(&entry.count.to_le_bytes());
+            if entry.bytes.len() <= 4 {
+                let mut inline = entry.bytes.clone();
+                inline.resize(4, 0);
+                tiff.extend_from_slice(&inline);
+            } else {
+                tiff.extend_from_slice(&(extra_offset as u32).to_le_bytes());
+                extra.extend_from_slice(&entry.bytes);
+                if entry.bytes.len() % 2 == 1 {
+                    extra.push(0);
+                }
+                extra_offset = ifd_offset + 2 + entries.len() * 12 + 4 + extra.len();
+            }
+        }
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(&extra);
+        tiff
+    }
+
+    /// Write the values as a GeoTIFF.
+    pub fn write_geotiff(&self, path: &Path) -> Result<(), EncodingError> {
+        fs::write(path, self.to_geotiff())?;
+        Ok(())
+    }
+}
+
+/// An entry of a TIFF image file directory
+struct IfdEntry {
+    tag: u16,
+    field_type: u16,
+    count: u32,
+    bytes: Vec<u8>,
+}
+
+impl IfdEntry {
+    fn short(tag: u16, values: &[u16]) -> Self {
+        Self {
+            tag,
+            field_type: 3,
+            count: values.len() as u32,
+            bytes: values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+        }
+    }
+
+    fn long(tag: u16, value: u32) -> Self {
+        Self {
+            tag,
+            field_type: 4,
+            count: 1,
+            bytes: value.to_le_bytes().to_vec(),
+        }
+    }
+
+    fn double(tag: u16, values: &[f64]) -> Self {
+        Self {
+            tag,
+            field_type: 12,
+            count: values.len() as u32,
+            bytes: values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+        }
+    }
+
+    fn ascii(tag: u16, value: &str) -> Self {
+        let mut bytes = value.as_bytes().to_vec();
+        bytes.push(0);
+        Self {
+            tag,
+            field_type: 2,
+            count: bytes.len() as u32,
+            bytes,
+        }
+    }
+}
+
+/// Render the values of zones at a refinement level, all ZoneIds have to be in the same format.
+pub fn rasterize(
+    dggrs: &dyn DggrsApi,
+    refinement_level: RefinementLevel,
+    values: &BTreeMap<ZoneId, f64>,
+    grid: &RasterGrid,
+) -> Result<RenderedRaster, DggrsError> {
+    let format = values
+        .keys()
+        .next()
+        .map_or(ZoneIdFormat::Text, ZoneId::format);
+    render(dggrs, refinement_level, format, grid, |zone_id| {
+        values.get(zone_id).copied()
+    })
+}
+
+/// Render the values of a coverage.
+pub fn rasterize_coverage(
+    coverage: &Coverage<f64>,
+    grid: &RasterGrid,
+) -> Result<RenderedRaster, DggrsError> {
+    render(
+        coverage.dggrs(),
+        coverage.refinement_level(),
+        coverage.zone_id_format(),
+        grid,
+        |zone_id| coverage.get(zone_id).copied(),
+    )
+}
+
+/// Locate the pixel centres tile by tile in parallel and look up the values of their zones
+fn render(
+    dggrs: &dyn DggrsApi,
+    refinement_level: RefinementLevel,
+    format: ZoneIdFormat,
+    grid: &RasterGrid,
+    value: impl Fn(&ZoneId) -> Option<f64> + Sync,
+) -> Result<RenderedRaster, DggrsError> {
+    let (width, height) = (grid.width(), grid.height());
+    let tiles: Vec<(usize, usize)> = (0..height)
+        .step_by(TILE_SIZE)
+        .flat_map(|row| (0..width).step_by(TILE_SIZE).map(move |col| (col, row)))
+        .collect();
+    let tile_width = |col: usize| (col + TILE_SIZE).min(width) - col;
+
+    let tile_values = tiles
+        .par_iter()
+        .map(|&(col, row)| {
+            let points: Vec<Point> = (row..(row + TILE_SIZE).min(height))
+                .flat_map(|r| {
+                    (col..col + tile_width(col)).map(move |c| {
+                        let (x, y) = grid.pixel_center(c, r);
+                        grid.projection.to_point(x, y)
+                    })
+                })
+                .collect();
+            let zone_ids = dggrs.zone_ids_from_points(refinement_level, &points, format)?;
+            Ok(zone_ids
+                .iter()
+                .map(|zone_id| value(zone_id).unwrap_or(f64::NAN))
+                .collect::<Vec<f64>>())
+        })
+        .collect::<Result<Vec<_>, DggrsError>>()?;
+
+    let mut values = vec![f64::NAN; width * height];
+    for (&(col, row), tile) in tiles.iter().zip(&tile_values) {
+        for (r, tile_row) in tile.chunks(tile_width(col)).enumerate() {
+            let start = (row + r) * width + col;
+            values[start..start + tile_row.len()].copy_from_slice(tile_row);
+        }
+    }
+    Ok(RenderedRaster {
+        grid: *grid,
+        values,
+    })
+}
@@ -0,0 +1,266 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Universal Binary JSON (UBJSON) encoding of JSON values, as used by DGGS-UBJSON.
+//!
+//! Integers are written with the smallest fitting integer type, integers beyond `i64` as high-precision numbers and all other numbers as `float64`.
+//! The reader also accepts the optimized container format with a type (`$`) and count (`#`).
+
+use crate::error::DggrsError;
+use crate::error::encoding::EncodingError;
+use serde_json::{Map, Number, Value};
+
+const FORMAT: &str = "UBJSON";
+
+/// Encode a JSON value as UBJSON.
+pub fn to_ubjson(value: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_value(&mut out, value);
+    out
+}
+
+/// Decode a JSON value from UBJSON, trailing bytes are rejected.
+pub fn from_ubjson(bytes: &[u8]) -> Result<Value, DggrsError> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let marker = reader.marker()?;
+    let value = reader.value(marker)?;
+    if reader.pos != bytes.len() {
+        return Err(EncodingError::invalid(FORMAT, "trailing bytes after the value").into());
+    }
+    Ok(value)
+}
+
+fn write_value(out: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => out.push(b'Z'),
+        Value::Bool(true) => out.push(b'T'),
+        Value::Bool(false) => out.push(b'F'),
+        Value::Number(n) => write_number(out, n),
+        Value::String(s) => {
+            out.push(b'S');
+            write_str(out, s);
+        }
+        Value::Array(values) => {
+            out.push(b'[');
+            values.iter().for_each(|v| write_value(out, v));
+            out.push(b']');
+        }
+        Value::Object(map) => {
+            out.push(b'{');
+            for (key, v) in map {
+                write_str(out, key);
+                write_value(out, v);
+            }
+            out.push(b'}');
+        }
+    }
+}
+
+fn write_number(out: &mut Vec<u8>, n: &Number) {
+    if let Some(i) = n.as_i64() {
+        write_int(out, i);
+    } else if n.is_u64() {
+        out.push(b'H');
+        write_str(out, &n.to_string());
+    } else {
+        out.push(b'D');
+        out.extend_from_slice(&n.as_f64().unwrap_or(f64::NAN).to_be_bytes());
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, i: i64) {
+    if let Ok(v) = i8::try_from(i) {
+        out.push(b'i');
+        out.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = u8::try_from(i) {
+        out.push(b'U');
+        out.push(v);
+    } else if let Ok(v) = i16::try_from(i) {
+        out.push(b'I');
+        out.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = i32::try_from(i) {
+        out.push(b'l');
+        out.extend_from_slice(&v.to_be_bytes());
+    } else {
+        out.push(b'L');
+        out.extend_from_slice(&i.to_be_bytes());
+    }
+}
+
+/// Strings and object keys are written as an integer length followed by the UTF-8 bytes.
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    write_int(out, s.len() as i64);
+    out.extend_from_slice(s.as_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8], DggrsError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| EncodingError::invalid(FORMAT, "unexpected end of input"))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DggrsError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    /// The next marker, skipping no-op markers.
+    fn marker(&mut self) -> Result<u8, DggrsError> {
+        loop {
+            let marker = self.take(1)?[0];
+            if marker != b'N' {
+                return Ok(marker);
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn int(&mut self, marker: u8) -> Result<i64, DggrsError> {
+        Ok(match marker {
+            b'i' => i8::from_be_bytes(self.take_array()?) as i64,
+            b'U' => self.take(1)?[0] as i64,
+            b'I' => i16::from_be_bytes(self.take_array()?) as i64,
+            b'l' => i32::from_be_bytes(self.take_array()?) as i64,
+            b'L' => i64::from_be_bytes(self.take_array()?),
+            _ => return Err(EncodingError::invalid(FORMAT, "expected an integer").into()),
+        })
+    }
+
+    fn length(&mut self) -> Result<usize, DggrsError> {
+        let marker = self.marker()?;
+        self.length_of(marker)
+    }
+
+    fn length_of(&mut self, marker: u8) -> Result<usize, DggrsError> {
+        usize::try_from(self.int(marker)?)
+            .map_err(|_| EncodingError::invalid(FORMAT, "negative length").into())
+    }
+
+    fn string(&mut self) -> Result<String, DggrsError> {
+        let length = self.length()?;
+        self.utf8(length)
+    }
+
+    fn utf8(&mut self, length: usize) -> Result<String, DggrsError> {
+        String::from_utf8(self.take(length)?.to_vec())
+            .map_err(|_| EncodingError::invalid(FORMAT, "invalid UTF-8").into())
+    }
+
+    fn value(&mut self, marker: u8) -> Result<Value, DggrsError> {
+        Ok(match marker {
+            b'Z' => Value::Null,
+            b'T' => Value::Bool(true),
+            b'F' => Value::Bool(false),
+            b'i' | b'U' | b'I' | b'l' | b'L' => Value::from(self.int(marker)?),
+            b'd' => float(f32::from_be_bytes(self.take_array()?) as f64),
+            b'D' => float(f64::from_be_bytes(self.take_array()?)),
+            b'H' => {
+                let digits = self.string()?;
+                serde_json::from_str::<Number>(&digits)
+                    .map(Value::Number)
+                    .map_err(|_| EncodingError::invalid(FORMAT, "invalid high-precision number"))?
+            }
+            b'C' => Value::String((self.take(1)?[0] as char).to_string()),
+            b'S' => Value::String(self.string()?),
+            b'[' => self.array()?,
+            b'{' => self.object()?,
+            _ => {
+                return Err(EncodingError::invalid(
+                    FORMAT,
+                    format!("unknown marker '{}'", marker as char),
+                )
+                .into());
+            }
+        })
+    }
+
+    /// The optional `$` type and `#` count of an optimized container.
+    fn container_header(&mut self) -> Result<(Option<u8>, Option<usize>), DggrsError> {
+        let mut element_type = None;
+        if self.peek() == Some(b'$') {
+            self.pos += 1;
+            element_type = Some(self.take(1)?[0]);
+        }
+
+        if self.peek() == Some(b'#') {
+            self.pos += 1;
+            Ok((element_type, Some(self.length()?)))
+        } else if element_type.is_some() {
+            Err(EncodingError::invalid(FORMAT, "an optimized type requires a count").into())
+        } else {
+            Ok((None, None))
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, DggrsError> {
+        let (element_type, count) = self.container_header()?;
+
+        let mut values = Vec::new();
+        loop {
+            let marker = match (count, element_type) {
+                (Some(count), _) if values.len() == count => break,
+                (Some(_), Some(element_type)) => element_type,
+                (Some(_), None) => self.marker()?,
+                (None, _) => match self.marker()? {
+                    b']' => break,
+                    marker => marker,
+                },
+            };
+            values.push(self.value(marker)?);
+        }
+
+        Ok(Value::Array(values))
+    }
+
+    /// Object keys are strings without the `S` marker.
+    fn object(&mut self) -> Result<Value, DggrsError> {
+        let (element_type, count) = self.container_header()?;
+
+        let mut map = Map::new();
+        loop {
+            let key_marker = match count {
+                Some(count) if map.len() == count => break,
+                Some(_) => self.marker()?,
+                None => match self.marker()? {
+                    b'}' => break,
+                    marker => marker,
+                },
+            };
+
+            let length = self.length_of(key_marker)?;
+            let key = self.utf8(length)?;
+            let marker = match element_type {
+                Some(element_type) => element_type,
+                None => self.marker()?,
+            };
+            map.insert(key, self.value(marker)?);
+        }
+
+        Ok(Value::Object(map))
+    }
+}
+
+fn float(v: f64) -> Value {
+    Number::from_f64(v).map_or(Value::Null, Value::Number)
+}
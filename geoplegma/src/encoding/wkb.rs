@@ -0,0 +1,137 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Well-known binary (WKB) and PostGIS extended WKB (EWKB) encoding of regions and zones.
+//!
+//! The encoding is little-endian (NDR) with 2D `lon lat` coordinates, regions become a `Polygon` or `MultiPolygon` and centroids a `Point`.
+//! EWKB adds the SRID flag to the geometry type followed by the SRID, which is `4326` for the WGS84 coordinates of all DGGRS.
+
+use crate::encoding::wkt::ZoneGeometry;
+use crate::error::DggrsError;
+use crate::types::{Point, Polygon, Region, Zone};
+
+/// The SRID of WGS84 longitude/latitude coordinates.
+pub const SRID_WGS84: u32 = 4326;
+
+const BYTE_ORDER_NDR: u8 = 1;
+const WKB_POINT: u32 = 1;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOLYGON: u32 = 6;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Encode a point as WKB.
+pub fn point_to_wkb(point: &Point) -> Vec<u8> {
+    let mut wkb = header(WKB_POINT, None, 21);
+    push_point(&mut wkb, point);
+    wkb
+}
+
+/// Encode a point as EWKB with SRID 4326.
+pub fn point_to_ewkb(point: &Point) -> Vec<u8> {
+    let mut wkb = header(WKB_POINT, Some(SRID_WGS84), 25);
+    push_point(&mut wkb, point);
+    wkb
+}
+
+/// Encode a region as a WKB polygon or multipolygon.
+pub fn region_to_wkb(region: &Region) -> Vec<u8> {
+    polygon(region, None)
+}
+
+/// Encode a region as an EWKB polygon or multipolygon with SRID 4326.
+pub fn region_to_ewkb(region: &Region) -> Vec<u8> {
+    polygon(region, Some(SRID_WGS84))
+}
+
+/// Encode the region or the centroid of a zone as WKB, the zone must have been generated with that output.
+pub fn zone_to_wkb(zone: &Zone, geometry: ZoneGeometry) -> Result<Vec<u8>, DggrsError> {
+    zone_geometry(zone, geometry, None)
+}
+
+/// Encode the region or the centroid of a zone as EWKB with SRID 4326, see `zone_to_wkb`.
+pub fn zone_to_ewkb(zone: &Zone, geometry: ZoneGeometry) -> Result<Vec<u8>, DggrsError> {
+    zone_geometry(zone, geometry, Some(SRID_WGS84))
+}
+
+fn zone_geometry(
+    zone: &Zone,
+    geometry: ZoneGeometry,
+    srid: Option<u32>,
+) -> Result<Vec<u8>, DggrsError> {
+    let missing = || DggrsError::MissingZoneGeometry(zone.id.to_string());
+    match geometry {
+        ZoneGeometry::Region => zone
+            .region
+            .as_ref()
+            .map(|region| polygon(region, srid))
+            .ok_or_else(missing),
+        ZoneGeometry::Center => {
+            let center = zone.center.as_ref().ok_or_else(missing)?;
+            let mut wkb = header(WKB_POINT, srid, 25);
+            push_point(&mut wkb, center);
+            Ok(wkb)
+        }
+    }
+}
+
+fn header(geometry_type: u32, srid: Option<u32>, capacity: usize) -> Vec<u8> {
+    let mut wkb = Vec::with_capacity(capacity);
+    wkb.push(BYTE_ORDER_NDR);
+    match srid {
+        Some(srid) => {
+            wkb.extend_from_slice(&(geometry_type | EWKB_SRID_FLAG).to_le_bytes());
+            wkb.extend_from_slice(&srid.to_le_bytes());
+        }
+        None => wkb.extend_from_slice(&geometry_type.to_le_bytes()),
+    }
+    wkb
+}
+
+fn polygon(region: &Region, srid: Option<u32>) -> Vec<u8> {
+    let capacity = 13 + region.polygons().len() * 9 + region.coords_count() * 16;
+    match region {
+        Region::Polygon(polygon) => {
+            let mut wkb = header(WKB_POLYGON, srid, capacity);
+            push_rings(&mut wkb, polygon);
+            wkb
+        }
+        Region::MultiPolygon(polygons) => {
+            let mut wkb = header(WKB_MULTIPOLYGON, srid, capacity);
+            wkb.extend_from_slice(&(polygons.len() as u32).to_le_bytes());
+            for polygon in polygons {
+                // NOTE: the parts of a multipolygon are complete WKB polygons without a SRID
+                wkb.extend(header(WKB_POLYGON, None, 0));
+                push_rings(&mut wkb, polygon);
+            }
+            wkb
+        }
+    }
+}
+
+fn push_rings(wkb: &mut Vec<u8>, polygon: &Polygon) {
+    // NOTE: an empty polygon has no rings rather than one empty ring
+    if polygon.exterior.is_empty() {
+        wkb.extend_from_slice(&0u32.to_le_bytes());
+        return;
+    }
+
+    let rings = std::iter::once(&polygon.exterior).chain(&polygon.interiors);
+    wkb.extend_from_slice(&(1 + polygon.interiors.len() as u32).to_le_bytes());
+    for ring in rings {
+        wkb.extend_from_slice(&(ring.len() as u32).to_le_bytes());
+        for point in ring {
+            push_point(wkb, point);
+        }
+    }
+}
+
+fn push_point(wkb: &mut Vec<u8>, point: &Point) {
+    wkb.extend_from_slice(&point.lon.to_le_bytes());
+    wkb.extend_from_slice(&point.lat.to_le_bytes());
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Well-known text (WKT) encoding of regions and zones.
+//!
+//! Coordinates are written as `lon lat` in WGS84, regions become a `POLYGON` or `MULTIPOLYGON` and centroids a `POINT`. The numbers are written with the shortest representation that reads back to the same `f64`.
+
+use crate::error::DggrsError;
+use crate::types::{Point, Polygon, Region, Zone};
+use std::fmt::Write;
+
+/// The geometry of a zone that is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZoneGeometry {
+    /// The region of the zone as a polygon.
+    #[default]
+    Region,
+    /// The centroid of the zone as a point.
+    Center,
+}
+
+/// Encode a point as WKT, e.g. `POINT (9.06 52.98)`.
+pub fn point_to_wkt(point: &Point) -> String {
+    format!("POINT ({} {})", point.lon, point.lat)
+}
+
+/// Encode a region as a WKT polygon or multipolygon, e.g. `POLYGON ((9 52, 10 52, 10 53, 9 52))`.
+pub fn region_to_wkt(region: &Region) -> String {
+    match region {
+        Region::Polygon(polygon) if polygon.exterior.is_empty() => "POLYGON EMPTY".to_string(),
+        Region::Polygon(polygon) => format!("POLYGON {}", polygon_text(polygon)),
+        Region::MultiPolygon(polygons) if polygons.is_empty() => "MULTIPOLYGON EMPTY".to_string(),
+        Region::MultiPolygon(polygons) => {
+            let parts: Vec<String> = polygons.iter().map(polygon_text).collect();
+            format!("MULTIPOLYGON ({})", parts.join(", "))
+        }
+    }
+}
+
+/// The rings of a polygon, e.g. `((9 52, 10 52, 10 53, 9 52), (...))`
+fn polygon_text(polygon: &Polygon) -> String {
+    let mut wkt = String::from("(");
+    for (r, ring) in std::iter::once(&polygon.exterior)
+        .chain(&polygon.interiors)
+        .enumerate()
+    {
+        if r > 0 {
+            wkt.push_str(", ");
+        }
+        wkt.push('(');
+        for (i, point) in ring.iter().enumerate() {
+            if i > 0 {
+                wkt.push_str(", ");
+            }
+            let _ = write!(wkt, "{} {}", point.lon, point.lat);
+        }
+        wkt.push(')');
+    }
+    wkt.push(')');
+    wkt
+}
+
+/// Encode the region or the centroid of a zone as WKT, the zone must have been generated with that output.
+pub fn zone_to_wkt(zone: &Zone, geometry: ZoneGeometry) -> Result<String, DggrsError> {
+    let missing = || DggrsError::MissingZoneGeometry(zone.id.to_string());
+    match geometry {
+        ZoneGeometry::Region => zone.region.as_ref().map(region_to_wkt).ok_or_else(missing),
+        ZoneGeometry::Center => zone.center.as_ref().map(point_to_wkt).ok_or_else(missing),
+    }
+}
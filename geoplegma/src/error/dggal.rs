@@ -38,6 +38,21 @@ pub enum DggalError {
         #[source]
         source: TryFromIntError,
     },
-    #[error("Invalid DGGAL ZoneId, checked with getZoneArea() resulted in inf")]
+    #[error("Invalid DGGAL ZoneId, the zone is unknown to DGGAL")]
     InvalidDggalZoneId,
+
+    #[error("Zone '{zone_id}' is not a sub-zone of '{ancestor_id}'")]
+    NotASubZone {
+        zone_id: String,
+        ancestor_id: String,
+    },
+
+    #[error(
+        "Sub-zone index {index} is out of range for parent zone '{parent_id}' with {count} sub-zones"
+    )]
+    SubZoneIndexOutOfRange {
+        parent_id: String,
+        index: u64,
+        count: u64,
+    },
 }
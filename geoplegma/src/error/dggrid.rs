@@ -33,6 +33,21 @@ pub enum DggridError {
     #[error("Missing required zone data")]
     MissingZoneData,
 
+    #[error("Zone '{zone_id}' is not a sub-zone of '{ancestor_id}'")]
+    NotASubZone {
+        zone_id: String,
+        ancestor_id: String,
+    },
+
+    #[error(
+        "Sub-zone index {index} is out of range for parent zone '{parent_id}' with {count} sub-zones"
+    )]
+    SubZoneIndexOutOfRange {
+        parent_id: String,
+        index: u64,
+        count: u64,
+    },
+
     // File I/O
     #[error("Failed to read file {path}")]
     FileRead {
@@ -0,0 +1,45 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use thiserror::Error;
+
+/// Error type for encoding and decoding zones and data structures.
+#[derive(Debug, Error)]
+pub enum EncodingError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[cfg(feature = "arrow")]
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+
+    #[cfg(feature = "geoparquet")]
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] parquet::errors::ParquetError),
+
+    #[cfg(feature = "json")]
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid {format}: {reason}")]
+    InvalidInput {
+        format: &'static str,
+        reason: String,
+    },
+}
+
+impl EncodingError {
+    /// Invalid input for an encoding, `format` names the encoding.
+    pub fn invalid(format: &'static str, reason: impl Into<String>) -> Self {
+        Self::InvalidInput {
+            format,
+            reason: reason.into(),
+        }
+    }
+}
@@ -47,6 +47,18 @@ pub enum H3oError {
         source: InvalidResolution,
     },
 
+    #[error("H3 zone ID {zone_id} is not a sub-zone of {ancestor_id}")]
+    NotASubZone {
+        zone_id: String,
+        ancestor_id: String,
+    },
+
+    #[error("Sub-zone index {index} is out of range for H3 parent zone {parent_id}")]
+    SubZoneIndexOutOfRange { parent_id: String, index: u64 },
+
     #[error("Missing required zone data")]
     MissingZoneData,
+
+    #[error("Invalid coordinate ({lat}, {lon})")]
+    InvalidCoordinate { lat: f64, lon: f64 },
 }
@@ -0,0 +1,31 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use thiserror::Error;
+
+/// Error type for the ingestion of rasters and points into coverages.
+#[derive(Debug, Error)]
+pub enum IngestError {
+    #[error("Only EPSG:4326 rasters are supported, got EPSG:{0}")]
+    UnsupportedCrs(u32),
+
+    #[error("A raster of {width}x{height} pixels needs {expected} values, got {found}")]
+    ShapeMismatch {
+        width: usize,
+        height: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("The affine transform of the raster is not invertible")]
+    SingularTransform,
+
+    #[error("Tile size must be positive, got {0}")]
+    InvalidTileSize(f64),
+}
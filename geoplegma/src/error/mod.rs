@@ -9,15 +9,22 @@
 
 pub mod dggal;
 pub mod dggrid;
+pub mod encoding;
 pub mod factory;
 pub mod h3o;
+pub mod ingest;
 pub mod port;
+pub mod structures;
 
 use crate::error::dggal::DggalError;
 use crate::error::dggrid::DggridError;
+use crate::error::encoding::EncodingError;
 use crate::error::factory::FactoryError;
 use crate::error::h3o::H3oError;
-use crate::types::{RefinementLevel, RelativeDepth};
+use crate::error::ingest::IngestError;
+use crate::error::structures::StructureError;
+use crate::geometry::Densification;
+use crate::types::{BoundingBox, Point, RefinementLevel, RelativeDepth};
 use std::num::ParseFloatError;
 use thiserror::Error;
 
@@ -35,6 +42,15 @@ pub enum DggrsError {
     #[error("H3o error: {0}")]
     H3o(#[from] H3oError),
 
+    #[error("Encoding error: {0}")]
+    Encoding(#[from] EncodingError),
+
+    #[error("Data structure error: {0}")]
+    Structure(#[from] StructureError),
+
+    #[error("Ingestion error: {0}")]
+    Ingest(#[from] IngestError),
+
     #[error("Depth must be non-negative, got {0}")]
     DepthBelowZero(i32),
 
@@ -69,6 +85,21 @@ pub enum DggrsError {
         maximum: RefinementLevel,
     },
 
+    #[error("Invalid bounding box {bbox:?}: {reason}")]
+    InvalidBoundingBox {
+        bbox: BoundingBox,
+        reason: &'static str,
+    },
+
+    #[error(
+        "Requested ancestor level {requested} is finer than the level {level} of zone '{zone_id}'"
+    )]
+    AncestorLevelTooFine {
+        zone_id: String,
+        requested: RefinementLevel,
+        level: RefinementLevel,
+    },
+
     #[error("Depth too large to convert to u8: {0}")]
     RefinementLevelTooHigh(RefinementLevel),
 
@@ -81,6 +112,18 @@ pub enum DggrsError {
     #[error("Invalid hex ZoneId: '{0}'")]
     InvalidHexId(String),
 
+    #[error("Operation '{0}' is not supported by this DGGRS adapter")]
+    UnsupportedOperation(String),
+
+    #[error("Zone '{0}' has no geometry")]
+    MissingZoneGeometry(String),
+
+    #[error("No zone found at {0:?}")]
+    NoZoneAtPoint(Point),
+
+    #[error("Invalid densification {0:?}, lengths must be positive and finite")]
+    InvalidDensification(Densification),
+
     // Parsing primitives
     #[error("Float parse error: {0}")]
     Float(#[from] ParseFloatError),
@@ -0,0 +1,64 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::types::{DggrsUid, RefinementLevel};
+use thiserror::Error;
+
+/// Error type for coverages, vectors and other DGGS data structures.
+#[derive(Debug, Error)]
+pub enum StructureError {
+    #[error("Zone '{zone_id}' is at refinement level {found}, the coverage is at {expected}")]
+    LevelMismatch {
+        zone_id: String,
+        expected: RefinementLevel,
+        found: RefinementLevel,
+    },
+
+    #[error("Zone '{0}' is outside the extent of the dense coverage")]
+    OutsideCoverage(String),
+
+    #[error(
+        "Block level {block_level} is finer than the refinement level {refinement_level} of the coverage"
+    )]
+    BlockLevelTooFine {
+        block_level: RefinementLevel,
+        refinement_level: RefinementLevel,
+    },
+
+    #[error("Refinement level {requested} is outside the range {min} to {max} of the DGGRS")]
+    LevelOutOfRange {
+        requested: RefinementLevel,
+        min: RefinementLevel,
+        max: RefinementLevel,
+    },
+
+    #[error("There is no refinement level coarser than {0} in the DGGRS")]
+    NoCoarserLevel(RefinementLevel),
+
+    #[error("Zone '{0}' is not among the sub-zones of any parent")]
+    NoParentShare(String),
+
+    #[error("The coverage is not partitioned into blocks")]
+    NotPartitioned,
+
+    #[error("Zone '{zone_id}' is not a sub-zone of block '{block_id}'")]
+    OutsideBlock { zone_id: String, block_id: String },
+
+    #[error("The data was written for DGGRS {found:?}, not {expected:?}")]
+    DggrsMismatch { expected: DggrsUid, found: DggrsUid },
+
+    #[error("The data is at refinement level {found}, not {expected}")]
+    RefinementLevelMismatch {
+        expected: RefinementLevel,
+        found: RefinementLevel,
+    },
+
+    #[error("The data has values of type '{found}', not '{expected}'")]
+    DataTypeMismatch { expected: String, found: String },
+}
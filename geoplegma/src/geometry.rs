@@ -0,0 +1,436 @@
+// Copyright 2025 contributors to the GeoPlegma project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Normalisation of zone regions at the antimeridian and the poles, and densification of their edges.
+//!
+//! The DGGRS libraries disagree on how they return zones near ±180°: DGGRID unwraps longitudes to the east, DGGAL and H3 return raw longitudes that jump across the antimeridian.
+//! All adapters apply `normalize` with the `GeometryMode` of the `DggrsApiConfig`, so that the regions of all DGGRS look alike.
+//!
+//! A ring is unwrapped by removing longitude jumps of more than 180°, except along a pole where all longitudes are the same point.
+//! A zone that encloses a pole ends 360° away from where it started after unwrapping, its ring is closed along the pole.
+//!
+//! Zone edges are curves on the earth, a polygon with straight segments in longitude/latitude or Web Mercator only approximates them.
+//! The `Densification` of the `DggrsApiConfig` controls how many vertices are inserted into each edge and with it the error of the approximation.
+
+use crate::error::DggrsError;
+use crate::types::{BoundingBox, Point, Polygon, Region};
+use geo::orient::{Direction, Orient};
+use geo::{BoundingRect, LineString, MapCoords, coord};
+
+/// How the regions of zones near the antimeridian and the poles are represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum GeometryMode {
+    /// The vertices as returned by the DGGRS library.
+    Raw,
+    /// Continuous longitudes, zones crossing the antimeridian extend beyond ±180° and zones around a pole are closed along it.
+    Unwrap,
+    /// Longitudes within ±180°, zones crossing the antimeridian are split into a MultiPolygon and zones around a pole are closed along it.
+    #[default]
+    Split,
+}
+
+/// Mean earth radius in metres, used for the spherical edge computations
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// Latitude up to which the deviation of a segment from the geodesic is estimated, the estimate grows without bound at the poles
+const MAX_DEVIATION_LAT: f64 = 89.0;
+
+/// The most vertices inserted into an edge, the limit of DGGRID's `densification` and the cap for DGGAL and `densify`
+pub const MAX_VERTICES_PER_EDGE: u32 = 500;
+
+/// How the edges of zone regions are densified.
+///
+/// DGGRID and DGGAL insert the vertices along the true zone edges, H3 edges are great circles so `densify` inserts them along the sphere.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Densification {
+    /// The corners of the zone only.
+    None,
+    /// A fixed number of vertices inserted into every edge.
+    VerticesPerEdge(u32),
+    /// As many vertices as needed so that no segment is longer than the given length in metres.
+    MaxSegmentLength(f64),
+    /// As many vertices as needed so that straight segments in longitude/latitude or Web Mercator deviate less than the given distance in metres from the geodesic.
+    MaxDeviation(f64),
+}
+
+impl Default for Densification {
+    fn default() -> Self {
+        Self::MaxDeviation(1.0)
+    }
+}
+
+impl Densification {
+    /// Reject maximum lengths and deviations that are not positive and finite.
+    pub fn validate(&self) -> Result<(), DggrsError> {
+        match *self {
+            Self::MaxSegmentLength(max) | Self::MaxDeviation(max)
+                if !(max.is_finite() && max > 0.0) =>
+            {
+                Err(DggrsError::InvalidDensification(*self))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The number of vertices to insert into an edge of `edge_length` metres that reaches up to latitude `max_abs_lat`.
+    ///
+    /// A straight segment of length `s` deviates about `s² tan(φ) / 8R` from the geodesic at latitude `φ`, the estimate is doubled to bound it from above.
+    /// Near the equator the deviation is of third order in `s`, so the latitude is taken as at least the angle `s / R` of the edge.
+    /// Invalid densifications, see `validate`, insert no vertices. At most `MAX_VERTICES_PER_EDGE` vertices are inserted.
+    pub fn vertices_per_edge(&self, edge_length: f64, max_abs_lat: f64) -> u32 {
+        let segments = match *self {
+            Self::None => return 0,
+            Self::VerticesPerEdge(n) => return n.min(MAX_VERTICES_PER_EDGE),
+            Self::MaxSegmentLength(max) => edge_length / max,
+            Self::MaxDeviation(max) => {
+                let lat = max_abs_lat
+                    .abs()
+                    .to_radians()
+                    .max(edge_length / EARTH_RADIUS)
+                    .min(MAX_DEVIATION_LAT.to_radians());
+                edge_length * (lat.tan() / (4.0 * EARTH_RADIUS * max)).sqrt()
+            }
+        };
+        if self.validate().is_err() {
+            return 0;
+        }
+        if segments.is_finite() {
+            (segments.ceil() as u32)
+                .saturating_sub(1)
+                .min(MAX_VERTICES_PER_EDGE)
+        } else {
+            0
+        }
+    }
+}
+
+/// Insert vertices along the great circles between the vertices of every ring.
+///
+/// This is exact for zones with great circle edges like H3, edges along a pole are left as they are.
+pub fn densify(region: Region, densification: Densification) -> Result<Region, DggrsError> {
+    densification.validate()?;
+    if densification == Densification::None {
+        return Ok(region);
+    }
+    let polygon = |p: &Polygon| {
+        Polygon::new(
+            densify_ring(&p.exterior, densification),
+            p.interiors
+                .iter()
+                .map(|ring| densify_ring(ring, densification))
+                .collect(),
+        )
+    };
+    Ok(match region {
+        Region::Polygon(p) => Region::Polygon(polygon(&p)),
+        Region::MultiPolygon(ps) => Region::MultiPolygon(ps.iter().map(polygon).collect()),
+    })
+}
+
+/// The great circle length of an edge in metres
+pub fn edge_length(a: Point, b: Point) -> f64 {
+    let (u, v) = (unit(a.lon, a.lat), unit(b.lon, b.lat));
+    angle(u, v) * EARTH_RADIUS
+}
+
+/// The mean edge length in metres of a DGGRS level with `zone_count` zones, taking them as regular hexagons
+pub fn mean_edge_length(zone_count: u64) -> f64 {
+    let area = 4.0 * std::f64::consts::PI * EARTH_RADIUS * EARTH_RADIUS / zone_count as f64;
+    (2.0 * area / (3.0 * 3f64.sqrt())).sqrt()
+}
+
+fn densify_ring(ring: &[Point], densification: Densification) -> Vec<Point> {
+    let mut densified = Vec::with_capacity(ring.len());
+    for edge in ring.windows(2) {
+        let (a, b) = (edge[0], edge[1]);
+        densified.push(a);
+        if a.lat.abs() == 90.0 && a.lat == b.lat {
+            continue;
+        }
+        let n = densification.vertices_per_edge(edge_length(a, b), a.lat.abs().max(b.lat.abs()));
+        let (u, v) = (unit(a.lon, a.lat), unit(b.lon, b.lat));
+        let theta = angle(u, v);
+        if n == 0 || theta < 1e-12 {
+            continue;
+        }
+        for i in 1..=n {
+            let t = f64::from(i) / f64::from(n + 1);
+            let (wa, wb) = (((1.0 - t) * theta).sin(), (t * theta).sin());
+            let p = [0, 1, 2].map(|k| (wa * u[k] + wb * v[k]) / theta.sin());
+            densified.push(Point::new(
+                p[2].atan2(p[0].hypot(p[1])).to_degrees(),
+                p[1].atan2(p[0]).to_degrees(),
+            ));
+        }
+    }
+    densified.extend(ring.last());
+    densified
+}
+
+fn unit(lon: f64, lat: f64) -> [f64; 3] {
+    let (lon, lat) = (lon.to_radians(), lat.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn cross(u: [f64; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+fn angle(u: [f64; 3], v: [f64; 3]) -> f64 {
+    let c = cross(u, v);
+    let dot = u[0] * v[0] + u[1] * v[1] + u[2] * v[2];
+    (c[0] * c[0] + c[1] * c[1] + c[2] * c[2]).sqrt().atan2(dot)
+}
+
+/// Normalise a region, `Unwrap` and `Split` wind the rings according to the right-hand rule.
+///
+/// The polygons of a MultiPolygon are normalised one by one, parts that were split before are not joined again.
+pub fn normalize(region: Region, mode: GeometryMode) -> Region {
+    let polygons: Vec<Polygon> = match mode {
+        GeometryMode::Raw => return region,
+        GeometryMode::Unwrap => region.polygons().iter().map(unwrap).collect(),
+        GeometryMode::Split => region
+            .polygons()
+            .iter()
+            .flat_map(split_antimeridian)
+            .collect(),
+    };
+
+    match (region, polygons.len()) {
+        (Region::Polygon(_), 1) => Region::Polygon(polygons.into_iter().next().unwrap_or_default()),
+        _ => Region::MultiPolygon(polygons),
+    }
+}
+
+/// The bounding box of split regions, it wraps around the antimeridian if the regions were split there
+pub fn bounding_box(polygon: &geo::MultiPolygon<f64>) -> Option<BoundingBox> {
+    let rect = polygon.bounding_rect()?;
+    let bbox = BoundingBox::new(rect.min().x, rect.min().y, rect.max().x, rect.max().y);
+
+    let rects: Vec<_> = polygon.iter().filter_map(|p| p.bounding_rect()).collect();
+    let east = rects
+        .iter()
+        .filter(|r| r.max().x == 180.0)
+        .map(|r| r.min().x);
+    let west = rects
+        .iter()
+        .filter(|r| r.min().x == -180.0)
+        .map(|r| r.max().x);
+    let (Some(min_lon), Some(max_lon)) = (east.reduce(f64::min), west.reduce(f64::max)) else {
+        return Some(bbox);
+    };
+
+    // NOTE: a zone around a pole touches both sides as well, but spans all longitudes
+    let inside = |r: &geo::Rect<f64>| r.min().x >= min_lon || r.max().x <= max_lon;
+    if min_lon > max_lon && rects.iter().all(inside) {
+        Some(BoundingBox::new(
+            min_lon,
+            bbox.min_lat,
+            max_lon,
+            bbox.max_lat,
+        ))
+    } else {
+        Some(bbox)
+    }
+}
+
+/// Whether any ring of the polygon has an edge that crosses the antimeridian or longitudes beyond ±180°.
+pub fn crosses_antimeridian(polygon: &Polygon) -> bool {
+    let unwrapped = to_geo(polygon);
+    rings(&unwrapped).any(|ring| ring.coords().any(|c| !(-180.0..=180.0).contains(&c.x)))
+}
+
+/// The latitude of the pole enclosed by the exterior ring of the polygon, if any.
+pub fn enclosed_pole(polygon: &Polygon) -> Option<f64> {
+    let ring = unwrap_ring(&polygon.exterior);
+    let (first, last) = (ring.first()?, ring.last()?);
+
+    // NOTE: the unwrapped ring of a zone around a pole ends 360 degrees away from where it started
+    ((last.x - first.x).abs() > 180.0).then(|| {
+        let mean_lat = ring.iter().map(|c| c.y).sum::<f64>() / ring.len() as f64;
+        if mean_lat >= 0.0 { 90.0 } else { -90.0 }
+    })
+}
+
+/// The polygon with continuous longitudes, starting within ±180°.
+pub fn unwrap(polygon: &Polygon) -> Polygon {
+    from_geo(&to_geo(polygon).orient(Direction::Default))
+}
+
+/// Split a polygon at the antimeridian into polygons within ±180°.
+pub fn split_antimeridian(polygon: &Polygon) -> Vec<Polygon> {
+    let unwrapped = to_geo(polygon);
+    let crosses =
+        rings(&unwrapped).any(|ring| ring.coords().any(|c| !(-180.0..=180.0).contains(&c.x)));
+    if !crosses {
+        return vec![from_geo(&unwrapped.orient(Direction::Default))];
+    }
+
+    // NOTE: the windows are clipped exactly instead of with boolean operations, which would move the vertices slightly
+    [-360.0, 0.0, 360.0]
+        .into_iter()
+        .filter_map(|shift: f64| {
+            let (min_x, max_x) = (shift - 180.0, shift + 180.0);
+            let exterior = clip_ring(unwrapped.exterior(), min_x, max_x)?;
+            let interiors = unwrapped
+                .interiors()
+                .iter()
+                .filter_map(|ring| clip_ring(ring, min_x, max_x))
+                .collect();
+            let part = geo::Polygon::new(exterior, interiors)
+                .map_coords(|c| coord! { x: c.x - shift, y: c.y });
+            Some(from_geo(&part.orient(Direction::Default)))
+        })
+        .collect()
+}
+
+/// Clip a ring to the longitudes between `min_x` and `max_x` (Sutherland-Hodgman), `None` if nothing of an area is left
+fn clip_ring(ring: &LineString<f64>, min_x: f64, max_x: f64) -> Option<LineString<f64>> {
+    let clip = |points: Vec<geo::Coord<f64>>, bound: f64, inside: &dyn Fn(f64) -> bool| {
+        let mut clipped = Vec::with_capacity(points.len() + 2);
+        for (i, current) in points.iter().enumerate() {
+            let previous = points[(i + points.len() - 1) % points.len()];
+            let crossing = || coord! { x: bound, y: crossing_lat(previous, *current, bound) };
+            match (inside(previous.x), inside(current.x)) {
+                (true, true) => clipped.push(*current),
+                (true, false) => clipped.push(crossing()),
+                (false, true) => {
+                    clipped.push(crossing());
+                    clipped.push(*current);
+                }
+                (false, false) => {}
+            }
+        }
+        clipped
+    };
+
+    // NOTE: the closing point is left out while clipping
+    let mut points: Vec<geo::Coord<f64>> = ring.coords().copied().collect();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let points = clip(points, min_x, &|x| x >= min_x);
+    let mut points = clip(points, max_x, &|x| x <= max_x);
+    points.dedup();
+
+    let area_left = points.len() >= 3 && points.iter().any(|c| c.x != points[0].x);
+    area_left.then(|| LineString::new(points))
+}
+
+/// The latitude at which the great circle edge from `a` to `b` crosses the meridian `lon`
+///
+/// Near the poles the edges of zones bend far from a straight line in longitude/latitude, so the crossing is computed on the sphere.
+fn crossing_lat(a: geo::Coord<f64>, b: geo::Coord<f64>, lon: f64) -> f64 {
+    let lon_rad = lon.to_radians();
+    let normal = cross(unit(a.x, a.y), unit(b.x, b.y));
+    let meridian = [-lon_rad.sin(), lon_rad.cos(), 0.0];
+    let d = cross(normal, meridian);
+    let horizontal = d[0] * lon_rad.cos() + d[1] * lon_rad.sin();
+    let norm = (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt();
+
+    if norm < 1e-12 || horizontal.abs() < 1e-12 {
+        // NOTE: the edge runs along the meridian or through a pole, the straight line is exact
+        let t = (lon - a.x) / (b.x - a.x);
+        return a.y + t * (b.y - a.y);
+    }
+
+    // NOTE: of the two intersections of the great circles, the one on the meridian half at `lon` is taken
+    let sign = horizontal.signum();
+    (sign * d[2]).atan2(horizontal.abs()).to_degrees()
+}
+
+fn rings(polygon: &geo::Polygon<f64>) -> impl Iterator<Item = &LineString<f64>> {
+    std::iter::once(polygon.exterior()).chain(polygon.interiors())
+}
+
+/// The longitudes of a ring made continuous, edges along a pole keep their extent
+fn unwrap_ring(ring: &[Point]) -> Vec<geo::Coord<f64>> {
+    let mut unwrapped: Vec<geo::Coord<f64>> = Vec::with_capacity(ring.len());
+    let mut previous: Option<Point> = None;
+    for point in ring {
+        let lon = match (unwrapped.last(), previous) {
+            (Some(last), Some(prev)) => {
+                let mut delta = point.lon - prev.lon;
+                let along_pole = prev.lat.abs() == 90.0 && point.lat == prev.lat;
+                if !along_pole {
+                    if delta > 180.0 {
+                        delta -= 360.0;
+                    } else if delta < -180.0 {
+                        delta += 360.0;
+                    }
+                }
+                last.x + delta
+            }
+            _ => point.lon,
+        };
+        unwrapped.push(coord! { x: lon, y: point.lat });
+        previous = Some(*point);
+    }
+
+    // NOTE: the ring starts within ±180 degrees, DGGRID may return longitudes beyond
+    if let Some(first) = unwrapped.first().copied()
+        && !(-180.0..=180.0).contains(&first.x)
+    {
+        let shift = -((first.x + 180.0) / 360.0).floor() * 360.0;
+        unwrapped.iter_mut().for_each(|c| c.x += shift);
+    }
+    unwrapped
+}
+
+/// The unwrapped polygon as a `geo` polygon, an exterior around a pole is closed along it and holes are moved next to the exterior
+fn to_geo(polygon: &Polygon) -> geo::Polygon<f64> {
+    let mut exterior = unwrap_ring(&polygon.exterior);
+    if let (Some(pole), Some(first), Some(last)) = (
+        enclosed_pole(polygon),
+        exterior.first().copied(),
+        exterior.last().copied(),
+    ) {
+        exterior.push(coord! { x: last.x, y: pole });
+        exterior.push(coord! { x: first.x, y: pole });
+        exterior.push(first);
+    }
+
+    let start = exterior.first().map_or(0.0, |c| c.x);
+    let interiors = polygon
+        .interiors
+        .iter()
+        .map(|ring| {
+            let mut ring = unwrap_ring(ring);
+            if let Some(first) = ring.first().copied() {
+                let shift = ((start - first.x) / 360.0).round() * 360.0;
+                ring.iter_mut().for_each(|c| c.x += shift);
+            }
+            LineString::new(ring)
+        })
+        .collect();
+
+    geo::Polygon::new(LineString::new(exterior), interiors)
+}
+
+fn from_geo(polygon: &geo::Polygon<f64>) -> Polygon {
+    let ring = |ls: &LineString<f64>| ls.coords().map(|c| Point::new(c.y, c.x)).collect();
+    Polygon::new(
+        ring(polygon.exterior()),
+        polygon.interiors().iter().map(ring).collect(),
+    )
+}
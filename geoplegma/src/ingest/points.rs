@@ -0,0 +1,237 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Binning of point observations into the zones of a DGGRS.
+//!
+//! The points are read in batches, each batch is located in parallel with `DggrsApi::zone_ids_from_points` and reduced into one state per zone. The states of the batches are merged and finished into a coverage.
+
+use crate::api::DggrsApi;
+use crate::error::DggrsError;
+use crate::structures::coverage::Coverage;
+use crate::types::{Point, RefinementLevel, ZoneId, ZoneIdFormat};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The number of points read from the input before they are located.
+const BATCH_SIZE: usize = 1 << 16;
+
+/// The number of points located and reduced by one task.
+const CHUNK_SIZE: usize = 4096;
+
+/// Reduces the values of the points in a zone into one value.
+///
+/// The values are added to states, which are merged when the points of a zone were reduced in parallel, so `merge` has to be associative and commutative.
+pub trait Reducer<V>: Sync {
+    type State: Send;
+    type Output;
+
+    /// The state of a zone without points.
+    fn init(&self) -> Self::State;
+
+    fn add(&self, state: &mut Self::State, value: V);
+
+    fn merge(&self, state: &mut Self::State, other: Self::State);
+
+    fn finish(&self, state: Self::State) -> Self::Output;
+}
+
+/// The number of points.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Count;
+
+impl<V> Reducer<V> for Count {
+    type State = u64;
+    type Output = u64;
+
+    fn init(&self) -> u64 {
+        0
+    }
+
+    fn add(&self, state: &mut u64, _value: V) {
+        *state += 1;
+    }
+
+    fn merge(&self, state: &mut u64, other: u64) {
+        *state += other;
+    }
+
+    fn finish(&self, state: u64) -> u64 {
+        state
+    }
+}
+
+/// The sum of the values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sum;
+
+impl Reducer<f64> for Sum {
+    type State = f64;
+    type Output = f64;
+
+    fn init(&self) -> f64 {
+        0.0
+    }
+
+    fn add(&self, state: &mut f64, value: f64) {
+        *state += value;
+    }
+
+    fn merge(&self, state: &mut f64, other: f64) {
+        *state += other;
+    }
+
+    fn finish(&self, state: f64) -> f64 {
+        state
+    }
+}
+
+/// The arithmetic mean of the values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mean;
+
+impl Reducer<f64> for Mean {
+    type State = (f64, u64);
+    type Output = f64;
+
+    fn init(&self) -> (f64, u64) {
+        (0.0, 0)
+    }
+
+    fn add(&self, state: &mut (f64, u64), value: f64) {
+        state.0 += value;
+        state.1 += 1;
+    }
+
+    fn merge(&self, state: &mut (f64, u64), other: (f64, u64)) {
+        state.0 += other.0;
+        state.1 += other.1;
+    }
+
+    fn finish(&self, (sum, count): (f64, u64)) -> f64 {
+        sum / count as f64
+    }
+}
+
+/// The smallest value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Min;
+
+impl Reducer<f64> for Min {
+    type State = f64;
+    type Output = f64;
+
+    fn init(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    fn add(&self, state: &mut f64, value: f64) {
+        *state = state.min(value);
+    }
+
+    fn merge(&self, state: &mut f64, other: f64) {
+        *state = state.min(other);
+    }
+
+    fn finish(&self, state: f64) -> f64 {
+        state
+    }
+}
+
+/// The largest value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Max;
+
+impl Reducer<f64> for Max {
+    type State = f64;
+    type Output = f64;
+
+    fn init(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+
+    fn add(&self, state: &mut f64, value: f64) {
+        *state = state.max(value);
+    }
+
+    fn merge(&self, state: &mut f64, other: f64) {
+        *state = state.max(other);
+    }
+
+    fn finish(&self, state: f64) -> f64 {
+        state
+    }
+}
+
+/// Bin points into the zones at a refinement level and reduce their values into a sparse coverage with textual ZoneIds.
+///
+/// Only zones containing at least one point are set.
+pub fn aggregate_points<V, R>(
+    points: impl IntoIterator<Item = (Point, V)>,
+    dggrs: Arc<dyn DggrsApi>,
+    refinement_level: RefinementLevel,
+    reducer: &R,
+) -> Result<Coverage<R::Output>, DggrsError>
+where
+    V: Send,
+    R: Reducer<V>,
+{
+    let mut coverage = Coverage::sparse(dggrs.clone(), refinement_level)?;
+    let mut states: HashMap<ZoneId, R::State> = HashMap::new();
+    let mut points = points.into_iter().peekable();
+    while points.peek().is_some() {
+        let batch: Vec<(Point, V)> = points.by_ref().take(BATCH_SIZE).collect();
+        let batch_states = batch
+            .into_par_iter()
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| reduce_chunk(dggrs.as_ref(), refinement_level, reducer, chunk))
+            .try_reduce(HashMap::new, |a, b| Ok(merge_states(reducer, a, b)))?;
+        states = merge_states(reducer, states, batch_states);
+    }
+
+    for (zone_id, state) in states {
+        coverage.set(zone_id, reducer.finish(state))?;
+    }
+    Ok(coverage)
+}
+
+fn reduce_chunk<V, R: Reducer<V>>(
+    dggrs: &dyn DggrsApi,
+    refinement_level: RefinementLevel,
+    reducer: &R,
+    chunk: Vec<(Point, V)>,
+) -> Result<HashMap<ZoneId, R::State>, DggrsError> {
+    let points: Vec<Point> = chunk.iter().map(|(point, _)| *point).collect();
+    let zone_ids = dggrs.zone_ids_from_points(refinement_level, &points, ZoneIdFormat::Text)?;
+
+    let mut states = HashMap::new();
+    for (zone_id, (_, value)) in zone_ids.into_iter().zip(chunk) {
+        let state = states.entry(zone_id).or_insert_with(|| reducer.init());
+        reducer.add(state, value);
+    }
+    Ok(states)
+}
+
+/// Merge the smaller map of states into the larger one
+fn merge_states<V, R: Reducer<V>>(
+    reducer: &R,
+    a: HashMap<ZoneId, R::State>,
+    b: HashMap<ZoneId, R::State>,
+) -> HashMap<ZoneId, R::State> {
+    let (mut into, from) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    for (zone_id, state) in from {
+        match into.get_mut(&zone_id) {
+            Some(existing) => reducer.merge(existing, state),
+            None => {
+                into.insert(zone_id, state);
+            }
+        }
+    }
+    into
+}
@@ -0,0 +1,404 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Resampling of geo-referenced rasters into the zones of a DGGRS.
+//!
+//! The extent of the raster is processed in tiles of a few degrees, the zones of each tile are taken from `DggrsApi::zones_from_bbox`, so that only the zones of one tile are held in memory besides the coverage.
+//! Rasters in EPSG:4326 with longitudes from -180° to 180° or from 0° to 360° are supported.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::error::ingest::IngestError;
+use crate::geometry::Densification;
+use crate::structures::coverage::Coverage;
+use crate::types::{BoundingBox, Point, RefinementLevel, Zone};
+use geo::{Area, BooleanOps, BoundingRect, Translate};
+use std::sync::Arc;
+
+/// The longitude shifts under which a zone can overlap a raster
+const LON_SHIFTS: [f64; 3] = [0.0, 360.0, -360.0];
+
+/// An affine transform from pixel to longitude/latitude coordinates, in the order of a GDAL geotransform.
+///
+/// The pixel `(col, row)` has its upper left corner at `lon = origin_lon + col * pixel_width + row * row_rotation` and `lat = origin_lat + col * column_rotation + row * pixel_height`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineTransform {
+    pub origin_lon: f64,
+    pub pixel_width: f64,
+    pub row_rotation: f64,
+    pub origin_lat: f64,
+    pub column_rotation: f64,
+    /// Negative for rasters with the northernmost row first.
+    pub pixel_height: f64,
+}
+
+impl AffineTransform {
+    /// A transform without rotation.
+    pub fn new(origin_lon: f64, pixel_width: f64, origin_lat: f64, pixel_height: f64) -> Self {
+        Self::from_gdal([origin_lon, pixel_width, 0.0, origin_lat, 0.0, pixel_height])
+    }
+
+    /// A transform from the six coefficients of a GDAL geotransform.
+    pub fn from_gdal(coefficients: [f64; 6]) -> Self {
+        let [
+            origin_lon,
+            pixel_width,
+            row_rotation,
+            origin_lat,
+            column_rotation,
+            pixel_height,
+        ] = coefficients;
+        Self {
+            origin_lon,
+            pixel_width,
+            row_rotation,
+            origin_lat,
+            column_rotation,
+            pixel_height,
+        }
+    }
+
+    /// The longitude and latitude of fractional pixel coordinates.
+    pub fn apply(&self, col: f64, row: f64) -> (f64, f64) {
+        (
+            self.origin_lon + col * self.pixel_width + row * self.row_rotation,
+            self.origin_lat + col * self.column_rotation + row * self.pixel_height,
+        )
+    }
+
+    /// The fractional pixel coordinates of a longitude and latitude, `None` if the transform is not invertible.
+    pub fn invert(&self, lon: f64, lat: f64) -> Option<(f64, f64)> {
+        let det = self.pixel_width * self.pixel_height - self.row_rotation * self.column_rotation;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let (dx, dy) = (lon - self.origin_lon, lat - self.origin_lat);
+        Some((
+            (dx * self.pixel_height - dy * self.row_rotation) / det,
+            (dy * self.pixel_width - dx * self.column_rotation) / det,
+        ))
+    }
+}
+
+/// How the pixel values are aggregated into the value of a zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Resampling {
+    /// The value of the pixel containing the zone centroid.
+    #[default]
+    Nearest,
+    /// The value at the zone centroid interpolated between the centres of the four nearest pixels.
+    Bilinear,
+    /// The mean of the pixels overlapping the zone, weighted by the area of overlap.
+    AreaWeightedMean,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterIngestConfig {
+    pub resampling: Resampling,
+    /// The width and height of the tiles in degrees.
+    pub tile_size: f64,
+}
+
+impl Default for RasterIngestConfig {
+    fn default() -> Self {
+        Self {
+            resampling: Resampling::Nearest,
+            tile_size: 10.0,
+        }
+    }
+}
+
+/// A geo-referenced two dimensional array of values in EPSG:4326, stored row by row.
+#[derive(Debug, Clone, Copy)]
+pub struct Raster<'a> {
+    values: &'a [f64],
+    width: usize,
+    height: usize,
+    transform: AffineTransform,
+    nodata: Option<f64>,
+}
+
+impl<'a> Raster<'a> {
+    /// A raster of `width` columns and `height` rows in the CRS with the EPSG code `epsg`, which has to be 4326.
+    pub fn new(
+        values: &'a [f64],
+        width: usize,
+        height: usize,
+        transform: AffineTransform,
+        epsg: u32,
+    ) -> Result<Self, IngestError> {
+        if epsg != 4326 {
+            return Err(IngestError::UnsupportedCrs(epsg));
+        }
+        if values.len() != width * height {
+            return Err(IngestError::ShapeMismatch {
+                width,
+                height,
+                expected: width * height,
+                found: values.len(),
+            });
+        }
+        if transform.invert(0.0, 0.0).is_none() {
+            return Err(IngestError::SingularTransform);
+        }
+        Ok(Self {
+            values,
+            width,
+            height,
+            transform,
+            nodata: None,
+        })
+    }
+
+    /// Pixels with this value are skipped, pixels with NaN values are always skipped.
+    pub fn with_nodata(mut self, nodata: f64) -> Self {
+        self.nodata = Some(nodata);
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn transform(&self) -> AffineTransform {
+        self.transform
+    }
+
+    /// The value of a pixel, `None` outside the raster or for no-data.
+    pub fn get(&self, col: usize, row: usize) -> Option<f64> {
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+        let value = self.values[row * self.width + col];
+        if value.is_nan() || self.nodata == Some(value) {
+            return None;
+        }
+        Some(value)
+    }
+
+    /// The extent as `(min_lon, min_lat, max_lon, max_lat)` in the longitudes of the raster, which may exceed 180°.
+    pub fn extent(&self) -> (f64, f64, f64, f64) {
+        let (w, h) = (self.width as f64, self.height as f64);
+        let corners =
+            [(0.0, 0.0), (w, 0.0), (0.0, h), (w, h)].map(|(c, r)| self.transform.apply(c, r));
+        corners.iter().fold(
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(min_lon, min_lat, max_lon, max_lat), &(lon, lat)| {
+                (
+                    min_lon.min(lon),
+                    min_lat.min(lat),
+                    max_lon.max(lon),
+                    max_lat.max(lat),
+                )
+            },
+        )
+    }
+
+    /// The fractional pixel coordinates of a point, its longitude is shifted by 360° if the raster does not cover it otherwise.
+    fn pixel(&self, point: Point) -> Option<(f64, f64)> {
+        LON_SHIFTS.iter().find_map(|shift| {
+            let (col, row) = self.transform.invert(point.lon + shift, point.lat)?;
+            let inside =
+                (0.0..self.width as f64).contains(&col) && (0.0..self.height as f64).contains(&row);
+            inside.then_some((col, row))
+        })
+    }
+
+    fn nearest(&self, point: Point) -> Option<f64> {
+        let (col, row) = self.pixel(point)?;
+        self.get(col as usize, row as usize)
+    }
+
+    fn bilinear(&self, point: Point) -> Option<f64> {
+        let (col, row) = self.pixel(point)?;
+        // NOTE: pixel values are located at the pixel centres
+        let (x, y) = (col - 0.5, row - 0.5);
+        let (c0, r0) = (x.floor(), y.floor());
+        let (tx, ty) = (x - c0, y - r0);
+
+        let (mut sum, mut weights) = (0.0, 0.0);
+        for (dc, dr, weight) in [
+            (0.0, 0.0, (1.0 - tx) * (1.0 - ty)),
+            (1.0, 0.0, tx * (1.0 - ty)),
+            (0.0, 1.0, (1.0 - tx) * ty),
+            (1.0, 1.0, tx * ty),
+        ] {
+            let c = (c0 + dc).clamp(0.0, self.width as f64 - 1.0) as usize;
+            let r = (r0 + dr).clamp(0.0, self.height as f64 - 1.0) as usize;
+            if let Some(value) = self.get(c, r) {
+                sum += value * weight;
+                weights += weight;
+            }
+        }
+        (weights > 0.0).then(|| sum / weights)
+    }
+
+    fn area_weighted_mean(&self, region: &geo::MultiPolygon<f64>) -> Option<f64> {
+        let (mut sum, mut weights) = (0.0, 0.0);
+        for shift in LON_SHIFTS {
+            let region = region.translate(shift, 0.0);
+            let Some(rect) = region.bounding_rect() else {
+                continue;
+            };
+            let Some((cols, rows)) = self.pixel_range(rect) else {
+                continue;
+            };
+            for row in rows {
+                for col in cols.clone() {
+                    let Some(value) = self.get(col, row) else {
+                        continue;
+                    };
+                    let pixel = self.pixel_polygon(col, row);
+                    let overlap = region.intersection(&pixel).unsigned_area();
+                    if overlap > 0.0 {
+                        // NOTE: the overlap in square degrees is scaled to the area on the sphere at the pixel centre
+                        let (_, lat) = self.transform.apply(col as f64 + 0.5, row as f64 + 0.5);
+                        let weight = overlap * lat.to_radians().cos().max(0.0);
+                        sum += value * weight;
+                        weights += weight;
+                    }
+                }
+            }
+        }
+        (weights > 0.0).then(|| sum / weights)
+    }
+
+    /// The columns and rows of the pixels overlapping a rectangle, `None` if the raster does not overlap it
+    fn pixel_range(
+        &self,
+        rect: geo::Rect<f64>,
+    ) -> Option<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+        let corners = [
+            (rect.min().x, rect.min().y),
+            (rect.max().x, rect.min().y),
+            (rect.min().x, rect.max().y),
+            (rect.max().x, rect.max().y),
+        ];
+        let (mut min_col, mut min_row) = (f64::INFINITY, f64::INFINITY);
+        let (mut max_col, mut max_row) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for (lon, lat) in corners {
+            let (col, row) = self.transform.invert(lon, lat)?;
+            (min_col, max_col) = (min_col.min(col), max_col.max(col));
+            (min_row, max_row) = (min_row.min(row), max_row.max(row));
+        }
+        let clamp = |v: f64, n: usize| v.clamp(0.0, n as f64) as usize;
+        let cols = clamp(min_col.floor(), self.width)..clamp(max_col.ceil(), self.width);
+        let rows = clamp(min_row.floor(), self.height)..clamp(max_row.ceil(), self.height);
+        (!cols.is_empty() && !rows.is_empty()).then_some((cols, rows))
+    }
+
+    fn pixel_polygon(&self, col: usize, row: usize) -> geo::Polygon<f64> {
+        let (c, r) = (col as f64, row as f64);
+        let ring: Vec<(f64, f64)> = [(c, r), (c + 1.0, r), (c + 1.0, r + 1.0), (c, r + 1.0)]
+            .into_iter()
+            .map(|(c, r)| self.transform.apply(c, r))
+            .collect();
+        geo::Polygon::new(ring.into(), vec![])
+    }
+}
+
+/// Resample a raster into a sparse coverage at a refinement level.
+///
+/// Zones are only set if at least one pixel with a value contributes to them. The centroid decides for the nearest and bilinear resampling whether a zone is covered by the raster.
+pub fn ingest_raster(
+    raster: &Raster,
+    dggrs: Arc<dyn DggrsApi>,
+    refinement_level: RefinementLevel,
+    config: RasterIngestConfig,
+) -> Result<Coverage<f64>, DggrsError> {
+    if !(config.tile_size > 0.0 && config.tile_size.is_finite()) {
+        return Err(IngestError::InvalidTileSize(config.tile_size).into());
+    }
+    let area_weighted = config.resampling == Resampling::AreaWeightedMean;
+    let zone_config = DggrsApiConfig {
+        region: area_weighted,
+        center: true,
+        densification: if area_weighted {
+            Densification::default()
+        } else {
+            Densification::None
+        },
+        ..DggrsApiConfig::ids_only()
+    };
+
+    let mut coverage = Coverage::sparse(dggrs.clone(), refinement_level)?;
+    for tile in tiles(raster.extent(), config.tile_size) {
+        let zones = dggrs.zones_from_bbox(refinement_level, Some(tile), Some(zone_config))?;
+        for zone in zones.zones {
+            // NOTE: zones overlapping several tiles are returned for each of them
+            if coverage.contains(&zone.id) {
+                continue;
+            }
+            if let Some(value) = resample(raster, &zone, config.resampling) {
+                coverage.set(zone.id, value)?;
+            }
+        }
+    }
+    Ok(coverage)
+}
+
+fn resample(raster: &Raster, zone: &Zone, resampling: Resampling) -> Option<f64> {
+    match resampling {
+        Resampling::Nearest => raster.nearest(zone.center?),
+        Resampling::Bilinear => raster.bilinear(zone.center?),
+        Resampling::AreaWeightedMean => {
+            raster.area_weighted_mean(&zone.region.as_ref()?.to_geo_multi_polygon())
+        }
+    }
+}
+
+/// The extent of a raster in tiles of `tile_size` degrees, with longitudes wrapped into [-180, 180]
+fn tiles(
+    (min_lon, min_lat, max_lon, max_lat): (f64, f64, f64, f64),
+    tile_size: f64,
+) -> Vec<BoundingBox> {
+    let (min_lat, max_lat) = (min_lat.max(-90.0), max_lat.min(90.0));
+    let (min_lon, max_lon) = if max_lon - min_lon >= 360.0 {
+        (-180.0, 180.0)
+    } else {
+        (min_lon, max_lon)
+    };
+
+    let mut tiles = Vec::new();
+    let mut lat = min_lat;
+    while lat < max_lat {
+        let next_lat = (lat + tile_size).min(max_lat);
+        let mut lon = min_lon;
+        while lon < max_lon {
+            let next_lon = (lon + tile_size).min(max_lon);
+            let west = wrap_lon(lon);
+            // NOTE: a tile ending at the antimeridian ends at 180°, not at -180°
+            let east = wrap_lon(next_lon);
+            let east = if east == -180.0 { 180.0 } else { east };
+            tiles.push(BoundingBox::new(west, lat, east, next_lat));
+            lon = next_lon;
+        }
+        lat = next_lat;
+    }
+    tiles
+}
+
+/// A longitude in [-180, 180)
+fn wrap_lon(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
@@ -0,0 +1,252 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Spatial joins of two collections of `geo` geometries through the zones of a DGGRS.
+//!
+//! Every geometry is indexed by the zones it touches at one refinement level: points by the zone containing them, lines and polygons by all zones whose region intersects them.
+//! Two geometries that intersect share at least one zone, so pairs sharing a zone are the candidates of the join, which are refined with the exact `geo` predicates.
+//! Geometries are given in longitude/latitude within ±180° and must not cross the antimeridian.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::error::structures::StructureError;
+use crate::geometry::Densification;
+use crate::structures::check_level;
+use crate::types::{BoundingBox, Point, RefinementLevel, ZoneId, ZoneIdFormat};
+use geo::{BoundingRect, Contains, CoordsIter, Geometry, Intersects, Within};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// The smallest extent of the bounding box used to cover a geometry, so that horizontal and vertical lines are covered as well
+const MIN_EXTENT: f64 = 1e-9;
+
+/// The relation that the geometries of a pair have to satisfy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinPredicate {
+    #[default]
+    Intersects,
+    /// The left geometry contains the right one.
+    Contains,
+    /// The left geometry is within the right one.
+    Within,
+}
+
+impl JoinPredicate {
+    pub fn evaluate(&self, left: &Geometry<f64>, right: &Geometry<f64>) -> bool {
+        match self {
+            Self::Intersects => left.intersects(right),
+            Self::Contains => left.contains(right),
+            Self::Within => left.is_within(right),
+        }
+    }
+}
+
+/// A pair of the join, the positions of a left geometry and of a right geometry in the index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JoinPair {
+    pub left: usize,
+    pub right: usize,
+}
+
+/// Geometries indexed by the zones they touch at one refinement level.
+pub struct ZoneJoinIndex {
+    dggrs: Arc<dyn DggrsApi>,
+    refinement_level: RefinementLevel,
+    geometries: Vec<Geometry<f64>>,
+    zones: BTreeMap<ZoneId, Vec<usize>>,
+}
+
+impl ZoneJoinIndex {
+    pub fn new(
+        dggrs: Arc<dyn DggrsApi>,
+        refinement_level: RefinementLevel,
+    ) -> Result<Self, DggrsError> {
+        check_level(dggrs.as_ref(), refinement_level)?;
+        Ok(Self {
+            dggrs,
+            refinement_level,
+            geometries: Vec::new(),
+            zones: BTreeMap::new(),
+        })
+    }
+
+    /// Index all geometries, their positions are their order in `geometries`.
+    pub fn from_geometries(
+        dggrs: Arc<dyn DggrsApi>,
+        refinement_level: RefinementLevel,
+        geometries: impl IntoIterator<Item = Geometry<f64>>,
+    ) -> Result<Self, DggrsError> {
+        let mut index = Self::new(dggrs, refinement_level)?;
+        for geometry in geometries {
+            index.insert(geometry)?;
+        }
+        Ok(index)
+    }
+
+    /// Index a geometry and return its position.
+    pub fn insert(&mut self, geometry: Geometry<f64>) -> Result<usize, DggrsError> {
+        let position = self.geometries.len();
+        for zone_id in self.zone_cover(&geometry)? {
+            self.zones.entry(zone_id).or_default().push(position);
+        }
+        self.geometries.push(geometry);
+        Ok(position)
+    }
+
+    pub fn dggrs(&self) -> &dyn DggrsApi {
+        self.dggrs.as_ref()
+    }
+
+    pub fn refinement_level(&self) -> RefinementLevel {
+        self.refinement_level
+    }
+
+    pub fn get(&self, position: usize) -> Option<&Geometry<f64>> {
+        self.geometries.get(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.geometries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.geometries.is_empty()
+    }
+
+    /// The positions of the geometries indexed in a zone.
+    pub fn in_zone(&self, zone_id: &ZoneId) -> &[usize] {
+        self.zones.get(zone_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The zones a geometry touches at the refinement level of the index.
+    ///
+    /// Points are located with `DggrsApi::zone_ids_from_points`, lines and polygons are covered by the zones of their bounding box whose region intersects them.
+    pub fn zone_cover(&self, geometry: &Geometry<f64>) -> Result<BTreeSet<ZoneId>, DggrsError> {
+        let mut cover = BTreeSet::new();
+        match geometry {
+            Geometry::Point(_) | Geometry::MultiPoint(_) => {
+                let points: Vec<Point> = geometry
+                    .coords_iter()
+                    .map(|c| Point::new(c.y, c.x))
+                    .collect();
+                cover.extend(self.dggrs.zone_ids_from_points(
+                    self.refinement_level,
+                    &points,
+                    ZoneIdFormat::Text,
+                )?);
+            }
+            Geometry::GeometryCollection(collection) => {
+                for part in collection {
+                    cover.append(&mut self.zone_cover(part)?);
+                }
+            }
+            _ => {
+                let Some(rect) = geometry.bounding_rect() else {
+                    return Ok(cover);
+                };
+                let pad = |min: f64, max: f64, limit: f64| {
+                    let margin = (MIN_EXTENT - (max - min)).max(0.0) / 2.0;
+                    ((min - margin).max(-limit), (max + margin).min(limit))
+                };
+                let (min_lon, max_lon) = pad(rect.min().x, rect.max().x, 180.0);
+                let (min_lat, max_lat) = pad(rect.min().y, rect.max().y, 90.0);
+                let bbox = BoundingBox::new(min_lon, min_lat, max_lon, max_lat);
+                let config = DggrsApiConfig {
+                    region: true,
+                    densification: Densification::default(),
+                    ..DggrsApiConfig::ids_only()
+                };
+                for zone in self
+                    .dggrs
+                    .zones_from_bbox(self.refinement_level, Some(bbox), Some(config))?
+                    .zones
+                {
+                    let region = zone
+                        .region
+                        .as_ref()
+                        .ok_or_else(|| DggrsError::MissingZoneGeometry(zone.id.to_string()))?;
+                    if region.to_geo_multi_polygon().intersects(geometry) {
+                        cover.insert(zone.id);
+                    }
+                }
+            }
+        }
+        Ok(cover)
+    }
+
+    /// The positions of the indexed geometries that share a zone with `geometry`, in ascending order.
+    pub fn candidates(&self, geometry: &Geometry<f64>) -> Result<Vec<usize>, DggrsError> {
+        let mut candidates: Vec<usize> = self
+            .zone_cover(geometry)?
+            .iter()
+            .flat_map(|zone_id| self.in_zone(zone_id).iter().copied())
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        Ok(candidates)
+    }
+
+    /// The positions of the indexed geometries that satisfy the predicate with `geometry` on the left.
+    pub fn query(
+        &self,
+        geometry: &Geometry<f64>,
+        predicate: JoinPredicate,
+    ) -> Result<Vec<usize>, DggrsError> {
+        Ok(self
+            .candidates(geometry)?
+            .into_iter()
+            .filter(|&position| predicate.evaluate(geometry, &self.geometries[position]))
+            .collect())
+    }
+}
+
+/// All pairs of geometries of two indexes that share a zone, ordered by the left and then the right position.
+///
+/// Both indexes have to be built with the same DGGRS and refinement level.
+pub fn candidate_pairs(
+    left: &ZoneJoinIndex,
+    right: &ZoneJoinIndex,
+) -> Result<BTreeSet<JoinPair>, DggrsError> {
+    if left.dggrs.uid() != right.dggrs.uid() {
+        return Err(StructureError::DggrsMismatch {
+            expected: left.dggrs.uid(),
+            found: right.dggrs.uid(),
+        }
+        .into());
+    }
+    if left.refinement_level != right.refinement_level {
+        return Err(StructureError::RefinementLevelMismatch {
+            expected: left.refinement_level,
+            found: right.refinement_level,
+        }
+        .into());
+    }
+    let mut pairs = BTreeSet::new();
+    for (zone_id, lefts) in &left.zones {
+        let rights = right.in_zone(zone_id);
+        for &l in lefts {
+            pairs.extend(rights.iter().map(|&r| JoinPair { left: l, right: r }));
+        }
+    }
+    Ok(pairs)
+}
+
+/// The candidate pairs of two indexes that satisfy the predicate.
+pub fn spatial_join(
+    left: &ZoneJoinIndex,
+    right: &ZoneJoinIndex,
+    predicate: JoinPredicate,
+) -> Result<Vec<JoinPair>, DggrsError> {
+    Ok(candidate_pairs(left, right)?
+        .into_iter()
+        .filter(|pair| {
+            predicate.evaluate(&left.geometries[pair.left], &right.geometries[pair.right])
+        })
+        .collect())
+}
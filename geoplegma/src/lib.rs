@@ -8,11 +8,18 @@
 // except according to those terms.
 
 #![doc = include_str!("../../README.md")]
+pub mod abstraction;
 pub mod adapters;
 pub mod api;
 pub mod constants;
+pub mod encoding;
 pub mod error;
 pub mod factory;
+pub mod geometry;
+pub mod ingest;
+pub mod join;
+pub mod structures;
+pub mod translate;
 pub mod types;
 
 pub use api::DggrsApiConfig as config;
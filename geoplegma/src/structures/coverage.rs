@@ -0,0 +1,394 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A coverage maps the zones of a DGGRS at one refinement level to values.
+//!
+//! A coverage is bound to a `DggrsApi`, e.g. from `factory::get`, and stores its values either densely for all zones of an extent or sparsely for the zones that were set.
+//! Optionally the zones are partitioned into blocks, their ancestors at a coarser block level, so that large coverages can be managed and encoded block by block.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::error::structures::StructureError;
+use crate::geometry::bounding_box;
+use crate::structures::check_level;
+use crate::types::{BoundingBox, DggrsUid, RefinementLevel, ZoneId, ZoneIdFormat};
+use geo::MultiPolygon;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// How the values of a coverage are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum CoverageStorage {
+    /// One slot for every zone of the extent, zones outside of it cannot be set.
+    Dense,
+    /// Only the zones that were set, any zone at the refinement level can be set.
+    Sparse,
+}
+
+/// The meta-data identifying the DGGRS, the refinement levels and the data type of a coverage.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageMetadata {
+    pub dggrs: DggrsUid,
+    pub refinement_level: RefinementLevel,
+    /// The refinement level of the blocks, `None` if the coverage is not partitioned.
+    pub block_level: Option<RefinementLevel>,
+    /// The extent of a dense coverage, `None` for the whole world or a sparse coverage.
+    pub extent: Option<BoundingBox>,
+    pub storage: CoverageStorage,
+    /// The name of the type of the values, see `CoverageValue`.
+    pub data_type: String,
+}
+
+/// A type of the values of a coverage, identified in the meta-data by its name.
+pub trait CoverageValue {
+    /// The name of the type, e.g. `f64`.
+    const DATA_TYPE: &'static str;
+}
+
+macro_rules! coverage_value {
+    ($($t:ty),*) => {$(
+        impl CoverageValue for $t {
+            const DATA_TYPE: &'static str = stringify!($t);
+        }
+    )*};
+}
+
+coverage_value!(
+    bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, String
+);
+
+enum Storage<T> {
+    /// The ZoneIds are sorted, the values are at the same positions.
+    Dense {
+        zone_ids: Vec<ZoneId>,
+        values: Vec<Option<T>>,
+    },
+    Sparse(BTreeMap<ZoneId, T>),
+}
+
+/// Values of type `T` for the zones of a DGGRS at one refinement level.
+///
+/// ZoneIds are used as given, so all ZoneIds of a coverage should be in the same format, see `DggrsApiConfig::preferred_id_format`.
+pub struct Coverage<T> {
+    dggrs: Arc<dyn DggrsApi>,
+    refinement_level: RefinementLevel,
+    extent: Option<BoundingBox>,
+    storage: Storage<T>,
+    block_level: Option<RefinementLevel>,
+    blocks: BTreeMap<ZoneId, BTreeSet<ZoneId>>,
+    zone_blocks: BTreeMap<ZoneId, ZoneId>,
+}
+
+impl<T> Coverage<T> {
+    /// An empty coverage in which any zone at the refinement level can be set.
+    pub fn sparse(
+        dggrs: Arc<dyn DggrsApi>,
+        refinement_level: RefinementLevel,
+    ) -> Result<Self, DggrsError> {
+        check_level(dggrs.as_ref(), refinement_level)?;
+        Ok(Self {
+            dggrs,
+            refinement_level,
+            extent: None,
+            storage: Storage::Sparse(BTreeMap::new()),
+            block_level: None,
+            blocks: BTreeMap::new(),
+            zone_blocks: BTreeMap::new(),
+        })
+    }
+
+    /// An empty coverage with a slot for every zone in the extent. If no extent is supplied the whole world is taken.
+    pub fn dense(
+        dggrs: Arc<dyn DggrsApi>,
+        refinement_level: RefinementLevel,
+        extent: Option<BoundingBox>,
+    ) -> Result<Self, DggrsError> {
+        check_level(dggrs.as_ref(), refinement_level)?;
+        let mut zone_ids: Vec<ZoneId> = dggrs
+            .zones_from_bbox(refinement_level, extent, Some(DggrsApiConfig::ids_only()))?
+            .zones
+            .into_iter()
+            .map(|zone| zone.id)
+            .collect();
+        zone_ids.sort();
+        zone_ids.dedup();
+
+        let values = zone_ids.iter().map(|_| None).collect();
+        Ok(Self {
+            dggrs,
+            refinement_level,
+            extent,
+            storage: Storage::Dense { zone_ids, values },
+            block_level: None,
+            blocks: BTreeMap::new(),
+            zone_blocks: BTreeMap::new(),
+        })
+    }
+
+    /// Partition the coverage into blocks, the ancestors of its zones at `block_level`.
+    ///
+    /// The zones of a dense coverage are all partitioned, those of a sparse coverage as they are set.
+    pub fn with_blocks(mut self, block_level: RefinementLevel) -> Result<Self, DggrsError> {
+        if block_level > self.refinement_level {
+            return Err(StructureError::BlockLevelTooFine {
+                block_level,
+                refinement_level: self.refinement_level,
+            }
+            .into());
+        }
+        check_level(self.dggrs.as_ref(), block_level)?;
+
+        self.block_level = Some(block_level);
+        self.blocks.clear();
+        self.zone_blocks.clear();
+        let zone_ids: Vec<ZoneId> = self.zone_ids().cloned().collect();
+        for zone_id in zone_ids {
+            self.insert_block(&zone_id)?;
+        }
+        Ok(self)
+    }
+
+    /// The DGGRS the zones of the coverage belong to.
+    pub fn dggrs(&self) -> &dyn DggrsApi {
+        self.dggrs.as_ref()
+    }
+
+    pub(crate) fn shared_dggrs(&self) -> Arc<dyn DggrsApi> {
+        self.dggrs.clone()
+    }
+
+    /// The refinement level of the zones of the coverage.
+    pub fn refinement_level(&self) -> RefinementLevel {
+        self.refinement_level
+    }
+
+    /// The refinement level of the blocks, `None` if the coverage is not partitioned.
+    pub fn block_level(&self) -> Option<RefinementLevel> {
+        self.block_level
+    }
+
+    /// The extent of a dense coverage, `None` for the whole world or a sparse coverage. See `compute_extent` for the extent of the zones with values.
+    pub fn extent(&self) -> Option<BoundingBox> {
+        self.extent
+    }
+
+    pub fn storage(&self) -> CoverageStorage {
+        match self.storage {
+            Storage::Dense { .. } => CoverageStorage::Dense,
+            Storage::Sparse(_) => CoverageStorage::Sparse,
+        }
+    }
+
+    pub fn get(&self, zone_id: &ZoneId) -> Option<&T> {
+        match &self.storage {
+            Storage::Dense { zone_ids, values } => zone_ids
+                .binary_search(zone_id)
+                .ok()
+                .and_then(|i| values[i].as_ref()),
+            Storage::Sparse(map) => map.get(zone_id),
+        }
+    }
+
+    pub fn get_mut(&mut self, zone_id: &ZoneId) -> Option<&mut T> {
+        match &mut self.storage {
+            Storage::Dense { zone_ids, values } => zone_ids
+                .binary_search(zone_id)
+                .ok()
+                .and_then(|i| values[i].as_mut()),
+            Storage::Sparse(map) => map.get_mut(zone_id),
+        }
+    }
+
+    /// Set the value of a zone and return the previous one.
+    ///
+    /// In a sparse coverage the zone is validated and has to be at the refinement level of the coverage, in a dense coverage it has to be within the extent.
+    pub fn set(&mut self, zone_id: ZoneId, value: T) -> Result<Option<T>, DggrsError> {
+        match &mut self.storage {
+            Storage::Dense { zone_ids, values } => {
+                let i = zone_ids
+                    .binary_search(&zone_id)
+                    .map_err(|_| StructureError::OutsideCoverage(zone_id.to_string()))?;
+                Ok(values[i].replace(value))
+            }
+            Storage::Sparse(map) => {
+                if !map.contains_key(&zone_id) {
+                    let info = self.dggrs.validate_zone(&zone_id)?;
+                    if info.refinement_level != self.refinement_level {
+                        return Err(StructureError::LevelMismatch {
+                            zone_id: zone_id.to_string(),
+                            expected: self.refinement_level,
+                            found: info.refinement_level,
+                        }
+                        .into());
+                    }
+                }
+                let previous = map.insert(zone_id.clone(), value);
+                if previous.is_none() {
+                    self.insert_block(&zone_id)?;
+                }
+                Ok(previous)
+            }
+        }
+    }
+
+    /// Remove the value of a zone, the slot of a dense coverage is kept.
+    pub fn remove(&mut self, zone_id: &ZoneId) -> Option<T> {
+        match &mut self.storage {
+            Storage::Dense { zone_ids, values } => zone_ids
+                .binary_search(zone_id)
+                .ok()
+                .and_then(|i| values[i].take()),
+            Storage::Sparse(map) => {
+                let value = map.remove(zone_id)?;
+                if let Some(block) = self.zone_blocks.remove(zone_id)
+                    && let Some(zones) = self.blocks.get_mut(&block)
+                {
+                    zones.remove(zone_id);
+                    if zones.is_empty() {
+                        self.blocks.remove(&block);
+                    }
+                }
+                Some(value)
+            }
+        }
+    }
+
+    /// Whether the zone has a value.
+    pub fn contains(&self, zone_id: &ZoneId) -> bool {
+        self.get(zone_id).is_some()
+    }
+
+    /// The number of zones with a value.
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Dense { values, .. } => values.iter().filter(|v| v.is_some()).count(),
+            Storage::Sparse(map) => map.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The zones of the coverage in ZoneId order, for a dense coverage all zones of the extent whether they have a value or not.
+    pub fn zone_ids(&self) -> impl Iterator<Item = &ZoneId> {
+        let (dense, sparse) = match &self.storage {
+            Storage::Dense { zone_ids, .. } => (Some(zone_ids.iter()), None),
+            Storage::Sparse(map) => (None, Some(map.keys())),
+        };
+        dense
+            .into_iter()
+            .flatten()
+            .chain(sparse.into_iter().flatten())
+    }
+
+    /// The format of the ZoneIds, taken from the first zone, `ZoneIdFormat::Text` if the coverage has no zones.
+    pub fn zone_id_format(&self) -> ZoneIdFormat {
+        self.zone_ids()
+            .next()
+            .map_or(ZoneIdFormat::Text, ZoneId::format)
+    }
+
+    /// The zones with a value and their values in ZoneId order.
+    pub fn iter(&self) -> impl Iterator<Item = (&ZoneId, &T)> {
+        let (dense, sparse) = match &self.storage {
+            Storage::Dense { zone_ids, values } => (
+                Some(
+                    zone_ids
+                        .iter()
+                        .zip(values)
+                        .filter_map(|(id, v)| v.as_ref().map(|v| (id, v))),
+                ),
+                None,
+            ),
+            Storage::Sparse(map) => (None, Some(map.iter())),
+        };
+        dense
+            .into_iter()
+            .flatten()
+            .chain(sparse.into_iter().flatten())
+    }
+
+    /// The ZoneIds of the blocks in ZoneId order, empty if the coverage is not partitioned.
+    pub fn blocks(&self) -> impl Iterator<Item = &ZoneId> {
+        self.blocks.keys()
+    }
+
+    /// The block a zone of the coverage belongs to.
+    pub fn block_of(&self, zone_id: &ZoneId) -> Option<&ZoneId> {
+        self.zone_blocks.get(zone_id)
+    }
+
+    /// The zones with a value in a block and their values in ZoneId order.
+    pub fn block(&self, block_id: &ZoneId) -> impl Iterator<Item = (&ZoneId, &T)> {
+        self.blocks
+            .get(block_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|zone_id| self.get(zone_id).map(|value| (zone_id, value)))
+    }
+
+    /// The bounding box of the regions of the zones with a value, it wraps around the antimeridian if the zones cross it.
+    pub fn compute_extent(&self) -> Result<Option<BoundingBox>, DggrsError> {
+        let config = DggrsApiConfig {
+            region: true,
+            ..DggrsApiConfig::ids_only()
+        };
+        let mut polygons = Vec::new();
+        for (zone_id, _) in self.iter() {
+            for zone in self
+                .dggrs
+                .zone_from_id(zone_id.clone(), Some(config))?
+                .zones
+            {
+                if let Some(region) = zone.region {
+                    polygons.extend(region.to_geo_multi_polygon());
+                }
+            }
+        }
+        Ok(bounding_box(&MultiPolygon::new(polygons)))
+    }
+
+    fn insert_block(&mut self, zone_id: &ZoneId) -> Result<(), DggrsError> {
+        let Some(block_level) = self.block_level else {
+            return Ok(());
+        };
+        let block = self.dggrs.ancestor_at_level(zone_id, block_level)?;
+        self.blocks
+            .entry(block.clone())
+            .or_default()
+            .insert(zone_id.clone());
+        self.zone_blocks.insert(zone_id.clone(), block);
+        Ok(())
+    }
+}
+
+impl<T: CoverageValue> Coverage<T> {
+    /// The name of the type of the values.
+    pub fn data_type(&self) -> &'static str {
+        T::DATA_TYPE
+    }
+
+    pub fn metadata(&self) -> CoverageMetadata {
+        CoverageMetadata {
+            dggrs: self.dggrs.uid(),
+            refinement_level: self.refinement_level,
+            block_level: self.block_level,
+            extent: self.extent,
+            storage: self.storage(),
+            data_type: T::DATA_TYPE.to_string(),
+        }
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An in-memory index of records keyed by the zones of a DGGRS, at any refinement level.
+//!
+//! The zones are ordered along the hierarchy of the DGGRS with `DggrsApi::hierarchy_key`, i.e. by the Z3 and Z7 digits of DGGRID, the index bits of H3 and the sub-zone indices of DGGAL.
+//! In this order the descendants of any zone follow the zone itself, so all records inside a zone are found with one range scan.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::geometry::edge_length;
+use crate::types::{HierarchyKey, Point, RefinementLevel, Zone, ZoneId, ZoneIdFormat};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+/// Records of type `V` keyed by zones, several records can be kept for the same zone.
+///
+/// Zones are identified by their position in the hierarchy, so the same zone in different ZoneId formats is one key. The records of a zone keep the ZoneId of the first record.
+pub struct ZoneIndex<V> {
+    dggrs: Arc<dyn DggrsApi>,
+    entries: BTreeMap<HierarchyKey, (ZoneId, Vec<V>)>,
+    len: usize,
+    /// The largest number of digits of the keys, i.e. the finest refinement level relative to the root zones
+    depth: usize,
+}
+
+impl<V> ZoneIndex<V> {
+    pub fn new(dggrs: Arc<dyn DggrsApi>) -> Self {
+        Self {
+            dggrs,
+            entries: BTreeMap::new(),
+            len: 0,
+            depth: 0,
+        }
+    }
+
+    /// Build an index from many records, the keys are computed in parallel and the records are sorted once.
+    ///
+    /// The records of a zone keep their order.
+    pub fn bulk_load(
+        dggrs: Arc<dyn DggrsApi>,
+        records: impl IntoIterator<Item = (ZoneId, V)>,
+    ) -> Result<Self, DggrsError>
+    where
+        V: Send,
+    {
+        let records: Vec<(ZoneId, V)> = records.into_iter().collect();
+        let mut keyed = records
+            .into_par_iter()
+            .map(|(zone_id, value)| Ok((key(dggrs.as_ref(), &zone_id)?, zone_id, value)))
+            .collect::<Result<Vec<_>, DggrsError>>()?;
+        // NOTE: the sort is stable, which keeps the order of the records of a zone
+        keyed.par_sort_by(|a, b| a.0.cmp(&b.0));
+
+        let len = keyed.len();
+        let depth = keyed
+            .iter()
+            .map(|(k, ..)| k.digits.len())
+            .max()
+            .unwrap_or(0);
+        let mut grouped: Vec<(HierarchyKey, (ZoneId, Vec<V>))> = Vec::new();
+        for (key, zone_id, value) in keyed {
+            match grouped.last_mut() {
+                Some((last, (_, values))) if *last == key => values.push(value),
+                _ => grouped.push((key, (zone_id, vec![value]))),
+            }
+        }
+        Ok(Self {
+            dggrs,
+            entries: grouped.into_iter().collect(),
+            len,
+            depth,
+        })
+    }
+
+    pub fn dggrs(&self) -> &dyn DggrsApi {
+        self.dggrs.as_ref()
+    }
+
+    pub fn insert(&mut self, zone_id: ZoneId, value: V) -> Result<(), DggrsError> {
+        let key = self.key(&zone_id)?;
+        self.depth = self.depth.max(key.digits.len());
+        self.entries
+            .entry(key)
+            .or_insert_with(|| (zone_id, Vec::new()))
+            .1
+            .push(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove all records of a zone and return them.
+    pub fn remove(&mut self, zone_id: &ZoneId) -> Result<Vec<V>, DggrsError> {
+        let key = self.key(zone_id)?;
+        let values = self
+            .entries
+            .remove(&key)
+            .map_or(Vec::new(), |(_, values)| values);
+        self.len -= values.len();
+        Ok(values)
+    }
+
+    /// The records of a zone, without those of its descendants.
+    pub fn get(&self, zone_id: &ZoneId) -> Result<&[V], DggrsError> {
+        let key = self.key(zone_id)?;
+        Ok(self
+            .entries
+            .get(&key)
+            .map_or(&[], |(_, values)| values.as_slice()))
+    }
+
+    /// The number of records.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of distinct zones with records.
+    pub fn zone_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// All records in the order of the hierarchy.
+    pub fn iter(&self) -> impl Iterator<Item = (&ZoneId, &V)> {
+        self.entries
+            .values()
+            .flat_map(|(zone_id, values)| values.iter().map(move |value| (zone_id, value)))
+    }
+
+    /// The records of a zone and of all its descendants at any finer refinement level, in the order of the hierarchy.
+    ///
+    /// Descendants are the zones whose primary parents lead to the ancestor, see `DggrsApi::ancestor_at_level`.
+    pub fn descendants(
+        &self,
+        ancestor: &ZoneId,
+    ) -> Result<impl Iterator<Item = (&ZoneId, &V)>, DggrsError> {
+        let ancestor = self.key(ancestor)?;
+        Ok(self
+            .subtree(ancestor)
+            .flat_map(|(zone_id, values)| values.iter().map(move |value| (zone_id, value))))
+    }
+
+    /// The zone with records whose centroid is closest to a point, with its records.
+    ///
+    /// The zone containing the point and its neighbours are searched, from the finest refinement level of the index to coarser ones until they hold records.
+    /// At the coarsest level the ring of neighbours is widened until it reaches zones with records, so only the centroids of the records in one ring are computed.
+    pub fn nearest(&self, point: Point) -> Result<Option<(&ZoneId, &[V])>, DggrsError> {
+        if self.entries.is_empty() {
+            return Ok(None);
+        }
+        let min_level = self.dggrs.min_refinement_level()?;
+        let finest_level = RefinementLevel::new(i32::from(min_level) + self.depth as i32)?;
+        let config = DggrsApiConfig {
+            neighbors: true,
+            ..DggrsApiConfig::ids_only()
+        };
+        let with_neighbors = |zones: Vec<Zone>| -> Vec<ZoneId> {
+            zones
+                .into_iter()
+                .flat_map(|zone| {
+                    std::iter::once(zone.id).chain(zone.neighbors.into_iter().flatten())
+                })
+                .collect()
+        };
+
+        let mut level = finest_level;
+        let mut ring = loop {
+            let ring = with_neighbors(
+                self.dggrs
+                    .zone_from_point(level, point, Some(config))?
+                    .zones,
+            );
+            if level <= min_level {
+                break ring;
+            }
+            let candidates = self.candidates(&ring)?;
+            if !candidates.is_empty() {
+                return self.closest(point, candidates);
+            }
+            level = RefinementLevel::new(i32::from(level) - 1)?;
+        };
+
+        let mut visited: HashSet<ZoneId> = ring.iter().cloned().collect();
+        loop {
+            let candidates = self.candidates(&ring)?;
+            if !candidates.is_empty() {
+                return self.closest(point, candidates);
+            }
+            let mut next = Vec::new();
+            for zone_id in ring {
+                for neighbor in
+                    with_neighbors(self.dggrs.zone_from_id(zone_id, Some(config))?.zones)
+                {
+                    if visited.insert(neighbor.clone()) {
+                        next.push(neighbor);
+                    }
+                }
+            }
+            if next.is_empty() {
+                // NOTE: all zones at the coarsest level were visited
+                return Ok(None);
+            }
+            ring = next;
+        }
+    }
+
+    /// The entries of the zones and of their descendants
+    fn candidates(&self, zone_ids: &[ZoneId]) -> Result<Vec<&(ZoneId, Vec<V>)>, DggrsError> {
+        let mut candidates = Vec::new();
+        for zone_id in zone_ids {
+            candidates.extend(self.subtree(self.key(zone_id)?));
+        }
+        Ok(candidates)
+    }
+
+    /// The entries of a zone and its descendants, one range of the ordered keys
+    fn subtree(&self, ancestor: HierarchyKey) -> impl Iterator<Item = &(ZoneId, Vec<V>)> {
+        self.entries
+            .range(ancestor.clone()..)
+            .take_while(move |(key, _)| key.starts_with(&ancestor))
+            .map(|(_, entry)| entry)
+    }
+
+    fn closest<'a>(
+        &self,
+        point: Point,
+        candidates: Vec<&'a (ZoneId, Vec<V>)>,
+    ) -> Result<Option<(&'a ZoneId, &'a [V])>, DggrsError> {
+        let mut closest: Option<(f64, &ZoneId, &[V])> = None;
+        for (zone_id, values) in candidates {
+            let center = self
+                .dggrs
+                .zone_info(zone_id)?
+                .center
+                .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string()))?;
+            let distance = edge_length(point, center);
+            if closest.is_none_or(|(d, ..)| distance < d) {
+                closest = Some((distance, zone_id, values.as_slice()));
+            }
+        }
+        Ok(closest.map(|(_, zone_id, values)| (zone_id, values)))
+    }
+
+    fn key(&self, zone_id: &ZoneId) -> Result<HierarchyKey, DggrsError> {
+        key(self.dggrs.as_ref(), zone_id)
+    }
+}
+
+/// The hierarchy key with the root zone in the text format, so that ZoneIds in any format are ordered together
+fn key(dggrs: &dyn DggrsApi, zone_id: &ZoneId) -> Result<HierarchyKey, DggrsError> {
+    let mut key = dggrs.hierarchy_key(zone_id)?;
+    if key.root_zone.format() != ZoneIdFormat::Text {
+        key.root_zone = dggrs.format_zone_id(&key.root_zone, ZoneIdFormat::Text)?;
+    }
+    Ok(key)
+}
@@ -0,0 +1,37 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Data structures whose values and geometries are keyed by the zones of a DGGRS.
+
+pub mod coverage;
+pub mod index;
+pub mod pyramid;
+pub mod vector;
+
+use crate::api::DggrsApi;
+use crate::error::DggrsError;
+use crate::error::structures::StructureError;
+use crate::types::RefinementLevel;
+
+/// Check that the refinement level of a data structure is within the range of the DGGRS
+pub(crate) fn check_level(
+    dggrs: &dyn DggrsApi,
+    refinement_level: RefinementLevel,
+) -> Result<(), DggrsError> {
+    let (min, max) = (dggrs.min_refinement_level()?, dggrs.max_refinement_level()?);
+    if refinement_level < min || refinement_level > max {
+        return Err(StructureError::LevelOutOfRange {
+            requested: refinement_level,
+            min,
+            max,
+        }
+        .into());
+    }
+    Ok(())
+}
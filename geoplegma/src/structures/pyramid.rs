@@ -0,0 +1,338 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Aggregation of coverages to coarser refinement levels and disaggregation to finer ones.
+//!
+//! A parent zone and its sub-zones one level finer are related through `DggrsApi::zones_from_parent`, weighted by the area of the sub-zones.
+//! In aperture 3 DGGRSs a sub-zone on a vertex of its parent is shared by the three parents around that vertex, each parent accounts for an equal share of its area.
+//! In the other DGGRSs every sub-zone has one parent, so rolling up follows `DggrsApi::ancestor_at_level` and only needs the areas of the zones in the coverage.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::error::structures::StructureError;
+use crate::structures::coverage::Coverage;
+use crate::types::{RefinementLevel, RelativeDepth, ZoneId, ZoneIdFormat};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// How the values of sub-zones and parent zones are related.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Aggregation {
+    /// Extensive values, e.g. counts or masses. A parent holds the sum of the shares of its sub-zones, a parent value is distributed over its sub-zones by area.
+    Sum,
+    /// Intensive values, e.g. densities or temperatures. A parent holds the area-weighted mean of its sub-zones, sub-zones take the area-weighted mean of their parents.
+    Mean,
+}
+
+/// A sub-zone of a parent and the fraction of it that belongs to the parent.
+struct Share {
+    zone_id: ZoneId,
+    area: f64,
+    /// One over the number of parents of the sub-zone.
+    fraction: f64,
+}
+
+impl Share {
+    /// The area of the sub-zone within the parent
+    fn weight(&self) -> f64 {
+        self.area * self.fraction
+    }
+}
+
+/// Aggregate a coverage to the next coarser refinement level.
+///
+/// Only parents with at least one sub-zone in the coverage are set.
+pub fn roll_up(
+    coverage: &Coverage<f64>,
+    aggregation: Aggregation,
+) -> Result<Coverage<f64>, DggrsError> {
+    let dggrs = coverage.dggrs();
+    if coverage.refinement_level() <= dggrs.min_refinement_level()? {
+        return Err(StructureError::NoCoarserLevel(coverage.refinement_level()).into());
+    }
+    let parent_level = next_level(coverage.refinement_level(), -1)?;
+    let format = coverage.zone_id_format();
+    let mut parents: BTreeMap<ZoneId, Vec<ZoneId>> = BTreeMap::new();
+    for zone_id in coverage.zone_ids() {
+        let parent = dggrs.ancestor_at_level(zone_id, parent_level)?;
+        parents
+            .entry(in_format(dggrs, parent, format)?)
+            .or_default()
+            .push(zone_id.clone());
+    }
+
+    let sub_zones = if shares_sub_zones(dggrs) {
+        let sub_zones = shares(dggrs, parents.into_keys().collect(), format)?;
+        let shared: HashSet<&ZoneId> = sub_zones.values().flatten().map(|s| &s.zone_id).collect();
+        if let Some(zone_id) = coverage.zone_ids().find(|z| !shared.contains(z)) {
+            return Err(StructureError::NoParentShare(zone_id.to_string()).into());
+        }
+        sub_zones
+    } else {
+        primary_shares(dggrs, parents, aggregation, format)?
+    };
+
+    let mut rolled_up = Coverage::sparse(coverage.shared_dggrs(), parent_level)?;
+    for (parent, sub_zones) in sub_zones {
+        let (mut sum, mut area) = (0.0, 0.0);
+        for share in &sub_zones {
+            let Some(value) = coverage.get(&share.zone_id) else {
+                continue;
+            };
+            match aggregation {
+                Aggregation::Sum => sum += value * share.fraction,
+                Aggregation::Mean => sum += value * share.weight(),
+            }
+            area += share.weight();
+        }
+        if area > 0.0 {
+            let value = match aggregation {
+                Aggregation::Sum => sum,
+                Aggregation::Mean => sum / area,
+            };
+            rolled_up.set(parent, value)?;
+        }
+    }
+    Ok(rolled_up)
+}
+
+/// Disaggregate a coverage to the next finer refinement level.
+///
+/// Only the sub-zones of zones in the coverage are set.
+pub fn drill_down(
+    coverage: &Coverage<f64>,
+    aggregation: Aggregation,
+) -> Result<Coverage<f64>, DggrsError> {
+    let dggrs = coverage.dggrs();
+    let sub_zone_level = next_level(coverage.refinement_level(), 1)?;
+    let format = coverage.zone_id_format();
+    let parents: BTreeSet<ZoneId> = coverage.zone_ids().cloned().collect();
+
+    let mut sums: BTreeMap<ZoneId, (f64, f64)> = BTreeMap::new();
+    for (parent, sub_zones) in shares(dggrs, parents, format)? {
+        let Some(value) = coverage.get(&parent) else {
+            continue;
+        };
+        let parent_area: f64 = sub_zones.iter().map(Share::weight).sum();
+        for share in sub_zones {
+            let weight = share.weight();
+            let (sum, area) = sums.entry(share.zone_id).or_default();
+            match aggregation {
+                Aggregation::Sum => *sum += value * weight / parent_area,
+                Aggregation::Mean => *sum += value * weight,
+            }
+            *area += weight;
+        }
+    }
+
+    let mut drilled_down = Coverage::sparse(coverage.shared_dggrs(), sub_zone_level)?;
+    for (sub_zone, (sum, area)) in sums {
+        let value = match aggregation {
+            Aggregation::Sum => sum,
+            Aggregation::Mean => sum / area,
+        };
+        drilled_down.set(sub_zone, value)?;
+    }
+    Ok(drilled_down)
+}
+
+/// Coverages of the same values at consecutive refinement levels, from the level of a coverage up to a coarser level.
+pub struct Pyramid {
+    aggregation: Aggregation,
+    levels: BTreeMap<RefinementLevel, Coverage<f64>>,
+}
+
+impl Pyramid {
+    /// Roll a coverage up level by level to `coarsest_level`, or to the coarsest level of the DGGRS if `None`.
+    pub fn build(
+        coverage: Coverage<f64>,
+        coarsest_level: Option<RefinementLevel>,
+        aggregation: Aggregation,
+    ) -> Result<Self, DggrsError> {
+        let dggrs = coverage.dggrs();
+        let coarsest_level = match coarsest_level {
+            Some(level) => level,
+            None => dggrs.min_refinement_level()?,
+        };
+        if coarsest_level > coverage.refinement_level()
+            || coarsest_level < dggrs.min_refinement_level()?
+        {
+            return Err(StructureError::LevelOutOfRange {
+                requested: coarsest_level,
+                min: dggrs.min_refinement_level()?,
+                max: coverage.refinement_level(),
+            }
+            .into());
+        }
+
+        let mut levels = BTreeMap::new();
+        let mut current = coverage;
+        while current.refinement_level() > coarsest_level {
+            let coarser = roll_up(&current, aggregation)?;
+            levels.insert(current.refinement_level(), current);
+            current = coarser;
+        }
+        levels.insert(current.refinement_level(), current);
+        Ok(Self {
+            aggregation,
+            levels,
+        })
+    }
+
+    pub fn aggregation(&self) -> Aggregation {
+        self.aggregation
+    }
+
+    /// The coverage at a refinement level of the pyramid.
+    pub fn get(&self, refinement_level: RefinementLevel) -> Option<&Coverage<f64>> {
+        self.levels.get(&refinement_level)
+    }
+
+    /// The coverages from the coarsest to the finest refinement level.
+    pub fn levels(&self) -> impl Iterator<Item = &Coverage<f64>> {
+        self.levels.values()
+    }
+
+    pub fn coarsest_level(&self) -> RefinementLevel {
+        *self
+            .levels
+            .keys()
+            .next()
+            .expect("a pyramid has at least one level")
+    }
+
+    pub fn finest_level(&self) -> RefinementLevel {
+        *self
+            .levels
+            .keys()
+            .next_back()
+            .expect("a pyramid has at least one level")
+    }
+}
+
+/// Whether sub-zones are shared by several parents, as in aperture 3 DGGRSs
+fn shares_sub_zones(dggrs: &dyn DggrsApi) -> bool {
+    dggrs.uid().spec().aperture == 3
+}
+
+/// The sub-zones of the parents with the fractions belonging to them.
+///
+/// In aperture 3 DGGRSs the neighbours of the parents are added, so that the sub-zones shared with them are split between all parents.
+fn shares(
+    dggrs: &dyn DggrsApi,
+    mut parents: BTreeSet<ZoneId>,
+    format: ZoneIdFormat,
+) -> Result<BTreeMap<ZoneId, Vec<Share>>, DggrsError> {
+    let config = DggrsApiConfig {
+        neighbors: shares_sub_zones(dggrs),
+        area_sqm: true,
+        preferred_id_format: format,
+        ..DggrsApiConfig::ids_only()
+    };
+    if config.neighbors {
+        let mut neighbors = BTreeSet::new();
+        for parent in &parents {
+            for zone in dggrs.zone_from_id(parent.clone(), Some(config))?.zones {
+                neighbors.extend(zone.neighbors.into_iter().flatten());
+            }
+        }
+        parents.append(&mut neighbors);
+    }
+
+    let config = DggrsApiConfig {
+        neighbors: false,
+        ..config
+    };
+    let mut sub_zones: BTreeMap<ZoneId, Vec<Share>> = BTreeMap::new();
+    let mut parent_counts: HashMap<ZoneId, u32> = HashMap::new();
+    for parent in parents {
+        let zones =
+            dggrs.zones_from_parent(RelativeDepth::from(1u8), parent.clone(), Some(config))?;
+        let mut shares = Vec::with_capacity(zones.zones.len());
+        for zone in zones.zones {
+            let area = zone
+                .area_sqm
+                .ok_or_else(|| DggrsError::MissingZoneGeometry(zone.id.to_string()))?;
+            *parent_counts.entry(zone.id.clone()).or_default() += 1;
+            shares.push(Share {
+                zone_id: zone.id,
+                area,
+                fraction: 1.0,
+            });
+        }
+        sub_zones.insert(parent, shares);
+    }
+
+    for share in sub_zones.values_mut().flatten() {
+        share.fraction = 1.0 / f64::from(parent_counts[&share.zone_id]);
+    }
+    Ok(sub_zones)
+}
+
+/// The sub-zones of the parents from `ancestor_at_level`, each belongs to its parent as a whole.
+///
+/// Sums do not depend on the areas, so they are only taken for means.
+fn primary_shares(
+    dggrs: &dyn DggrsApi,
+    parents: BTreeMap<ZoneId, Vec<ZoneId>>,
+    aggregation: Aggregation,
+    format: ZoneIdFormat,
+) -> Result<BTreeMap<ZoneId, Vec<Share>>, DggrsError> {
+    let config = DggrsApiConfig {
+        area_sqm: true,
+        preferred_id_format: format,
+        ..DggrsApiConfig::ids_only()
+    };
+    let area = |zone_id: &ZoneId| match aggregation {
+        Aggregation::Sum => Ok(1.0),
+        Aggregation::Mean => dggrs
+            .zone_from_id(zone_id.clone(), Some(config))?
+            .zones
+            .into_iter()
+            .next()
+            .and_then(|zone| zone.area_sqm)
+            .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string())),
+    };
+    parents
+        .into_iter()
+        .map(|(parent, sub_zones)| {
+            let shares = sub_zones
+                .into_iter()
+                .map(|zone_id| {
+                    Ok(Share {
+                        area: area(&zone_id)?,
+                        zone_id,
+                        fraction: 1.0,
+                    })
+                })
+                .collect::<Result<Vec<_>, DggrsError>>()?;
+            Ok((parent, shares))
+        })
+        .collect()
+}
+
+fn next_level(level: RefinementLevel, step: i32) -> Result<RefinementLevel, DggrsError> {
+    RefinementLevel::new(i32::from(level) + step)
+}
+
+fn in_format(
+    dggrs: &dyn DggrsApi,
+    zone_id: ZoneId,
+    format: ZoneIdFormat,
+) -> Result<ZoneId, DggrsError> {
+    if zone_id.format() == format {
+        Ok(zone_id)
+    } else {
+        dggrs.format_zone_id(&zone_id, format)
+    }
+}
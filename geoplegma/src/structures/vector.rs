@@ -0,0 +1,412 @@
+// Copyright 2025 contributors to the GeoPlegmata project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A DGGS vector holds point, line and polygon features whose vertices are the zones of a DGGRS at one refinement level.
+//!
+//! `geo` geometries are quantised by replacing every coordinate with the zone that contains it, and converted back with the centroids of the zones.
+//! Quantising often maps consecutive vertices onto the same zone, `dedup` removes them and collapses features that become degenerate.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::error::structures::StructureError;
+use crate::structures::check_level;
+use crate::types::{DggrsUid, Point, RefinementLevel, ZoneId, ZoneIdFormat};
+use geo::{Coord, LineString, coord};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+/// A geometry whose vertices are ZoneIds. The rings of polygons are closed, their first and last zone are the same.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum DggsGeometry {
+    Point(ZoneId),
+    LineString(Vec<ZoneId>),
+    Polygon {
+        exterior: Vec<ZoneId>,
+        interiors: Vec<Vec<ZoneId>>,
+    },
+}
+
+impl DggsGeometry {
+    /// All vertices of the geometry, including the closing vertices of rings.
+    pub fn zone_ids(&self) -> Box<dyn Iterator<Item = &ZoneId> + '_> {
+        match self {
+            Self::Point(zone_id) => Box::new(std::iter::once(zone_id)),
+            Self::LineString(zone_ids) => Box::new(zone_ids.iter()),
+            Self::Polygon {
+                exterior,
+                interiors,
+            } => Box::new(exterior.iter().chain(interiors.iter().flatten())),
+        }
+    }
+
+    /// Remove consecutive identical zones.
+    ///
+    /// A line within one zone becomes a point, a polygon exterior with fewer than three distinct zones becomes a line or a point, and such interiors are dropped.
+    pub fn dedup(self) -> Self {
+        match self {
+            Self::Point(_) => self,
+            Self::LineString(mut zone_ids) => {
+                zone_ids.dedup();
+                match zone_ids.len() {
+                    1 => Self::Point(zone_ids.remove(0)),
+                    _ => Self::LineString(zone_ids),
+                }
+            }
+            Self::Polygon {
+                mut exterior,
+                interiors,
+            } => {
+                exterior.dedup();
+                // NOTE: the closing zone repeats the first one
+                match exterior.len() {
+                    1..=2 => Self::Point(exterior.remove(0)),
+                    3 => Self::LineString(exterior[..2].to_vec()),
+                    // NOTE: polygons with three or more distinct zones are kept, as are empty ones
+                    _ => Self::Polygon {
+                        exterior,
+                        interiors: interiors
+                            .into_iter()
+                            .map(|mut ring| {
+                                ring.dedup();
+                                ring
+                            })
+                            .filter(|ring| ring.len() > 3)
+                            .collect(),
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// The value of a feature attribute.
+///
+/// With the `serde` feature, values are serialised as plain JSON values.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum AttributeValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<bool> for AttributeValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for AttributeValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+/// A geometry with key-value attributes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DggsFeature {
+    pub geometry: DggsGeometry,
+    pub attributes: BTreeMap<String, AttributeValue>,
+}
+
+impl DggsFeature {
+    pub fn new(geometry: DggsGeometry) -> Self {
+        Self {
+            geometry,
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<AttributeValue>,
+    ) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// The meta-data identifying the DGGRS and the refinement level of a vector.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorMetadata {
+    pub dggrs: DggrsUid,
+    pub refinement_level: RefinementLevel,
+    pub feature_count: usize,
+}
+
+/// Features whose vertices are the zones of a DGGRS at one refinement level.
+pub struct DggsVector {
+    dggrs: Arc<dyn DggrsApi>,
+    refinement_level: RefinementLevel,
+    features: Vec<DggsFeature>,
+}
+
+impl DggsVector {
+    pub fn new(
+        dggrs: Arc<dyn DggrsApi>,
+        refinement_level: RefinementLevel,
+    ) -> Result<Self, DggrsError> {
+        check_level(dggrs.as_ref(), refinement_level)?;
+        Ok(Self {
+            dggrs,
+            refinement_level,
+            features: Vec::new(),
+        })
+    }
+
+    /// The DGGRS the zones of the features belong to.
+    pub fn dggrs(&self) -> &dyn DggrsApi {
+        self.dggrs.as_ref()
+    }
+
+    /// The refinement level of the zones of the features.
+    pub fn refinement_level(&self) -> RefinementLevel {
+        self.refinement_level
+    }
+
+    pub fn metadata(&self) -> VectorMetadata {
+        VectorMetadata {
+            dggrs: self.dggrs.uid(),
+            refinement_level: self.refinement_level,
+            feature_count: self.features.len(),
+        }
+    }
+
+    pub fn features(&self) -> &[DggsFeature] {
+        &self.features
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &DggsFeature> {
+        self.features.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Add a feature, its zones are validated and have to be at the refinement level of the vector.
+    pub fn push(&mut self, feature: DggsFeature) -> Result<(), DggrsError> {
+        let zone_ids: HashSet<&ZoneId> = feature.geometry.zone_ids().collect();
+        for zone_id in zone_ids {
+            let info = self.dggrs.validate_zone(zone_id)?;
+            if info.refinement_level != self.refinement_level {
+                return Err(StructureError::LevelMismatch {
+                    zone_id: zone_id.to_string(),
+                    expected: self.refinement_level,
+                    found: info.refinement_level,
+                }
+                .into());
+            }
+        }
+        self.features.push(feature);
+        Ok(())
+    }
+
+    /// Quantise a `geo` geometry and add it with the attributes, multi-part geometries are added as one feature per part.
+    ///
+    /// Returns the number of features added.
+    pub fn push_geo(
+        &mut self,
+        geometry: &geo::Geometry<f64>,
+        attributes: BTreeMap<String, AttributeValue>,
+    ) -> Result<usize, DggrsError> {
+        let geometries = self.quantise(geometry)?;
+        let count = geometries.len();
+        self.features
+            .extend(geometries.into_iter().map(|geometry| DggsFeature {
+                geometry,
+                attributes: attributes.clone(),
+            }));
+        Ok(count)
+    }
+
+    /// Replace every coordinate of a `geo` geometry with the zone that contains it, one geometry per part.
+    ///
+    /// Lines are quantised as line strings, rectangles and triangles as polygons. All coordinates of the geometry are located with one call of `DggrsApi::zone_ids_from_points`.
+    pub fn quantise(&self, geometry: &geo::Geometry<f64>) -> Result<Vec<DggsGeometry>, DggrsError> {
+        let mut parts = Vec::new();
+        let mut points = Vec::new();
+        collect_parts(geometry, &mut parts, &mut points);
+        let mut zone_ids = self
+            .dggrs
+            .zone_ids_from_points(self.refinement_level, &points, ZoneIdFormat::Text)?
+            .into_iter();
+
+        let mut next = |count: usize| -> Vec<ZoneId> { zone_ids.by_ref().take(count).collect() };
+        Ok(parts
+            .into_iter()
+            .map(|part| match part {
+                Part::Point => DggsGeometry::Point(
+                    next(1)
+                        .pop()
+                        .expect("zone_ids_from_points returns a zone for every point"),
+                ),
+                Part::LineString(count) => DggsGeometry::LineString(next(count)),
+                Part::Polygon(counts) => {
+                    let mut rings = counts.into_iter().map(&mut next);
+                    DggsGeometry::Polygon {
+                        exterior: rings.next().unwrap_or_default(),
+                        interiors: rings.collect(),
+                    }
+                }
+            })
+            .collect())
+    }
+
+    /// Convert a geometry into a `geo` geometry with the centroids of its zones as coordinates.
+    pub fn to_geo(&self, geometry: &DggsGeometry) -> Result<geo::Geometry<f64>, DggrsError> {
+        let line = |zone_ids: &[ZoneId]| -> Result<LineString<f64>, DggrsError> {
+            zone_ids
+                .iter()
+                .map(|zone_id| self.centroid(zone_id))
+                .collect()
+        };
+
+        Ok(match geometry {
+            DggsGeometry::Point(zone_id) => geo::Point(self.centroid(zone_id)?).into(),
+            DggsGeometry::LineString(zone_ids) => line(zone_ids)?.into(),
+            DggsGeometry::Polygon {
+                exterior,
+                interiors,
+            } => geo::Polygon::new(
+                line(exterior)?,
+                interiors
+                    .iter()
+                    .map(|ring| line(ring))
+                    .collect::<Result<_, _>>()?,
+            )
+            .into(),
+        })
+    }
+
+    /// Remove consecutive identical zones from all features, see `DggsGeometry::dedup`.
+    pub fn dedup(&mut self) {
+        for feature in &mut self.features {
+            let geometry =
+                std::mem::replace(&mut feature.geometry, DggsGeometry::LineString(vec![]));
+            feature.geometry = geometry.dedup();
+        }
+    }
+
+    /// Snap a `geo` geometry to the centroids of the zones at the refinement level, one geometry per part.
+    pub fn snap(
+        &self,
+        geometry: &geo::Geometry<f64>,
+    ) -> Result<Vec<geo::Geometry<f64>>, DggrsError> {
+        self.quantise(geometry)?
+            .into_iter()
+            .map(|g| self.to_geo(&g.dedup()))
+            .collect()
+    }
+
+    fn centroid(&self, zone_id: &ZoneId) -> Result<Coord<f64>, DggrsError> {
+        let config = DggrsApiConfig {
+            center: true,
+            ..DggrsApiConfig::ids_only()
+        };
+        self.dggrs
+            .zone_from_id(zone_id.clone(), Some(config))?
+            .zones
+            .into_iter()
+            .next()
+            .and_then(|zone| zone.center)
+            .map(|center| coord! { x: center.lon, y: center.lat })
+            .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string()))
+    }
+}
+
+/// A part of a `geo` geometry in the shape of the `DggsGeometry` it is quantised into, with the number of coordinates of its rings
+enum Part {
+    Point,
+    LineString(usize),
+    /// The exterior ring followed by the interior rings
+    Polygon(Vec<usize>),
+}
+
+/// Split a `geo` geometry into its parts and collect their coordinates in order, lines become line strings, rectangles and triangles polygons
+fn collect_parts(geometry: &geo::Geometry<f64>, parts: &mut Vec<Part>, points: &mut Vec<Point>) {
+    use geo::Geometry as G;
+
+    let mut ring = |coords: &[Coord<f64>]| {
+        points.extend(coords.iter().map(|c| Point::new(c.y, c.x)));
+        coords.len()
+    };
+    let mut polygon = |p: &geo::Polygon<f64>| {
+        Part::Polygon(
+            std::iter::once(p.exterior())
+                .chain(p.interiors())
+                .map(|ls| ring(&ls.0))
+                .collect(),
+        )
+    };
+
+    match geometry {
+        G::Point(p) => {
+            ring(&[p.0]);
+            parts.push(Part::Point);
+        }
+        G::Line(l) => parts.push(Part::LineString(ring(&[l.start, l.end]))),
+        G::LineString(ls) => parts.push(Part::LineString(ring(&ls.0))),
+        G::Polygon(p) => parts.push(polygon(p)),
+        G::MultiPoint(mp) => {
+            for p in mp {
+                ring(&[p.0]);
+                parts.push(Part::Point);
+            }
+        }
+        G::MultiLineString(mls) => {
+            for ls in mls {
+                parts.push(Part::LineString(ring(&ls.0)));
+            }
+        }
+        G::MultiPolygon(mp) => parts.extend(mp.iter().map(polygon)),
+        G::GeometryCollection(gc) => {
+            for g in gc {
+                collect_parts(g, parts, points);
+            }
+        }
+        G::Rect(r) => parts.push(polygon(&r.to_polygon())),
+        G::Triangle(t) => parts.push(polygon(&t.to_polygon())),
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright 2025 contributors to the GeoPlegma project.
+// Originally authored by Michael Jendryke, GeoInsight (michael.jendryke@geoinsight.ai)
+//
+// Licenced under the Apache Licence, Version 2.0 <LICENCE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENCE-MIT or http://opensource.org/licenses/MIT>, at your
+// discretion. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Translation of zones between two DGGRS, e.g. from IGEO7 to H3.
+//!
+//! The translation is computed from the zone geometries of the source and target DGGRS, any pair of adapters returned by `factory::get` can be combined.
+//! The overlap of two zones is computed on the longitude/latitude coordinates of their regions and measured with geodesic areas. The regions are split at the antimeridian (`GeometryMode::Split`), so zones crossing it are compared part by part.
+
+use crate::api::{DggrsApi, DggrsApiConfig};
+use crate::error::DggrsError;
+use crate::geometry::{Densification, bounding_box};
+use crate::types::{RefinementLevel, Zone, ZoneId, Zones};
+use geo::orient::{Direction, Orient};
+use geo::{BooleanOps, GeodesicArea, MultiPolygon};
+
+/// How source zones are mapped onto target zones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TranslationMethod {
+    /// Each source zone maps to the target zone containing its centroid, with a weight of `1.0`.
+    #[default]
+    Centroid,
+    /// Each source zone maps to all target zones it overlaps, the weight is the fraction of the source zone area inside the target zone.
+    ///
+    /// The weights of a source zone add up to `1.0`, which makes them suitable to distribute extensive values such as counts.
+    AreaWeighted,
+    /// Each source zone maps to all target zones it overlaps, the weight is the fraction of the target zone area covered by the source zone.
+    ///
+    /// Together the target zones cover the source zone completely, the weights are suitable to average intensive values such as densities.
+    Coverage,
+}
+
+/// A source zone mapped onto a target zone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneTranslation {
+    pub source: ZoneId,
+    pub target: ZoneId,
+    pub weight: f64,
+}
+
+/// Translate zones of the `source` DGGRS into zones of the `target` DGGRS at `target_level`.
+///
+/// Target zones that do not overlap a source zone are not returned. The order of the translations follows the order of the source `zones`.
+pub fn translate(
+    source: &dyn DggrsApi,
+    target: &dyn DggrsApi,
+    zones: &[ZoneId],
+    target_level: RefinementLevel,
+    method: TranslationMethod,
+) -> Result<Vec<ZoneTranslation>, DggrsError> {
+    let mut translations = Vec::new();
+
+    for zone_id in zones {
+        match method {
+            TranslationMethod::Centroid => {
+                translations.push(translate_centroid(source, target, zone_id, target_level)?)
+            }
+            TranslationMethod::AreaWeighted | TranslationMethod::Coverage => translations.extend(
+                translate_overlap(source, target, zone_id, target_level, method)?,
+            ),
+        }
+    }
+
+    Ok(translations)
+}
+
+fn translate_centroid(
+    source: &dyn DggrsApi,
+    target: &dyn DggrsApi,
+    zone_id: &ZoneId,
+    target_level: RefinementLevel,
+) -> Result<ZoneTranslation, DggrsError> {
+    let center = source
+        .zone_info(zone_id)?
+        .center
+        .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string()))?;
+
+    let target_zone =
+        first_zone(target.zone_from_point(target_level, center, Some(center_config()))?)
+            .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string()))?;
+
+    Ok(ZoneTranslation {
+        source: zone_id.clone(),
+        target: target_zone.id,
+        weight: 1.0,
+    })
+}
+
+fn translate_overlap(
+    source: &dyn DggrsApi,
+    target: &dyn DggrsApi,
+    zone_id: &ZoneId,
+    target_level: RefinementLevel,
+    method: TranslationMethod,
+) -> Result<Vec<ZoneTranslation>, DggrsError> {
+    let source_zone = first_zone(source.zone_from_id(zone_id.clone(), Some(region_config()))?)
+        .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string()))?;
+    let source_polygon = zone_polygon(&source_zone)?;
+    let source_area = geodesic_area(&source_polygon);
+
+    let bbox = bounding_box(&source_polygon)
+        .ok_or_else(|| DggrsError::MissingZoneGeometry(zone_id.to_string()))?;
+    let target_zones = target.zones_from_bbox(target_level, Some(bbox), Some(region_config()))?;
+
+    let mut translations = Vec::new();
+    for target_zone in target_zones.zones {
+        let target_polygon = zone_polygon(&target_zone)?;
+        let overlap = geodesic_area(&source_polygon.intersection(&target_polygon));
+
+        let reference_area = match method {
+            TranslationMethod::Coverage => geodesic_area(&target_polygon),
+            _ => source_area,
+        };
+
+        if overlap > 0.0 && reference_area > 0.0 {
+            translations.push(ZoneTranslation {
+                source: zone_id.clone(),
+                target: target_zone.id,
+                weight: (overlap / reference_area).min(1.0),
+            });
+        }
+    }
+
+    Ok(translations)
+}
+
+/// Only the ZoneIds and the centroids are needed
+fn center_config() -> DggrsApiConfig {
+    DggrsApiConfig {
+        center: true,
+        ..DggrsApiConfig::ids_only()
+    }
+}
+
+/// The regions are densified, so that the overlap of zones with geodesic edges is measured correctly
+fn region_config() -> DggrsApiConfig {
+    DggrsApiConfig {
+        region: true,
+        densification: Densification::default(),
+        ..center_config()
+    }
+}
+
+fn first_zone(zones: Zones) -> Option<Zone> {
+    zones.zones.into_iter().next()
+}
+
+fn zone_polygon(zone: &Zone) -> Result<MultiPolygon<f64>, DggrsError> {
+    zone.region
+        .as_ref()
+        .map(|r| r.to_geo_multi_polygon())
+        .ok_or_else(|| DggrsError::MissingZoneGeometry(zone.id.to_string()))
+}
+
+/// The geodesic area of the polygon, clockwise rings would otherwise be measured as the area of their complement
+fn geodesic_area<G: Orient + GeodesicArea<f64>>(geometry: &G) -> f64 {
+    geometry.orient(Direction::Default).geodesic_area_unsigned()
+}
@@ -9,11 +9,13 @@
 use crate::constants::DGGRS_SPECS;
 use crate::error::DggrsError;
 use crate::error::factory::DggrsUidError;
+use geo::GeodesicArea;
 use std::convert::{From, TryFrom};
 use std::fmt;
 use std::str::FromStr;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     pub lat: f64,
     pub lon: f64,
@@ -29,32 +31,123 @@ impl Point {
     }
 }
 
+/// A polygon of a zone region with an exterior ring and holes, all rings are closed.
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct Region {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon {
     pub exterior: Vec<Point>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub interiors: Vec<Vec<Point>>,
 }
 
-impl Region {
-    pub fn new(mut exterior: Vec<Point>) -> Self {
-        if let Some(first) = exterior.first().copied() {
-            if exterior.last().copied() != Some(first) {
-                exterior.push(first);
-            }
+impl Polygon {
+    pub fn new(exterior: Vec<Point>, interiors: Vec<Vec<Point>>) -> Self {
+        Self {
+            exterior: close_ring(exterior),
+            interiors: interiors.into_iter().map(close_ring).collect(),
         }
-        Self { exterior }
     }
 
     pub fn coords_count(&self) -> usize {
-        self.exterior.len()
+        self.exterior.len() + self.interiors.iter().map(Vec::len).sum::<usize>()
     }
 
     pub fn to_geo_polygon(&self) -> geo::Polygon<f64> {
-        let coords: Vec<_> = self.exterior.iter().map(Point::to_coord).collect();
-        geo::Polygon::new(geo::LineString::from(coords), vec![])
+        let ring = |points: &Vec<Point>| {
+            geo::LineString::from(points.iter().map(Point::to_coord).collect::<Vec<_>>())
+        };
+        geo::Polygon::new(
+            ring(&self.exterior),
+            self.interiors.iter().map(ring).collect(),
+        )
+    }
+
+    /// The geodesic area in square metres, the rings may be wound in either direction.
+    pub fn geodesic_area(&self) -> f64 {
+        // NOTE: the signed area of a ring smaller than half the earth is correct up to its sign, whereas the unsigned area of a clockwise ring is the area of its complement
+        let ring_area = |points: &Vec<Point>| {
+            let ring =
+                geo::LineString::from(points.iter().map(Point::to_coord).collect::<Vec<_>>());
+            geo::Polygon::new(ring, vec![]).geodesic_area_signed().abs()
+        };
+        let holes: f64 = self.interiors.iter().map(ring_area).sum();
+        (ring_area(&self.exterior) - holes).max(0.0)
+    }
+}
+
+fn close_ring(mut ring: Vec<Point>) -> Vec<Point> {
+    if let Some(first) = ring.first().copied() {
+        if ring.last().copied() != Some(first) {
+            ring.push(first);
+        }
+    }
+    ring
+}
+
+/// The region of a zone in longitude/latitude coordinates.
+///
+/// With serde the region is tagged by its kind, e.g. `{"polygon": {"exterior": [...], "interiors": [...]}}` or `{"multipolygon": [...]}`, holes are left out if there are none.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Region {
+    /// A single polygon, possibly with holes.
+    Polygon(Polygon),
+    /// Several polygons, e.g. the parts of a zone split at the antimeridian.
+    MultiPolygon(Vec<Polygon>),
+}
+
+impl Default for Region {
+    fn default() -> Self {
+        Region::Polygon(Polygon::default())
+    }
+}
+
+impl Region {
+    /// A polygon region without holes, the ring is closed if necessary.
+    pub fn new(exterior: Vec<Point>) -> Self {
+        Region::Polygon(Polygon::new(exterior, vec![]))
+    }
+
+    /// The polygons of the region, a single one unless it is a MultiPolygon.
+    pub fn polygons(&self) -> &[Polygon] {
+        match self {
+            Region::Polygon(polygon) => std::slice::from_ref(polygon),
+            Region::MultiPolygon(polygons) => polygons,
+        }
+    }
+
+    /// The number of points of all rings, including the closing points.
+    pub fn coords_count(&self) -> usize {
+        self.polygons().iter().map(Polygon::coords_count).sum()
+    }
+
+    pub fn to_geo_multi_polygon(&self) -> geo::MultiPolygon<f64> {
+        geo::MultiPolygon::new(
+            self.polygons()
+                .iter()
+                .map(Polygon::to_geo_polygon)
+                .collect(),
+        )
+    }
+
+    /// The geodesic area in square metres, the sum of the areas of the polygons minus their holes.
+    pub fn geodesic_area(&self) -> f64 {
+        self.polygons().iter().map(Polygon::geodesic_area).sum()
     }
 }
 
+/// A longitude/latitude bounding box in degrees.
+///
+/// A box with `min_lon > max_lon` wraps around the antimeridian, e.g. from 170° E to 170° W.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoundingBox {
     pub min_lon: f64,
     pub min_lat: f64,
@@ -78,10 +171,54 @@ impl BoundingBox {
         max_lon: 180.0,
         max_lat: 90.0,
     };
+
+    /// Check that the coordinates are within ±180°/±90° and that the latitudes are not inverted.
+    pub fn validate(&self) -> Result<(), DggrsError> {
+        let invalid = |reason| {
+            Err(DggrsError::InvalidBoundingBox {
+                bbox: *self,
+                reason,
+            })
+        };
+
+        let lons = [self.min_lon, self.max_lon];
+        let lats = [self.min_lat, self.max_lat];
+        if lons.iter().chain(&lats).any(|v| !v.is_finite()) {
+            return invalid("coordinates must be finite");
+        }
+        if lons.iter().any(|lon| !(-180.0..=180.0).contains(lon)) {
+            return invalid("longitudes must be within [-180, 180]");
+        }
+        if lats.iter().any(|lat| !(-90.0..=90.0).contains(lat)) {
+            return invalid("latitudes must be within [-90, 90]");
+        }
+        if self.min_lat > self.max_lat {
+            return invalid("min_lat must not be larger than max_lat");
+        }
+        Ok(())
+    }
+
+    /// Whether the box wraps around the antimeridian.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.min_lon > self.max_lon
+    }
+
+    /// The box split at the antimeridian into an eastern and a western box, or the box itself if it does not wrap.
+    pub fn split_antimeridian(&self) -> Vec<BoundingBox> {
+        if self.crosses_antimeridian() {
+            vec![
+                BoundingBox::new(self.min_lon, self.min_lat, 180.0, self.max_lat),
+                BoundingBox::new(-180.0, self.min_lat, self.max_lon, self.max_lat),
+            ]
+        } else {
+            vec![*self]
+        }
+    }
 }
 
 // NOTE: The naming needs to be adjusted to the DGGRS Registry
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DggrsUid {
     ISEA3HDGGRID,
     IGEO7,
@@ -147,6 +284,7 @@ impl fmt::Display for DggrsUid {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DggrsName {
     ISEA3H,
     IGEO7,
@@ -178,6 +316,7 @@ impl fmt::Display for DggrsName {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DggrsImplementation {
     Native,
     DGGRID,
@@ -197,7 +336,11 @@ impl fmt::Display for DggrsImplementation {
     }
 }
 
+/// The specification of a DGGRS in the registry.
+///
+/// With the `serde` feature, a DggrsSpec is serialised with all its fields, but deserialised by looking up its `id` in the registry, the other fields are ignored.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DggrsSpec {
     pub id: DggrsUid,
     pub name: DggrsName,
@@ -214,30 +357,171 @@ pub struct DggrsSpec {
     pub default_relative_depth: RelativeDepth,
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DggrsSpec {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct SpecId {
+            id: DggrsUid,
+        }
+
+        let spec_id = SpecId::deserialize(deserializer)?;
+        Ok(spec_id.id.spec().clone())
+    }
+}
+
+/// A zone with the outputs requested in the `DggrsApiConfig`.
+///
+/// With the `serde` feature, outputs that were not requested are left out of the serialised form.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Zone {
     pub id: ZoneId,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub region: Option<Region>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub center: Option<Point>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vertex_count: Option<u32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub children: Option<Vec<ZoneId>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub neighbors: Option<Vec<ZoneId>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub area_sqm: Option<f64>,
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Zones {
     pub zones: Vec<Zone>,
 }
 
+/// Lightweight information about a zone, derived from its ZoneId wherever the DGGRS allows it.
+///
+/// ZoneIds in here are in the same format as `id`. The `center` is only filled by `DggrsApi::zone_info`, `DggrsApi::validate_zone` leaves it empty.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZoneInfo {
+    pub id: ZoneId,
+    pub refinement_level: RefinementLevel,
+    /// The ancestor of the zone at the coarsest refinement level, i.e. the base cell or root face.
+    pub root_zone: ZoneId,
+    /// The number of corners of the zone, not the number of (densified) vertices of its region.
+    pub vertex_count: u32,
+    /// Whether the zone is a pentagon or otherwise irregular compared to the other zones of the DGGRS.
+    pub is_pentagon: bool,
+    /// The primary parent zone, `None` for zones at the coarsest refinement level.
+    pub primary_parent: Option<ZoneId>,
+    pub center: Option<Point>,
+}
+
+/// The position of a zone in the hierarchy of its DGGRS, following the primary parents from the root zone.
+///
+/// Keys are ordered by the root zone and then digit by digit, a zone comes before its descendants and the keys of all descendants of a zone start with its key.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HierarchyKey {
+    /// The ancestor of the zone at the coarsest refinement level, in the format of the zone.
+    pub root_zone: ZoneId,
+    /// One digit per refinement level below the root, ordering the sub-zones of a parent the same way as `DggrsApi::sub_zone_ids`.
+    pub digits: Vec<u64>,
+}
+
+impl HierarchyKey {
+    /// Whether the key is the key of a zone or of one of its descendants.
+    pub fn starts_with(&self, ancestor: &HierarchyKey) -> bool {
+        self.root_zone == ancestor.root_zone && self.digits.starts_with(&ancestor.digits)
+    }
+}
+
+/// The ID of a zone.
+///
+/// With the `serde` feature, a ZoneId is serialised tagged by its variant, i.e. `{"str": "A4-0-A"}`, `{"hex": "8a1fb46622dffff"}` or `{"int": 622236750694711295}`.
+/// Deserialised ZoneIds are validated the same way as `ZoneId::new_str` and `ZoneId::new_hex`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ZoneIdRepr", into = "ZoneIdRepr")
+)]
 pub enum ZoneId {
     StrId(String),
     HexId(HexString),
     IntId(u64),
 }
 
+/// The serialised form of a ZoneId, which is validated on its way back into a `ZoneId`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ZoneIdRepr {
+    Str(String),
+    Hex(String),
+    Int(u64),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ZoneIdRepr> for ZoneId {
+    type Error = DggrsError;
+
+    fn try_from(repr: ZoneIdRepr) -> Result<Self, Self::Error> {
+        match repr {
+            ZoneIdRepr::Str(s) => ZoneId::new_str(&s),
+            ZoneIdRepr::Hex(h) => ZoneId::new_hex(&h),
+            ZoneIdRepr::Int(i) => Ok(ZoneId::new_int(i)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ZoneId> for ZoneIdRepr {
+    fn from(zone_id: ZoneId) -> Self {
+        match zone_id {
+            ZoneId::StrId(s) => ZoneIdRepr::Str(s),
+            ZoneId::HexId(h) => ZoneIdRepr::Hex(h.0),
+            ZoneId::IntId(i) => ZoneIdRepr::Int(i),
+        }
+    }
+}
+
+/// The representation of a ZoneId that an adapter should produce.
+///
+/// Every adapter can convert losslessly between both formats with `DggrsApi::format_zone_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ZoneIdFormat {
+    /// The canonical textual ZoneId of the DGGRS, e.g. the H3 hexadecimal string, the DGGRID Z3/Z7 hexadecimal string or the DGGAL textual ID.
+    #[default]
+    Text,
+    /// The compact 64-bit integer ZoneId.
+    Int,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
 pub struct HexString(String);
 
 impl HexString {
@@ -254,6 +538,20 @@ impl HexString {
     }
 }
 
+impl TryFrom<String> for HexString {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        HexString::new(&s)
+    }
+}
+
+impl From<HexString> for String {
+    fn from(h: HexString) -> Self {
+        h.0
+    }
+}
+
 impl fmt::Display for HexString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -285,6 +583,14 @@ impl ZoneId {
         ZoneId::IntId(id)
     }
 
+    /// The format of this ZoneId, hexadecimal and string ZoneIds are both textual.
+    pub fn format(&self) -> ZoneIdFormat {
+        match self {
+            ZoneId::IntId(_) => ZoneIdFormat::Int,
+            ZoneId::StrId(_) | ZoneId::HexId(_) => ZoneIdFormat::Text,
+        }
+    }
+
     /// convert ZoneId::StrId as String
     pub fn as_str(&self) -> Option<&str> {
         match self {
@@ -310,6 +616,9 @@ impl ZoneId {
     }
 }
 
+/// Guesses the ZoneId variant from the characters of the string.
+///
+/// Since this does not know which DGGRS the ZoneId belongs to, a H3 index like `"123"` becomes a `ZoneId::IntId`. Use `DggrsApi::parse_zone_id` when the DGGRS is known.
 impl FromStr for ZoneId {
     type Err = DggrsError;
 
@@ -353,7 +662,13 @@ impl fmt::Display for ZoneId {
     }
 }
 
+/// With the `serde` feature, serialised as a plain integer that is validated on deserialisation.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "i32", into = "i32")
+)]
 pub struct RefinementLevel(i32);
 
 impl RefinementLevel {
@@ -424,7 +739,13 @@ impl fmt::Display for RefinementLevel {
     }
 }
 
+/// With the `serde` feature, serialised as a plain integer that is validated on deserialisation.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "i32", into = "i32")
+)]
 pub struct RelativeDepth(i32);
 
 impl RelativeDepth {
@@ -0,0 +1,170 @@
+#![cfg(feature = "json")]
+
+mod common;
+
+use common::{dggrs, level, store_dir};
+use geo::{Geometry, line_string, point};
+use geoplegma::abstraction::local::{LocalCoverageStore, LocalVectorStore};
+use geoplegma::abstraction::{
+    CoverageAbstraction, VectorAbstraction, read_coverage, read_vector, write_coverage,
+    write_vector,
+};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::structures::StructureError;
+use geoplegma::structures::coverage::{Coverage, CoverageStorage};
+use geoplegma::structures::vector::{AttributeValue, DggsVector};
+use geoplegma::types::{BoundingBox, DggrsUid, Point};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+fn sparse_coverage(dggrs: &Arc<dyn DggrsApi>) -> Coverage<f64> {
+    let mut coverage = Coverage::sparse(dggrs.clone(), level(3))
+        .unwrap()
+        .with_blocks(level(1))
+        .unwrap();
+    for (i, (lat, lon)) in [(45.0, 10.0), (45.5, 10.5), (-30.0, 150.0), (60.0, -100.0)]
+        .into_iter()
+        .enumerate()
+    {
+        let zone_id = dggrs
+            .zone_from_point(level(3), Point::new(lat, lon), None)
+            .unwrap()
+            .zones
+            .remove(0)
+            .id;
+        coverage.set(zone_id, i as f64 * 0.5).unwrap();
+    }
+    coverage
+}
+
+/// Verify that a sparse coverage is written block by block and read back
+fn test_sparse_coverage_round_trip(dggrs: Arc<dyn DggrsApi>, name: &str) {
+    let coverage = sparse_coverage(&dggrs);
+    let dir = store_dir(name);
+    let mut store = LocalCoverageStore::create(&dir).unwrap();
+    write_coverage(&coverage, &mut store).unwrap();
+
+    let store = LocalCoverageStore::open(&dir);
+    let block_ids = CoverageAbstraction::<f64>::block_ids(&store).unwrap();
+    assert_eq!(block_ids, coverage.blocks().cloned().collect::<Vec<_>>());
+    let metadata = CoverageAbstraction::<f64>::read_metadata(&store).unwrap();
+    assert_eq!(metadata, coverage.metadata());
+
+    let back: Coverage<f64> = read_coverage(dggrs, &store).unwrap();
+    assert_eq!(
+        back.iter().collect::<Vec<_>>(),
+        coverage.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(back.block_level(), Some(level(1)));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// Verify that a dense coverage is restored with the same slots
+fn test_dense_coverage_round_trip(dggrs: Arc<dyn DggrsApi>, name: &str) {
+    let extent = BoundingBox::new(5.0, 40.0, 15.0, 50.0);
+    let mut coverage: Coverage<u32> = Coverage::dense(dggrs.clone(), level(3), Some(extent))
+        .unwrap()
+        .with_blocks(level(2))
+        .unwrap();
+    let ids: Vec<_> = coverage.zone_ids().step_by(2).cloned().collect();
+    for (i, id) in ids.into_iter().enumerate() {
+        coverage.set(id, i as u32).unwrap();
+    }
+
+    let dir = store_dir(name);
+    write_coverage(&coverage, &mut LocalCoverageStore::create(&dir).unwrap()).unwrap();
+    let back: Coverage<u32> = read_coverage(dggrs, &LocalCoverageStore::open(&dir)).unwrap();
+    assert_eq!(back.storage(), CoverageStorage::Dense);
+    assert_eq!(back.extent(), Some(extent));
+    assert_eq!(back.zone_ids().count(), coverage.zone_ids().count());
+    assert_eq!(
+        back.iter().collect::<Vec<_>>(),
+        coverage.iter().collect::<Vec<_>>()
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// Verify that a vector is written in segments and read back with its attributes
+fn test_vector_round_trip(dggrs: Arc<dyn DggrsApi>, name: &str) {
+    let mut vector = DggsVector::new(dggrs.clone(), level(4)).unwrap();
+    for i in 0..5 {
+        let mut attributes = BTreeMap::new();
+        attributes.insert("index".to_string(), AttributeValue::Int(i));
+        let geometry: Geometry<f64> = if i % 2 == 0 {
+            point!(x: 10.0 + i as f64, y: 45.0).into()
+        } else {
+            line_string![(x: 10.0, y: 45.0), (x: 12.0, y: 46.0 + i as f64)].into()
+        };
+        vector.push_geo(&geometry, attributes).unwrap();
+    }
+
+    let dir = store_dir(name);
+    let mut store = LocalVectorStore::create(&dir).unwrap();
+    write_vector(&vector, &mut store, 2).unwrap();
+    assert_eq!(store.segment_count().unwrap(), 3);
+    assert_eq!(store.read_metadata().unwrap(), vector.metadata());
+
+    let back = read_vector(dggrs, &LocalVectorStore::open(&dir)).unwrap();
+    assert_eq!(back.features(), vector.features());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn h3_sparse_coverage_round_trip() {
+    test_sparse_coverage_round_trip(dggrs(DggrsUid::H3), "h3-sparse");
+}
+
+#[test]
+fn h3_dense_coverage_round_trip() {
+    test_dense_coverage_round_trip(dggrs(DggrsUid::H3), "h3-dense");
+}
+
+#[test]
+fn h3_vector_round_trip() {
+    test_vector_round_trip(dggrs(DggrsUid::H3), "h3-vector");
+}
+
+#[test]
+fn igeo7_sparse_coverage_round_trip() {
+    test_sparse_coverage_round_trip(dggrs(DggrsUid::IGEO7), "igeo7-sparse");
+}
+
+#[test]
+fn isea3h_dggal_vector_round_trip() {
+    test_vector_round_trip(dggrs(DggrsUid::ISEA3HDGGAL), "isea3h-dggal-vector");
+}
+
+#[test]
+fn unpartitioned_coverage_is_rejected() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let coverage: Coverage<f64> = Coverage::sparse(dggrs, level(3)).unwrap();
+    let dir = store_dir("unpartitioned");
+    assert!(matches!(
+        write_coverage(&coverage, &mut LocalCoverageStore::create(&dir).unwrap()),
+        Err(DggrsError::Structure(StructureError::NotPartitioned))
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn mismatching_dggrs_and_data_type_are_rejected() {
+    let h3 = dggrs(DggrsUid::H3);
+    let coverage = sparse_coverage(&h3);
+    let dir = store_dir("mismatch");
+    write_coverage(&coverage, &mut LocalCoverageStore::create(&dir).unwrap()).unwrap();
+
+    let store = LocalCoverageStore::open(&dir);
+    assert!(matches!(
+        read_coverage::<f32>(h3, &store),
+        Err(DggrsError::Structure(
+            StructureError::DataTypeMismatch { .. }
+        ))
+    ));
+    let igeo7 = dggrs(DggrsUid::IGEO7);
+    assert!(matches!(
+        read_coverage::<f64>(igeo7, &store),
+        Err(DggrsError::Structure(StructureError::DggrsMismatch { .. }))
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
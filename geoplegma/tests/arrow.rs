@@ -0,0 +1,141 @@
+#![cfg(feature = "arrow")]
+
+use arrow_schema::DataType;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::encoding::arrow::{ZoneBatchBuilder, record_batch_to_zones, zones_to_record_batch};
+use geoplegma::types::{
+    BoundingBox, Point, Polygon, RefinementLevel, Region, Zone, ZoneId, ZoneIdFormat, Zones,
+};
+
+fn bbox() -> Option<BoundingBox> {
+    Some(BoundingBox::new(8.0, 52.0, 9.0, 53.0))
+}
+
+/// Verify that zones pushed into the batch builder decode to the zones of zones_from_bbox
+fn test_batch_builder_round_trip<T: DggrsApi>(adapter: &T, format: ZoneIdFormat) {
+    let level = RefinementLevel::new(4).unwrap();
+    let config = DggrsApiConfig {
+        preferred_id_format: format,
+        ..Default::default()
+    };
+
+    let mut builder = ZoneBatchBuilder::new(format);
+    adapter
+        .zones_from_bbox_into(level, bbox(), Some(config), &mut builder)
+        .unwrap();
+    let batch = builder.finish().unwrap();
+
+    let zones = adapter
+        .zones_from_bbox(level, bbox(), Some(config))
+        .unwrap();
+    assert_eq!(batch.num_rows(), zones.zones.len());
+
+    let schema = batch.schema();
+    let id_type = match format {
+        ZoneIdFormat::Text => DataType::Utf8,
+        ZoneIdFormat::Int => DataType::UInt64,
+    };
+    assert_eq!(schema.field_with_name("id").unwrap().data_type(), &id_type);
+    assert_eq!(
+        schema.field_with_name("region").unwrap().metadata()["ARROW:extension:name"],
+        "geoarrow.multipolygon"
+    );
+    assert_eq!(
+        schema.field_with_name("center").unwrap().metadata()["ARROW:extension:name"],
+        "geoarrow.point"
+    );
+
+    let back = record_batch_to_zones(&batch, adapter).unwrap();
+    for (zone, back) in zones.zones.iter().zip(back.zones.iter()) {
+        assert_eq!(back.id, zone.id);
+        assert_eq!(back.region, zone.region);
+        assert_eq!(back.center, zone.center);
+        assert_eq!(back.vertex_count, zone.vertex_count);
+        assert_eq!(back.area_sqm, zone.area_sqm);
+        assert_eq!(back.neighbors, zone.neighbors);
+        assert_eq!(back.children, zone.children);
+    }
+}
+
+#[test]
+fn h3_text_batch_round_trip() {
+    test_batch_builder_round_trip(&H3Impl::default(), ZoneIdFormat::Text);
+}
+
+#[test]
+fn h3_int_batch_round_trip() {
+    test_batch_builder_round_trip(&H3Impl::default(), ZoneIdFormat::Int);
+}
+
+#[test]
+fn missing_outputs_are_null() {
+    let adapter = H3Impl::default();
+    let config = DggrsApiConfig {
+        region: false,
+        neighbors: false,
+        children: false,
+        area_sqm: false,
+        vertex_count: false,
+        ..Default::default()
+    };
+    let zones = adapter
+        .zones_from_bbox(RefinementLevel::new(3).unwrap(), bbox(), Some(config))
+        .unwrap();
+
+    let batch = zones_to_record_batch(&zones, &adapter, ZoneIdFormat::Int).unwrap();
+    assert_eq!(
+        batch.column_by_name("region").unwrap().null_count(),
+        zones.zones.len()
+    );
+    assert_eq!(batch.column_by_name("center").unwrap().null_count(), 0);
+
+    let back = record_batch_to_zones(&batch, &adapter).unwrap();
+    assert!(matches!(back.zones[0].id, ZoneId::IntId(_)));
+    assert!(
+        back.zones
+            .iter()
+            .all(|z| z.region.is_none() && z.neighbors.is_none())
+    );
+    assert_eq!(
+        adapter
+            .format_zone_id(&back.zones[0].id, ZoneIdFormat::Text)
+            .unwrap(),
+        zones.zones[0].id
+    );
+}
+
+#[test]
+fn holes_and_multipolygons_round_trip() {
+    let adapter = H3Impl::default();
+    let ring = |min_lon: f64, size: f64| {
+        vec![
+            Point::new(52.0, min_lon),
+            Point::new(52.0, min_lon + size),
+            Point::new(52.0 + size, min_lon + size),
+            Point::new(52.0 + size, min_lon),
+        ]
+    };
+    let regions = [
+        Region::Polygon(Polygon::new(ring(8.0, 1.0), vec![ring(8.25, 0.5)])),
+        Region::MultiPolygon(vec![
+            Polygon::new(ring(8.0, 0.5), vec![]),
+            Polygon::new(ring(9.0, 0.5), vec![ring(9.1, 0.2)]),
+        ]),
+    ];
+    let zones = Zones {
+        zones: regions
+            .into_iter()
+            .map(|region| Zone {
+                id: ZoneId::new_hex("8a1fb46622dffff").unwrap(),
+                region: Some(region),
+                ..Default::default()
+            })
+            .collect(),
+    };
+
+    let batch = zones_to_record_batch(&zones, &adapter, ZoneIdFormat::Text).unwrap();
+    let back = record_batch_to_zones(&batch, &adapter).unwrap();
+    assert_eq!(back.zones[0].region, zones.zones[0].region);
+    assert_eq!(back.zones[1].region, zones.zones[1].region);
+}
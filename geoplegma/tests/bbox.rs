@@ -0,0 +1,110 @@
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::error::DggrsError;
+use geoplegma::types::{BoundingBox, DggrsUid, RefinementLevel, ZoneId};
+use std::collections::HashSet;
+
+fn zone_ids<T: DggrsApi>(adapter: &T, bbox: BoundingBox) -> HashSet<ZoneId> {
+    let config = DggrsApiConfig {
+        region: false,
+        children: false,
+        neighbors: false,
+        area_sqm: false,
+        vertex_count: false,
+        ..Default::default()
+    };
+    adapter
+        .zones_from_bbox(RefinementLevel::new(3).unwrap(), Some(bbox), Some(config))
+        .unwrap()
+        .zones
+        .into_iter()
+        .map(|z| z.id)
+        .collect()
+}
+
+/// Verify that a bbox across the antimeridian returns the zones of both sides
+fn test_wrapped_bbox<T: DggrsApi>(adapter: &T) {
+    let wrapped = zone_ids(adapter, BoundingBox::new(170.0, -10.0, -170.0, 10.0));
+    let east = zone_ids(adapter, BoundingBox::new(170.0, -10.0, 180.0, 10.0));
+    let west = zone_ids(adapter, BoundingBox::new(-180.0, -10.0, -170.0, 10.0));
+
+    assert!(!east.is_empty() && !west.is_empty());
+    assert_eq!(wrapped, &east | &west);
+
+    // NOTE: the unwrapped box would span the whole globe
+    let world = zone_ids(adapter, BoundingBox::new(-170.0, -10.0, 170.0, 10.0));
+    assert!(wrapped.len() < world.len());
+}
+
+/// Verify that invalid boxes are rejected
+fn test_invalid_bbox<T: DggrsApi>(adapter: &T) {
+    let level = RefinementLevel::new(3).unwrap();
+    for bbox in [
+        BoundingBox::new(0.0, 10.0, 10.0, -10.0),
+        BoundingBox::new(0.0, -95.0, 10.0, 10.0),
+        BoundingBox::new(-190.0, -10.0, 10.0, 10.0),
+        BoundingBox::new(0.0, f64::NAN, 10.0, 10.0),
+    ] {
+        let result = adapter.zones_from_bbox(level, Some(bbox), None);
+        assert!(
+            matches!(result, Err(DggrsError::InvalidBoundingBox { .. })),
+            "{bbox:?} is accepted"
+        );
+    }
+}
+
+#[test]
+fn h3_wrapped_bbox() {
+    let adapter = H3Impl::default();
+    test_wrapped_bbox(&adapter);
+}
+
+#[test]
+fn dggal_wrapped_bbox() {
+    let adapter = DggalImpl::new(DggrsUid::ISEA3HDGGAL);
+    test_wrapped_bbox(&adapter);
+}
+
+#[test]
+fn igeo7_wrapped_bbox() {
+    let adapter = Igeo7Impl::default();
+    test_wrapped_bbox(&adapter);
+}
+
+#[test]
+fn h3_invalid_bbox() {
+    let adapter = H3Impl::default();
+    test_invalid_bbox(&adapter);
+}
+
+#[test]
+fn dggal_invalid_bbox() {
+    let adapter = DggalImpl::new(DggrsUid::ISEA3HDGGAL);
+    test_invalid_bbox(&adapter);
+}
+
+#[test]
+fn igeo7_invalid_bbox() {
+    let adapter = Igeo7Impl::default();
+    test_invalid_bbox(&adapter);
+}
+
+#[test]
+fn wrapped_bbox_is_split() {
+    let bbox = BoundingBox::new(170.0, -10.0, -170.0, 10.0);
+    assert!(bbox.crosses_antimeridian());
+    assert!(bbox.validate().is_ok());
+    assert_eq!(
+        bbox.split_antimeridian(),
+        vec![
+            BoundingBox::new(170.0, -10.0, 180.0, 10.0),
+            BoundingBox::new(-180.0, -10.0, -170.0, 10.0),
+        ]
+    );
+    assert_eq!(
+        BoundingBox::WORLD.split_antimeridian(),
+        vec![BoundingBox::WORLD]
+    );
+}
@@ -0,0 +1,39 @@
+//! Helpers shared by the integration tests, every test crate uses a part of them.
+#![allow(dead_code)]
+
+use geoplegma::api::DggrsApi;
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, ZoneId};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub fn level(l: i32) -> RefinementLevel {
+    RefinementLevel::new(l).unwrap()
+}
+
+/// The DGGRS registered under the id
+pub fn dggrs(id: DggrsUid) -> Arc<dyn DggrsApi> {
+    geoplegma::get(id).unwrap()
+}
+
+/// The zone containing the point at refinement level `l`
+pub fn zone_at(dggrs: &dyn DggrsApi, l: i32, point: Point) -> ZoneId {
+    dggrs
+        .zone_from_point(level(l), point, None)
+        .unwrap()
+        .zones
+        .remove(0)
+        .id
+}
+
+/// Run the tests one after another with the same instance of a DGGRS
+pub fn test_all(id: DggrsUid, tests: &[fn(Arc<dyn DggrsApi>)]) {
+    let dggrs = dggrs(id);
+    for test in tests {
+        test(dggrs.clone());
+    }
+}
+
+/// A directory for a store, unique to the test process
+pub fn store_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("geoplegma-{}-{name}", std::process::id()))
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use common::{dggrs, level, test_all, zone_at};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::structures::StructureError;
+use geoplegma::structures::coverage::{Coverage, CoverageStorage};
+use geoplegma::types::{BoundingBox, DggrsUid, Point, ZoneId, ZoneIdFormat};
+use std::sync::Arc;
+
+/// Verify that values are set, replaced and removed in a sparse coverage
+fn test_sparse(dggrs: Arc<dyn DggrsApi>) {
+    let mut coverage: Coverage<f64> = Coverage::sparse(dggrs.clone(), level(3)).unwrap();
+    assert!(coverage.is_empty());
+    assert_eq!(coverage.storage(), CoverageStorage::Sparse);
+
+    let a = zone_at(dggrs.as_ref(), 3, Point::new(45.0, 10.0));
+    let b = zone_at(dggrs.as_ref(), 3, Point::new(-30.0, 150.0));
+    assert_eq!(coverage.set(a.clone(), 1.0).unwrap(), None);
+    assert_eq!(coverage.set(b.clone(), 2.0).unwrap(), None);
+    assert_eq!(coverage.set(a.clone(), 3.0).unwrap(), Some(1.0));
+    assert_eq!(coverage.get(&a), Some(&3.0));
+    assert_eq!(coverage.len(), 2);
+
+    *coverage.get_mut(&b).unwrap() += 1.0;
+    assert_eq!(coverage.remove(&b), Some(3.0));
+    assert!(!coverage.contains(&b));
+    assert_eq!(coverage.iter().collect::<Vec<_>>(), vec![(&a, &3.0)]);
+
+    let mut int_ids: Coverage<f64> = Coverage::sparse(dggrs.clone(), level(3)).unwrap();
+    assert_eq!(int_ids.zone_id_format(), ZoneIdFormat::Text);
+    let a = dggrs.format_zone_id(&a, ZoneIdFormat::Int).unwrap();
+    int_ids.set(a, 1.0).unwrap();
+    assert_eq!(int_ids.zone_id_format(), ZoneIdFormat::Int);
+}
+
+/// Verify that zones at another refinement level are rejected
+fn test_level_mismatch(dggrs: Arc<dyn DggrsApi>) {
+    let mut coverage: Coverage<u8> = Coverage::sparse(dggrs.clone(), level(3)).unwrap();
+    let coarse = zone_at(dggrs.as_ref(), 2, Point::new(45.0, 10.0));
+    assert!(matches!(
+        coverage.set(coarse, 1),
+        Err(DggrsError::Structure(StructureError::LevelMismatch { .. }))
+    ));
+}
+
+/// Verify that a dense coverage has a slot for every zone of its extent
+fn test_dense(dggrs: Arc<dyn DggrsApi>) {
+    let extent = BoundingBox::new(5.0, 40.0, 15.0, 50.0);
+    let mut coverage: Coverage<u32> =
+        Coverage::dense(dggrs.clone(), level(3), Some(extent)).unwrap();
+    let slots = coverage.zone_ids().count();
+    assert!(slots > 0);
+    assert!(coverage.is_empty());
+    assert_eq!(coverage.extent(), Some(extent));
+
+    let ids: Vec<ZoneId> = coverage.zone_ids().cloned().collect();
+    for (i, id) in ids.iter().enumerate() {
+        coverage.set(id.clone(), i as u32).unwrap();
+    }
+    assert_eq!(coverage.len(), slots);
+    assert_eq!(coverage.get(&ids[1]), Some(&1));
+
+    let outside = zone_at(dggrs.as_ref(), 3, Point::new(-30.0, 150.0));
+    assert!(matches!(
+        coverage.set(outside, 0),
+        Err(DggrsError::Structure(StructureError::OutsideCoverage(_)))
+    ));
+
+    coverage.remove(&ids[0]);
+    assert_eq!(coverage.len(), slots - 1);
+    assert_eq!(coverage.zone_ids().count(), slots);
+}
+
+/// Verify that zones are partitioned into blocks by their ancestors
+fn test_blocks(dggrs: Arc<dyn DggrsApi>) {
+    let extent = BoundingBox::new(5.0, 40.0, 15.0, 50.0);
+    let mut coverage: Coverage<u32> = Coverage::dense(dggrs.clone(), level(3), Some(extent))
+        .unwrap()
+        .with_blocks(level(1))
+        .unwrap();
+    let ids: Vec<ZoneId> = coverage.zone_ids().cloned().collect();
+    for id in &ids {
+        coverage.set(id.clone(), 1).unwrap();
+    }
+
+    let blocks: Vec<ZoneId> = coverage.blocks().cloned().collect();
+    assert!(!blocks.is_empty());
+    let in_blocks: usize = blocks.iter().map(|b| coverage.block(b).count()).sum();
+    assert_eq!(in_blocks, ids.len());
+    for id in &ids {
+        let block = coverage.block_of(id).unwrap();
+        assert_eq!(dggrs.ancestor_at_level(id, level(1)).unwrap(), *block);
+    }
+
+    assert!(matches!(
+        Coverage::<u32>::sparse(dggrs, level(1))
+            .unwrap()
+            .with_blocks(level(3)),
+        Err(DggrsError::Structure(
+            StructureError::BlockLevelTooFine { .. }
+        ))
+    ));
+}
+
+/// Verify the meta-data and the computed extent of a coverage
+fn test_metadata(dggrs: Arc<dyn DggrsApi>) {
+    let mut coverage: Coverage<f32> = Coverage::sparse(dggrs.clone(), level(3))
+        .unwrap()
+        .with_blocks(level(2))
+        .unwrap();
+    let point = Point::new(45.0, 10.0);
+    coverage
+        .set(zone_at(dggrs.as_ref(), 3, point), 1.0)
+        .unwrap();
+
+    let metadata = coverage.metadata();
+    assert_eq!(metadata.dggrs, dggrs.uid());
+    assert_eq!(metadata.refinement_level, level(3));
+    assert_eq!(metadata.block_level, Some(level(2)));
+    assert_eq!(metadata.data_type, "f32");
+
+    let extent = coverage.compute_extent().unwrap().unwrap();
+    assert!(extent.min_lon < point.lon && point.lon < extent.max_lon);
+    assert!(extent.min_lat < point.lat && point.lat < extent.max_lat);
+}
+
+const TESTS: &[fn(Arc<dyn DggrsApi>)] = &[
+    test_sparse,
+    test_level_mismatch,
+    test_dense,
+    test_blocks,
+    test_metadata,
+];
+
+#[test]
+fn h3_coverage() {
+    test_all(DggrsUid::H3, TESTS);
+}
+
+#[test]
+fn igeo7_coverage() {
+    test_all(DggrsUid::IGEO7, TESTS);
+}
+
+#[test]
+fn isea3h_dggal_coverage() {
+    test_all(DggrsUid::ISEA3HDGGAL, TESTS);
+}
+
+#[test]
+fn level_out_of_range_is_rejected() {
+    let dggrs = dggrs(DggrsUid::H3);
+    assert!(matches!(
+        Coverage::<u8>::sparse(dggrs, level(17)),
+        Err(DggrsError::Structure(
+            StructureError::LevelOutOfRange { .. }
+        ))
+    ));
+}
@@ -0,0 +1,229 @@
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::error::DggrsError;
+use geoplegma::geometry::{
+    Densification, GeometryMode, MAX_VERTICES_PER_EDGE, densify, edge_length,
+};
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, Region, Zone};
+
+fn zone<T: DggrsApi>(adapter: &T, point: Point, densification: Densification) -> Zone {
+    let config = DggrsApiConfig {
+        densification,
+        geometry: GeometryMode::Raw,
+        children: false,
+        neighbors: false,
+        ..Default::default()
+    };
+    adapter
+        .zone_from_point(RefinementLevel::new(2).unwrap(), point, Some(config))
+        .unwrap()
+        .zones
+        .remove(0)
+}
+
+fn exterior(zone: &Zone) -> Vec<Point> {
+    zone.region.as_ref().unwrap().polygons()[0].exterior.clone()
+}
+
+/// The point halfway along the great circle from `a` to `b`
+fn midpoint(a: Point, b: Point) -> Point {
+    let region = densify(Region::new(vec![a, b]), Densification::VerticesPerEdge(1)).unwrap();
+    region.polygons()[0].exterior[1]
+}
+
+#[test]
+fn vertices_per_edge_follow_the_policy() {
+    assert_eq!(Densification::None.vertices_per_edge(1000.0, 0.0), 0);
+    assert_eq!(
+        Densification::VerticesPerEdge(7).vertices_per_edge(1000.0, 0.0),
+        7
+    );
+    assert_eq!(
+        Densification::MaxSegmentLength(300.0).vertices_per_edge(1000.0, 0.0),
+        3
+    );
+    assert_eq!(
+        Densification::MaxSegmentLength(1000.0).vertices_per_edge(1000.0, 0.0),
+        0
+    );
+    // NOTE: geodesics bend away from straight lines towards the poles
+    let deviation = Densification::MaxDeviation(10.0);
+    assert!(
+        deviation.vertices_per_edge(100_000.0, 70.0) > deviation.vertices_per_edge(100_000.0, 10.0)
+    );
+    // NOTE: long edges deviate near the equator as well
+    assert!(deviation.vertices_per_edge(1_000_000.0, 0.0) > 0);
+}
+
+#[test]
+fn vertices_per_edge_are_capped() {
+    assert_eq!(
+        Densification::VerticesPerEdge(u32::MAX).vertices_per_edge(1000.0, 0.0),
+        MAX_VERTICES_PER_EDGE
+    );
+    assert_eq!(
+        Densification::MaxSegmentLength(1e-9).vertices_per_edge(1000.0, 0.0),
+        MAX_VERTICES_PER_EDGE
+    );
+}
+
+#[test]
+fn invalid_lengths_are_rejected() {
+    for max in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        for densification in [
+            Densification::MaxSegmentLength(max),
+            Densification::MaxDeviation(max),
+        ] {
+            assert!(matches!(
+                densification.validate(),
+                Err(DggrsError::InvalidDensification(_))
+            ));
+            assert!(densify(Region::new(vec![Point::new(0.0, 0.0)]), densification).is_err());
+        }
+    }
+    assert!(Densification::MaxSegmentLength(1.0).validate().is_ok());
+
+    let config = DggrsApiConfig {
+        densification: Densification::MaxSegmentLength(0.0),
+        ..DggrsApiConfig::ids_only()
+    };
+    assert!(matches!(
+        H3Impl::default().zone_from_point(
+            RefinementLevel::new(2).unwrap(),
+            Point::new(45.0, 10.0),
+            Some(config)
+        ),
+        Err(DggrsError::InvalidDensification(_))
+    ));
+}
+
+#[test]
+fn densify_inserts_points_on_the_great_circle() {
+    let a = Point::new(10.0, 20.0);
+    let b = Point::new(30.0, 60.0);
+    let region = densify(Region::new(vec![a, b]), Densification::VerticesPerEdge(4)).unwrap();
+    let ring = &region.polygons()[0].exterior;
+    assert_eq!(ring.len(), 11);
+    assert_eq!(ring[0], a);
+    assert_eq!(ring[5], b);
+
+    let length = edge_length(a, b);
+    for point in &ring[1..5] {
+        let through = edge_length(a, *point) + edge_length(*point, b);
+        assert!((through - length).abs() < 1e-3);
+    }
+    assert!((edge_length(a, ring[1]) - length / 5.0).abs() < 1e-3);
+}
+
+#[test]
+fn none_leaves_the_region_unchanged() {
+    let region = Region::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 10.0),
+        Point::new(10.0, 10.0),
+    ]);
+    let densified = densify(region.clone(), Densification::None).unwrap();
+    assert_eq!(densified.polygons()[0], region.polygons()[0]);
+}
+
+/// Verify that without densification the region only has the corners of the zone
+fn test_no_densification<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(45.0, 10.0);
+    let corners = exterior(&zone(adapter, point, Densification::None));
+    assert!(
+        corners.len()
+            <= zone(adapter, point, Densification::None)
+                .vertex_count
+                .unwrap() as usize
+                + 1
+    );
+    let densified = exterior(&zone(adapter, point, Densification::VerticesPerEdge(2)));
+    assert!(densified.len() > corners.len());
+}
+
+/// Verify that no segment of the region is longer than the maximum
+fn test_max_segment_length<T: DggrsApi>(adapter: &T) {
+    let max = 50_000.0;
+    let zone = zone(
+        adapter,
+        Point::new(45.0, 10.0),
+        Densification::MaxSegmentLength(max),
+    );
+    let ring = exterior(&zone);
+    assert!(
+        ring.windows(2)
+            .all(|edge| edge_length(edge[0], edge[1]) <= max * 1.001)
+    );
+}
+
+/// Verify that the straight segments near a pole and near the equator stay close to the geodesic
+fn test_max_deviation<T: DggrsApi>(adapter: &T) {
+    let max = 100.0;
+    for point in [Point::new(80.0, 10.0), Point::new(0.5, 10.0)] {
+        let zone = zone(adapter, point, Densification::MaxDeviation(max));
+        for edge in exterior(&zone).windows(2) {
+            let straight = Point::new(
+                (edge[0].lat + edge[1].lat) / 2.0,
+                (edge[0].lon + edge[1].lon) / 2.0,
+            );
+            assert!(edge_length(straight, midpoint(edge[0], edge[1])) <= max);
+        }
+    }
+}
+
+#[test]
+fn h3_no_densification() {
+    test_no_densification(&H3Impl::default());
+}
+
+#[test]
+fn h3_max_segment_length() {
+    test_max_segment_length(&H3Impl::default());
+}
+
+#[test]
+fn h3_huge_vertices_per_edge() {
+    let (adapter, point) = (H3Impl::default(), Point::new(45.0, 10.0));
+    let corners = exterior(&zone(&adapter, point, Densification::None)).len() - 1;
+    let densified = zone(&adapter, point, Densification::VerticesPerEdge(u32::MAX));
+    assert_eq!(
+        exterior(&densified).len(),
+        corners * (MAX_VERTICES_PER_EDGE as usize + 1) + 1
+    );
+}
+
+#[test]
+fn h3_area_only_has_no_region() {
+    let config = DggrsApiConfig {
+        area_sqm: true,
+        densification: Densification::VerticesPerEdge(4),
+        ..DggrsApiConfig::ids_only()
+    };
+    let zone = H3Impl::default()
+        .zone_from_point(
+            RefinementLevel::new(2).unwrap(),
+            Point::new(45.0, 10.0),
+            Some(config),
+        )
+        .unwrap()
+        .zones
+        .remove(0);
+    assert!(zone.region.is_none());
+    assert!(zone.area_sqm.is_some());
+}
+
+#[test]
+fn h3_max_deviation() {
+    test_max_deviation(&H3Impl::default());
+}
+
+#[test]
+fn dggal_no_densification() {
+    test_no_densification(&DggalImpl::new(DggrsUid::ISEA3HDGGAL));
+}
+
+#[test]
+fn dggal_max_segment_length() {
+    test_max_segment_length(&DggalImpl::new(DggrsUid::ISEA3HDGGAL));
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "json")]
+
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
+use geoplegma::adapters::dggrid::isea3h::Isea3hImpl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::Densification;
+use geoplegma::encoding::dggs_json::DggsJson;
+use geoplegma::encoding::ubjson::{from_ubjson, to_ubjson};
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, RelativeDepth, ZoneId};
+use serde_json::json;
+
+fn parent_zone<T: DggrsApi>(adapter: &T) -> ZoneId {
+    let config = DggrsApiConfig {
+        region: false,
+        area_sqm: false,
+        densification: Densification::None,
+        ..Default::default()
+    };
+    adapter
+        .zone_from_point(
+            RefinementLevel::new(3).unwrap(),
+            Point::new(52.98, 9.06),
+            Some(config),
+        )
+        .unwrap()
+        .zones
+        .first()
+        .unwrap()
+        .id
+        .clone()
+}
+
+/// Verify that values survive DGGS-JSON and DGGS-UBJSON and land on the same sub-zones
+fn test_dggs_json_round_trip<T: DggrsApi>(adapter: &T) {
+    let parent = parent_zone(adapter);
+    let relative_depth = RelativeDepth::new(2).unwrap();
+    let sub_zones = adapter
+        .sub_zone_ids(relative_depth, parent.clone())
+        .unwrap();
+
+    let value = |field: &str, zone_id: &ZoneId| -> Option<f64> {
+        let index = sub_zones.iter().position(|z| z == zone_id)? as f64;
+        match field {
+            "index" => Some(index),
+            _ => (index as u64).is_multiple_of(2).then_some(index / 2.0),
+        }
+    };
+
+    let document =
+        DggsJson::encode(adapter, &parent, relative_depth, &["index", "half"], value).unwrap();
+    assert_eq!(document.depths, vec![relative_depth]);
+    assert_eq!(
+        document.values["index"][0].shape.sub_zones,
+        sub_zones.len() as u64
+    );
+
+    let json = document.to_json().unwrap();
+    assert!(json.contains(r#""zoneId""#));
+    assert_eq!(DggsJson::from_json(&json).unwrap(), document);
+
+    let ubjson = document.to_ubjson().unwrap();
+    assert_eq!(DggsJson::from_ubjson(&ubjson).unwrap(), document);
+
+    let decoded = document.decode(adapter).unwrap();
+    for (zone_id, v) in &decoded["index"] {
+        assert_eq!(*v, value("index", zone_id));
+    }
+    for (zone_id, v) in &decoded["half"] {
+        assert_eq!(*v, value("half", zone_id));
+    }
+}
+
+#[test]
+fn h3_dggs_json_round_trip() {
+    let adapter = H3Impl::default();
+    test_dggs_json_round_trip(&adapter);
+}
+
+#[test]
+fn dggal_dggs_json_round_trip() {
+    let adapter = DggalImpl::new(DggrsUid::ISEA3HDGGAL);
+    test_dggs_json_round_trip(&adapter);
+}
+
+#[test]
+fn igeo7_dggs_json_round_trip() {
+    let adapter = Igeo7Impl::default();
+    test_dggs_json_round_trip(&adapter);
+}
+
+#[test]
+fn isea3h_dggs_json_round_trip() {
+    let adapter = Isea3hImpl::default();
+    test_dggs_json_round_trip(&adapter);
+}
+
+#[test]
+fn dggs_json_of_another_dggrs_is_rejected() {
+    let h3 = H3Impl::default();
+    let document = DggsJson::encode(
+        &h3,
+        &parent_zone(&h3),
+        RelativeDepth::new(1).unwrap(),
+        &["value"],
+        |_, _| Some(1.0),
+    )
+    .unwrap();
+
+    let dggal = DggalImpl::new(DggrsUid::IVEA3H);
+    assert!(document.decode(&dggal).is_err());
+}
+
+#[test]
+fn ubjson_round_trip() {
+    let value = json!({
+        "null": null,
+        "bool": [true, false],
+        "ints": [0, -1, 200, -300, 70000, -5000000000i64, u64::MAX],
+        "float": 1.5,
+        "text": "DGGS",
+        "long": "x".repeat(300),
+    });
+
+    let bytes = to_ubjson(&value);
+    assert_eq!(bytes[0], b'{');
+    assert_eq!(from_ubjson(&bytes).unwrap(), value);
+}
+
+#[test]
+fn ubjson_reads_optimized_containers() {
+    // an array of three uint8 with type and count, and an object with a count
+    let bytes = [
+        b'{', b'#', b'i', 1, b'i', 1, b'a', b'[', b'$', b'U', b'#', b'i', 3, 1, 2, 3,
+    ];
+    assert_eq!(from_ubjson(&bytes).unwrap(), json!({"a": [1, 2, 3]}));
+
+    assert!(from_ubjson(b"[i").is_err());
+    assert!(from_ubjson(b"ZZ").is_err());
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "json")]
+
+use geo::orient::{Direction, Orient};
+use geo::{LineString, Polygon};
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::encoding::geojson::{read_zones, write_zones, zones_from_geojson, zones_to_geojson};
+use geoplegma::types::{
+    Point, Polygon as RegionPolygon, RefinementLevel, Region, Zone, ZoneId, Zones,
+};
+use serde_json::Value;
+
+fn positions(ring: &Value) -> Vec<(f64, f64)> {
+    ring.as_array()
+        .unwrap()
+        .iter()
+        .map(|p| (p[0].as_f64().unwrap(), p[1].as_f64().unwrap()))
+        .collect()
+}
+
+fn is_counter_clockwise(ring: &[(f64, f64)]) -> bool {
+    let polygon = Polygon::new(LineString::from(ring.to_vec()), vec![]);
+    polygon.orient(Direction::Default) == polygon
+}
+
+#[test]
+fn h3_zones_round_trip() {
+    let adapter = H3Impl::default();
+    let zones = adapter
+        .zones_from_bbox(
+            RefinementLevel::new(4).unwrap(),
+            Some(geoplegma::types::BoundingBox::new(8.0, 52.0, 9.0, 53.0)),
+            Some(DggrsApiConfig::default()),
+        )
+        .unwrap();
+
+    let mut buffer = Vec::new();
+    write_zones(&mut buffer, &zones, &adapter).unwrap();
+
+    let value: Value = serde_json::from_slice(&buffer).unwrap();
+    let features = value["features"].as_array().unwrap();
+    assert_eq!(features.len(), zones.zones.len());
+
+    for feature in features {
+        assert_eq!(feature["geometry"]["type"], "Polygon");
+        assert_eq!(feature["properties"]["level"], 4);
+        let ring = positions(&feature["geometry"]["coordinates"][0]);
+        assert!(is_counter_clockwise(&ring));
+    }
+
+    let back = read_zones(buffer.as_slice(), &adapter).unwrap();
+    for (zone, back) in zones.zones.iter().zip(back.zones.iter()) {
+        assert_eq!(back.id, zone.id);
+        assert_eq!(back.center, zone.center);
+        assert_eq!(back.children, zone.children);
+        assert_eq!(back.neighbors, zone.neighbors);
+        assert_eq!(back.vertex_count, zone.vertex_count);
+        assert_eq!(back.area_sqm, zone.area_sqm);
+        assert_eq!(
+            back.region.as_ref().map(|r| r.coords_count()),
+            zone.region.as_ref().map(|r| r.coords_count())
+        );
+    }
+}
+
+#[test]
+fn regions_are_split_at_the_antimeridian() {
+    let adapter = H3Impl::default();
+    let zones = Zones {
+        zones: vec![Zone {
+            id: ZoneId::new_hex("8a1fb46622dffff").unwrap(),
+            region: Some(Region::new(vec![
+                Point::new(10.0, 179.0),
+                Point::new(12.0, 179.0),
+                Point::new(12.0, -179.0),
+                Point::new(10.0, -179.0),
+            ])),
+            ..Default::default()
+        }],
+    };
+
+    let value = zones_to_geojson(&zones, &adapter).unwrap();
+    let geometry = &value["features"][0]["geometry"];
+    assert_eq!(geometry["type"], "MultiPolygon");
+
+    let parts = geometry["coordinates"].as_array().unwrap();
+    assert_eq!(parts.len(), 2);
+    for part in parts {
+        let ring = positions(&part[0]);
+        assert!(ring.iter().all(|(lon, _)| (-180.0..=180.0).contains(lon)));
+        assert!(is_counter_clockwise(&ring));
+    }
+
+    let back = zones_from_geojson(&value, &adapter).unwrap();
+    let Some(Region::MultiPolygon(parts)) = back.zones[0].region.as_ref() else {
+        panic!("the parts stay split");
+    };
+    assert_eq!(parts.len(), 2);
+    let lons: Vec<f64> = parts
+        .iter()
+        .flat_map(|p| p.exterior.iter().map(|p| p.lon))
+        .collect();
+    assert!(lons.iter().all(|lon| (-180.0..=180.0).contains(lon)));
+    assert!(lons.contains(&179.0) && lons.contains(&-179.0));
+}
+
+#[test]
+fn holes_round_trip() {
+    let adapter = H3Impl::default();
+    let ring = |min_lon: f64, size: f64| {
+        vec![
+            Point::new(52.0, min_lon),
+            Point::new(52.0, min_lon + size),
+            Point::new(52.0 + size, min_lon + size),
+            Point::new(52.0 + size, min_lon),
+        ]
+    };
+    let region = Region::Polygon(RegionPolygon::new(ring(8.0, 1.0), vec![ring(8.25, 0.5)]));
+    let zones = Zones {
+        zones: vec![Zone {
+            id: ZoneId::new_hex("8a1fb46622dffff").unwrap(),
+            region: Some(region),
+            ..Default::default()
+        }],
+    };
+
+    let value = zones_to_geojson(&zones, &adapter).unwrap();
+    let coordinates = value["features"][0]["geometry"]["coordinates"]
+        .as_array()
+        .unwrap();
+    assert_eq!(coordinates.len(), 2);
+    assert!(is_counter_clockwise(&positions(&coordinates[0])));
+    assert!(!is_counter_clockwise(&positions(&coordinates[1])));
+
+    let back = zones_from_geojson(&value, &adapter).unwrap();
+    let Some(Region::Polygon(polygon)) = back.zones[0].region.as_ref() else {
+        panic!("a polygon with a hole stays a polygon");
+    };
+    assert_eq!(polygon.interiors.len(), 1);
+    assert_eq!(polygon.interiors[0].len(), 5);
+}
@@ -0,0 +1,140 @@
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::{
+    GeometryMode, crosses_antimeridian, enclosed_pole, normalize, split_antimeridian,
+};
+use geoplegma::types::{Point, Polygon, RefinementLevel, Region, Zone};
+
+fn zone<T: DggrsApi>(adapter: &T, point: Point, geometry: GeometryMode) -> Zone {
+    let config = DggrsApiConfig {
+        geometry,
+        children: false,
+        neighbors: false,
+        ..Default::default()
+    };
+    adapter
+        .zone_from_point(RefinementLevel::new(1).unwrap(), point, Some(config))
+        .unwrap()
+        .zones
+        .remove(0)
+}
+
+fn lons(region: &Region) -> Vec<f64> {
+    region
+        .polygons()
+        .iter()
+        .flat_map(|p| p.exterior.iter().map(|p| p.lon))
+        .collect()
+}
+
+/// Verify that a zone across the antimeridian is split or unwrapped, with the same area in every mode
+fn test_antimeridian_zone<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(5.0, 179.9);
+    let raw = zone(adapter, point, GeometryMode::Raw);
+    let unwrapped = zone(adapter, point, GeometryMode::Unwrap);
+    let split = zone(adapter, point, GeometryMode::Split);
+
+    let Some(Region::MultiPolygon(parts)) = split.region.as_ref() else {
+        panic!("the zone is split at the antimeridian");
+    };
+    assert_eq!(parts.len(), 2);
+    assert!(
+        lons(split.region.as_ref().unwrap())
+            .iter()
+            .all(|lon| (-180.0..=180.0).contains(lon))
+    );
+
+    let Some(Region::Polygon(polygon)) = unwrapped.region.as_ref() else {
+        panic!("the unwrapped zone is one polygon");
+    };
+    assert!(crosses_antimeridian(polygon));
+    assert!(
+        polygon
+            .exterior
+            .windows(2)
+            .all(|edge| (edge[1].lon - edge[0].lon).abs() < 180.0)
+    );
+
+    assert_eq!(raw.area_sqm, split.area_sqm);
+    assert_eq!(raw.area_sqm, unwrapped.area_sqm);
+    let area = raw.area_sqm.unwrap();
+    let split_area = split.region.as_ref().unwrap().geodesic_area();
+    assert!((split_area / area - 1.0).abs() < 1e-4);
+}
+
+/// Verify that a zone around a pole is closed along the pole
+fn test_polar_zone<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(89.9, 0.0);
+    let raw = zone(adapter, point, GeometryMode::Raw);
+    let split = zone(adapter, point, GeometryMode::Split);
+
+    let Some(Region::Polygon(polygon)) = raw.region.as_ref() else {
+        panic!("the raw zone is one polygon");
+    };
+    assert_eq!(enclosed_pole(polygon), Some(90.0));
+
+    let region = split.region.as_ref().unwrap();
+    assert!(
+        region
+            .polygons()
+            .iter()
+            .flat_map(|p| p.exterior.iter())
+            .any(|p| p.lat == 90.0)
+    );
+    assert!(
+        lons(region)
+            .iter()
+            .all(|lon| (-180.0..=180.0).contains(lon))
+    );
+
+    // NOTE: the ring is cut where its great circle edge crosses the antimeridian, so the parts add up to the zone
+    let area = raw.area_sqm.unwrap();
+    assert!((region.geodesic_area() / area - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn h3_antimeridian_zone() {
+    let adapter = H3Impl::default();
+    test_antimeridian_zone(&adapter);
+}
+
+#[test]
+fn h3_polar_zone() {
+    let adapter = H3Impl::default();
+    test_polar_zone(&adapter);
+}
+
+#[test]
+fn east_unwrapped_polygons_are_split() {
+    // NOTE: DGGRID writes zones across the antimeridian with longitudes beyond 180
+    let polygon = Polygon::new(
+        vec![
+            Point::new(10.0, 179.0),
+            Point::new(10.0, 181.0),
+            Point::new(12.0, 181.0),
+            Point::new(12.0, 179.0),
+        ],
+        vec![],
+    );
+    assert!(crosses_antimeridian(&polygon));
+    assert_eq!(enclosed_pole(&polygon), None);
+
+    let parts = split_antimeridian(&polygon);
+    assert_eq!(parts.len(), 2);
+
+    let region = normalize(Region::Polygon(polygon), GeometryMode::Split);
+    assert_eq!(region.polygons(), parts.as_slice());
+    assert_eq!(normalize(region.clone(), GeometryMode::Split), region);
+}
+
+#[test]
+fn polygons_off_the_antimeridian_are_unchanged() {
+    let region = Region::new(vec![
+        Point::new(52.0, 8.0),
+        Point::new(52.0, 9.0),
+        Point::new(53.0, 9.0),
+    ]);
+    for mode in [GeometryMode::Raw, GeometryMode::Unwrap, GeometryMode::Split] {
+        assert_eq!(normalize(region.clone(), mode), region);
+    }
+}
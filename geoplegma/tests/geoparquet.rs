@@ -0,0 +1,254 @@
+#![cfg(feature = "geoparquet")]
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, Float64Array};
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::encoding::geoparquet::{
+    GeoParquetOptions, read_metadata, read_zone_table, read_zones, write_zone_table, write_zones,
+};
+use geoplegma::types::{BoundingBox, DggrsUid, RefinementLevel, Zone, ZoneId, ZoneIdFormat, Zones};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use std::collections::HashSet;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+fn temp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("geoplegma-{}-{name}.parquet", std::process::id()))
+}
+
+fn zones<T: DggrsApi>(adapter: &T, format: ZoneIdFormat) -> Zones {
+    let config = DggrsApiConfig {
+        preferred_id_format: format,
+        ..Default::default()
+    };
+    adapter
+        .zones_from_bbox(
+            RefinementLevel::new(5).unwrap(),
+            Some(BoundingBox::new(8.0, 52.0, 9.0, 53.0)),
+            Some(config),
+        )
+        .unwrap()
+}
+
+fn assert_same_zones(decoded: &[Zone], zones: &[Zone]) {
+    assert_eq!(decoded.len(), zones.len());
+    for (back, zone) in decoded.iter().zip(zones) {
+        assert_eq!(back.id, zone.id);
+        assert_eq!(back.region, zone.region);
+        assert_eq!(back.center, zone.center);
+        assert_eq!(back.vertex_count, zone.vertex_count);
+        assert_eq!(back.area_sqm, zone.area_sqm);
+        assert_eq!(back.neighbors, zone.neighbors);
+        assert_eq!(back.children, zone.children);
+    }
+}
+
+/// Verify that zones written as GeoParquet read back unchanged, with the DGGRS in the file metadata
+fn test_zones_round_trip<T: DggrsApi>(adapter: &T, format: ZoneIdFormat) {
+    let zones = zones(adapter, format);
+    let path = temp_file(&format!("zones-{format:?}"));
+
+    let options = GeoParquetOptions {
+        format,
+        ..Default::default()
+    };
+    write_zones(File::create(&path).unwrap(), &zones, adapter, options).unwrap();
+
+    let metadata = read_metadata(File::open(&path).unwrap()).unwrap();
+    assert_eq!(metadata.dggrs, adapter.uid());
+    assert_eq!(
+        metadata.refinement_level,
+        Some(RefinementLevel::new(5).unwrap())
+    );
+    assert_eq!(metadata.partition_level, None);
+
+    let decoded = read_zones(File::open(&path).unwrap(), adapter).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_same_zones(&decoded.zones, &zones.zones);
+}
+
+/// Verify that the rows of an ancestor share one row group and that consecutive ancestors are packed up to the row group size
+fn test_row_groups_by_ancestor<T: DggrsApi>(adapter: &T) {
+    let zones = zones(adapter, ZoneIdFormat::Text);
+    let partition_level = RefinementLevel::new(3).unwrap();
+    let ancestor = |zone_id: &ZoneId| adapter.ancestor_at_level(zone_id, partition_level).unwrap();
+    let ancestors: HashSet<ZoneId> = zones.zones.iter().map(|z| ancestor(&z.id)).collect();
+    assert!(ancestors.len() > 1);
+
+    let row_group_rows = zones.zones.len() / 2;
+    let path = temp_file("partitioned");
+    let options = GeoParquetOptions {
+        partition_level: Some(partition_level),
+        row_group_rows: Some(row_group_rows),
+        ..Default::default()
+    };
+    write_zones(File::create(&path).unwrap(), &zones, adapter, options).unwrap();
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+    let row_groups = builder.metadata().num_row_groups();
+    assert!(row_groups > 1 && row_groups <= ancestors.len());
+    let mut seen = HashSet::new();
+    for row_group in 0..row_groups {
+        let ids: Vec<ZoneId> = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .with_row_groups(vec![row_group])
+            .build()
+            .unwrap()
+            .flat_map(|batch| {
+                let batch = batch.unwrap();
+                let ids = batch.column_by_name("id").unwrap().as_string::<i32>();
+                ids.iter()
+                    .map(|id| adapter.parse_zone_id(id.unwrap()).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let group_ancestors: HashSet<ZoneId> = ids.iter().map(ancestor).collect();
+        assert!(ids.len() <= row_group_rows || group_ancestors.len() == 1);
+        assert!(group_ancestors.is_disjoint(&seen));
+        seen.extend(group_ancestors);
+    }
+
+    let unlimited = GeoParquetOptions {
+        row_group_rows: None,
+        ..options
+    };
+    write_zones(File::create(&path).unwrap(), &zones, adapter, unlimited).unwrap();
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+    assert_eq!(builder.metadata().num_row_groups(), 1);
+
+    let geo = builder
+        .metadata()
+        .file_metadata()
+        .key_value_metadata()
+        .unwrap()
+        .iter()
+        .find(|kv| kv.key == "geo")
+        .and_then(|kv| kv.value.clone())
+        .unwrap();
+    let geo: serde_json::Value = serde_json::from_str(&geo).unwrap();
+    assert_eq!(geo["primary_column"], "region");
+    assert_eq!(geo["columns"]["region"]["encoding"], "multipolygon");
+
+    let mut decoded = read_zones(File::open(&path).unwrap(), adapter).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mut expected = zones.zones.clone();
+    expected.sort_by(|a, b| a.id.cmp(&b.id));
+    decoded.zones.sort_by(|a, b| a.id.cmp(&b.id));
+    assert_same_zones(&decoded.zones, &expected);
+}
+
+/// Verify that value columns keyed by ZoneId read back unchanged
+fn test_zone_table_round_trip<T: DggrsApi>(adapter: &T, format: ZoneIdFormat) {
+    let zone_ids: Vec<ZoneId> = zones(adapter, format)
+        .zones
+        .into_iter()
+        .map(|z| z.id)
+        .collect();
+    let values: ArrayRef = Arc::new(Float64Array::from_iter(
+        (0..zone_ids.len()).map(|i| (i % 3 != 0).then_some(i as f64)),
+    ));
+    let path = temp_file(&format!("table-{format:?}"));
+
+    let options = GeoParquetOptions {
+        format,
+        partition_level: Some(RefinementLevel::new(4).unwrap()),
+        ..Default::default()
+    };
+    write_zone_table(
+        File::create(&path).unwrap(),
+        adapter,
+        &zone_ids,
+        vec![("value".to_string(), values)],
+        options,
+    )
+    .unwrap();
+
+    let (decoded_ids, batch) = read_zone_table(File::open(&path).unwrap(), adapter).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let decoded_values = batch
+        .column_by_name("value")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap();
+    let mut decoded: Vec<(ZoneId, Option<f64>)> =
+        decoded_ids.into_iter().zip(decoded_values.iter()).collect();
+    let mut expected: Vec<(ZoneId, Option<f64>)> = zone_ids
+        .into_iter()
+        .enumerate()
+        .map(|(i, id)| (id, (i % 3 != 0).then_some(i as f64)))
+        .collect();
+    decoded.sort_by(|a, b| a.0.cmp(&b.0));
+    expected.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn h3_zones_round_trip() {
+    let adapter = H3Impl::default();
+    test_zones_round_trip(&adapter, ZoneIdFormat::Text);
+    test_zones_round_trip(&adapter, ZoneIdFormat::Int);
+}
+
+#[test]
+fn h3_row_groups_by_ancestor() {
+    let adapter = H3Impl::default();
+    test_row_groups_by_ancestor(&adapter);
+}
+
+#[test]
+fn h3_zone_table_round_trip() {
+    let adapter = H3Impl::default();
+    test_zone_table_round_trip(&adapter, ZoneIdFormat::Text);
+    test_zone_table_round_trip(&adapter, ZoneIdFormat::Int);
+}
+
+#[test]
+fn h3_ancestor_at_level() {
+    let adapter = H3Impl::default();
+    let zone_id = ZoneId::new_hex("8a1fb46622dffff").unwrap();
+
+    let ancestor = adapter
+        .ancestor_at_level(&zone_id, RefinementLevel::new(5).unwrap())
+        .unwrap();
+    let info = adapter.validate_zone(&ancestor).unwrap();
+    assert_eq!(info.refinement_level, RefinementLevel::new(5).unwrap());
+    assert_eq!(
+        adapter
+            .ancestor_at_level(&zone_id, RefinementLevel::new(10).unwrap())
+            .unwrap(),
+        zone_id
+    );
+    assert!(
+        adapter
+            .ancestor_at_level(&zone_id, RefinementLevel::new(11).unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn h3_table_of_other_dggrs_is_rejected() {
+    let h3 = H3Impl::default();
+    let zone_ids: Vec<ZoneId> = zones(&h3, ZoneIdFormat::Text)
+        .zones
+        .into_iter()
+        .map(|z| z.id)
+        .collect();
+    let path = temp_file("rejected");
+    write_zone_table(
+        File::create(&path).unwrap(),
+        &h3,
+        &zone_ids,
+        vec![],
+        GeoParquetOptions::default(),
+    )
+    .unwrap();
+
+    let igeo7 = geoplegma::get(DggrsUid::IGEO7).unwrap();
+    assert!(read_zone_table(File::open(&path).unwrap(), igeo7.as_ref()).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::{dggrs, level, test_all, zone_at};
+use geoplegma::api::DggrsApi;
+use geoplegma::structures::index::ZoneIndex;
+use geoplegma::types::{DggrsUid, Point, RelativeDepth, ZoneId, ZoneIdFormat};
+use std::sync::Arc;
+
+/// Records at refinement levels 3 to 5 on a grid of points around central Europe, numbered in order
+fn records(dggrs: &dyn DggrsApi) -> Vec<(ZoneId, usize)> {
+    (0..60)
+        .map(|i| {
+            let point = Point::new(40.0 + (i / 6) as f64 * 1.1, 2.0 + (i % 6) as f64 * 1.3);
+            (zone_at(dggrs, 3 + i as i32 % 3, point), i)
+        })
+        .collect()
+}
+
+/// Verify that keys of ancestors are prefixes and that sub-zones are ordered as by `sub_zone_ids`
+fn test_hierarchy_key(dggrs: Arc<dyn DggrsApi>) {
+    let zone_id = zone_at(dggrs.as_ref(), 5, Point::new(47.3, 8.5));
+    let key = dggrs.hierarchy_key(&zone_id).unwrap();
+    let min_level = i32::from(dggrs.min_refinement_level().unwrap());
+    assert_eq!(key.digits.len() as i32, 5 - min_level);
+    for l in min_level..5 {
+        let ancestor = dggrs.ancestor_at_level(&zone_id, level(l)).unwrap();
+        let ancestor_key = dggrs.hierarchy_key(&ancestor).unwrap();
+        assert!(key.starts_with(&ancestor_key));
+        assert!(ancestor_key < key);
+    }
+
+    let parent = dggrs.ancestor_at_level(&zone_id, level(4)).unwrap();
+    if let Ok(sub_zones) = dggrs.sub_zone_ids(RelativeDepth::new(1).unwrap(), parent) {
+        let keys: Vec<_> = sub_zones
+            .iter()
+            .map(|z| dggrs.hierarchy_key(z).unwrap())
+            .collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+}
+
+/// Verify the range scans, lookups and nearest-zone queries against a linear scan
+fn test_index(dggrs: Arc<dyn DggrsApi>) {
+    let records = records(dggrs.as_ref());
+    let index = ZoneIndex::bulk_load(dggrs.clone(), records.clone()).unwrap();
+    assert_eq!(index.len(), records.len());
+
+    let mut inserted = ZoneIndex::new(dggrs.clone());
+    for (zone_id, value) in records.iter().cloned() {
+        inserted.insert(zone_id, value).unwrap();
+    }
+    assert_eq!(
+        inserted.iter().collect::<Vec<_>>(),
+        index.iter().collect::<Vec<_>>()
+    );
+
+    for (zone_id, _) in &records {
+        let ancestor = dggrs.ancestor_at_level(zone_id, level(2)).unwrap();
+        let mut found: Vec<usize> = index
+            .descendants(&ancestor)
+            .unwrap()
+            .map(|(_, value)| *value)
+            .collect();
+        found.sort();
+        let expected: Vec<usize> = records
+            .iter()
+            .filter(|(z, _)| dggrs.ancestor_at_level(z, level(2)).unwrap() == ancestor)
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    let (zone_id, value) = &records[7];
+    assert!(index.get(zone_id).unwrap().contains(value));
+    let as_int = dggrs.format_zone_id(zone_id, ZoneIdFormat::Int).unwrap();
+    assert!(index.get(&as_int).unwrap().contains(value));
+
+    let center = dggrs.zone_info(zone_id).unwrap().center.unwrap();
+    let (nearest, values) = index.nearest(center).unwrap().unwrap();
+    assert_eq!(nearest, zone_id);
+    assert!(values.contains(value));
+
+    let mut index = index;
+    let removed = index.remove(&as_int).unwrap();
+    assert!(removed.contains(value));
+    assert_eq!(index.len(), records.len() - removed.len());
+    assert!(index.get(zone_id).unwrap().is_empty());
+}
+
+const TESTS: &[fn(Arc<dyn DggrsApi>)] = &[test_hierarchy_key, test_index];
+
+#[test]
+fn h3_index() {
+    test_all(DggrsUid::H3, TESTS);
+}
+
+#[test]
+fn igeo7_index() {
+    test_all(DggrsUid::IGEO7, TESTS);
+}
+
+#[test]
+fn isea3h_dggal_index() {
+    test_all(DggrsUid::ISEA3HDGGAL, TESTS);
+}
+
+#[test]
+fn nearest_in_empty_index_is_none() {
+    let index: ZoneIndex<u8> = ZoneIndex::new(dggrs(DggrsUid::H3));
+    assert!(index.nearest(Point::new(10.0, 10.0)).unwrap().is_none());
+}
+
+/// Verify that records beyond the neighbours of the root zone are found by widening the ring of neighbours
+#[test]
+fn h3_nearest_beyond_the_neighbours_of_the_root_zone() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let near = zone_at(dggrs.as_ref(), 4, Point::new(47.3, 8.5));
+    let far = zone_at(dggrs.as_ref(), 4, Point::new(60.0, 100.0));
+    let index = ZoneIndex::bulk_load(dggrs, [(near.clone(), 1), (far, 2)]).unwrap();
+    let (nearest, values) = index.nearest(Point::new(-34.0, 18.5)).unwrap().unwrap();
+    assert_eq!(nearest, &near);
+    assert_eq!(values, &[1]);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::{dggrs, level};
+use geo::{Geometry, LineString, Polygon, Rect, coord, point};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::structures::StructureError;
+use geoplegma::join::{JoinPair, JoinPredicate, ZoneJoinIndex, candidate_pairs, spatial_join};
+use geoplegma::types::DggrsUid;
+use std::sync::Arc;
+
+/// A grid of points around central Europe
+fn points() -> Vec<Geometry<f64>> {
+    (0..12)
+        .flat_map(|i| {
+            (0..12)
+                .map(move |j| point! { x: 0.3 + i as f64 * 0.83, y: 40.1 + j as f64 * 0.77 }.into())
+        })
+        .collect()
+}
+
+/// Overlapping rectangles, a triangle with a hole and lines, some of them horizontal or vertical
+fn shapes() -> Vec<Geometry<f64>> {
+    let rect = |x: f64, y: f64, w: f64, h: f64| -> Geometry<f64> {
+        Rect::new(coord! { x: x, y: y }, coord! { x: x + w, y: y + h }).into()
+    };
+    vec![
+        rect(1.0, 41.0, 2.0, 2.0),
+        rect(2.5, 42.5, 3.0, 1.5),
+        rect(8.0, 47.0, 0.4, 0.4),
+        Polygon::new(
+            LineString::from(vec![(4.0, 44.0), (9.0, 44.0), (6.5, 49.0), (4.0, 44.0)]),
+            vec![LineString::from(vec![
+                (6.0, 45.0),
+                (7.0, 45.0),
+                (6.5, 46.0),
+                (6.0, 45.0),
+            ])],
+        )
+        .into(),
+        LineString::from(vec![(0.0, 45.2), (10.0, 45.2)]).into(),
+        LineString::from(vec![(5.3, 40.0), (5.3, 50.0)]).into(),
+        LineString::from(vec![(0.5, 49.5), (9.5, 40.5)]).into(),
+    ]
+}
+
+fn brute_force(
+    left: &[Geometry<f64>],
+    right: &[Geometry<f64>],
+    predicate: JoinPredicate,
+) -> Vec<JoinPair> {
+    let mut pairs = Vec::new();
+    for (l, a) in left.iter().enumerate() {
+        for (r, b) in right.iter().enumerate() {
+            if predicate.evaluate(a, b) {
+                pairs.push(JoinPair { left: l, right: r });
+            }
+        }
+    }
+    pairs
+}
+
+/// Verify that the join finds exactly the pairs of a brute-force comparison
+fn test_join(dggrs: Arc<dyn DggrsApi>) {
+    let points = ZoneJoinIndex::from_geometries(dggrs.clone(), level(3), points()).unwrap();
+    let shapes = ZoneJoinIndex::from_geometries(dggrs.clone(), level(3), shapes()).unwrap();
+    assert_eq!(points.len(), 144);
+
+    for predicate in [
+        JoinPredicate::Intersects,
+        JoinPredicate::Contains,
+        JoinPredicate::Within,
+    ] {
+        assert_eq!(
+            spatial_join(&shapes, &points, predicate).unwrap(),
+            brute_force(&self::shapes(), &self::points(), predicate)
+        );
+        assert_eq!(
+            spatial_join(&shapes, &shapes, predicate).unwrap(),
+            brute_force(&self::shapes(), &self::shapes(), predicate)
+        );
+    }
+
+    // NOTE: the candidates are a superset of the intersecting pairs
+    let candidates = candidate_pairs(&points, &shapes).unwrap();
+    let exact = spatial_join(&points, &shapes, JoinPredicate::Within).unwrap();
+    assert!(exact.iter().all(|pair| candidates.contains(pair)));
+    assert!(candidates.len() >= exact.len());
+
+    let polygon = shapes.get(3).unwrap();
+    let inside = points.query(polygon, JoinPredicate::Contains).unwrap();
+    assert!(!inside.is_empty());
+    assert!(points.candidates(polygon).unwrap().len() >= inside.len());
+}
+
+#[test]
+fn h3_join() {
+    test_join(dggrs(DggrsUid::H3));
+}
+
+#[test]
+fn igeo7_join() {
+    test_join(dggrs(DggrsUid::IGEO7));
+}
+
+#[test]
+fn isea3h_dggal_join() {
+    test_join(dggrs(DggrsUid::ISEA3HDGGAL));
+}
+
+#[test]
+fn indexes_at_different_levels_are_rejected() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let left = ZoneJoinIndex::from_geometries(dggrs.clone(), level(3), points()).unwrap();
+    let right = ZoneJoinIndex::from_geometries(dggrs, level(4), points()).unwrap();
+    assert!(matches!(
+        spatial_join(&left, &right, JoinPredicate::Intersects),
+        Err(DggrsError::Structure(
+            StructureError::RefinementLevelMismatch { .. }
+        ))
+    ));
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use common::{dggrs, level};
+use geoplegma::adapters::dggrid::common::read::transformed_points;
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::ingest::points::{Count, Max, Mean, Min, Reducer, Sum, aggregate_points};
+use geoplegma::types::{DggrsUid, Point, ZoneId, ZoneIdFormat};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Deterministic pseudo-random observations around central Europe
+fn observations(count: usize) -> Vec<(Point, f64)> {
+    let mut state: u64 = 42;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    };
+    (0..count)
+        .map(|_| {
+            let point = Point::new(40.0 + next() * 10.0, 5.0 + next() * 10.0);
+            (point, (next() * 100.0).round())
+        })
+        .collect()
+}
+
+/// The values of the points grouped by zone, one zone at a time
+fn naive(dggrs: &dyn DggrsApi, points: &[(Point, f64)]) -> BTreeMap<ZoneId, Vec<f64>> {
+    let mut groups: BTreeMap<ZoneId, Vec<f64>> = BTreeMap::new();
+    for (point, value) in points {
+        let zone_id = dggrs
+            .zone_from_point(level(4), *point, None)
+            .unwrap()
+            .zones
+            .remove(0)
+            .id;
+        groups.entry(zone_id).or_default().push(*value);
+    }
+    groups
+}
+
+/// The spread of the values, to check that reducers can be supplied by the caller
+struct Range;
+
+impl Reducer<f64> for Range {
+    type State = Option<(f64, f64)>;
+    type Output = f64;
+
+    fn init(&self) -> Self::State {
+        None
+    }
+
+    fn add(&self, state: &mut Self::State, value: f64) {
+        self.merge(state, Some((value, value)));
+    }
+
+    fn merge(&self, state: &mut Self::State, other: Self::State) {
+        *state = match (*state, other) {
+            (Some((a, b)), Some((c, d))) => Some((a.min(c), b.max(d))),
+            (a, b) => a.or(b),
+        };
+    }
+
+    fn finish(&self, state: Self::State) -> f64 {
+        state.map_or(0.0, |(min, max)| max - min)
+    }
+}
+
+/// Verify that the batched lookup agrees with `zone_from_point`
+fn test_zone_ids_from_points(dggrs: Arc<dyn DggrsApi>) {
+    let points: Vec<Point> = observations(50).into_iter().map(|(p, _)| p).collect();
+    for format in [ZoneIdFormat::Text, ZoneIdFormat::Int] {
+        let zone_ids = dggrs
+            .zone_ids_from_points(level(4), &points, format)
+            .unwrap();
+        assert_eq!(zone_ids.len(), points.len());
+        for (point, zone_id) in points.iter().zip(zone_ids) {
+            let expected = dggrs
+                .zone_from_point(level(4), *point, None)
+                .unwrap()
+                .zones
+                .remove(0)
+                .id;
+            assert_eq!(dggrs.format_zone_id(&expected, format).unwrap(), zone_id);
+        }
+    }
+}
+
+/// Verify that the built-in and custom reducers agree with a sequential grouping
+fn test_reducers(dggrs: Arc<dyn DggrsApi>, count: usize) {
+    let points = observations(count);
+    let groups = naive(dggrs.as_ref(), &points);
+
+    let counts = aggregate_points(points.clone(), dggrs.clone(), level(4), &Count).unwrap();
+    let sums = aggregate_points(points.clone(), dggrs.clone(), level(4), &Sum).unwrap();
+    let means = aggregate_points(points.clone(), dggrs.clone(), level(4), &Mean).unwrap();
+    let mins = aggregate_points(points.clone(), dggrs.clone(), level(4), &Min).unwrap();
+    let maxs = aggregate_points(points.clone(), dggrs.clone(), level(4), &Max).unwrap();
+    let ranges = aggregate_points(points, dggrs, level(4), &Range).unwrap();
+
+    assert_eq!(counts.len(), groups.len());
+    for (zone_id, values) in &groups {
+        let sum: f64 = values.iter().sum();
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(counts.get(zone_id), Some(&(values.len() as u64)));
+        assert!((sums.get(zone_id).unwrap() - sum).abs() < 1e-6);
+        assert!((means.get(zone_id).unwrap() - sum / values.len() as f64).abs() < 1e-9);
+        assert_eq!(mins.get(zone_id), Some(&min));
+        assert_eq!(maxs.get(zone_id), Some(&max));
+        assert_eq!(ranges.get(zone_id), Some(&(max - min)));
+    }
+}
+
+#[test]
+fn h3_points() {
+    let dggrs = dggrs(DggrsUid::H3);
+    test_zone_ids_from_points(dggrs.clone());
+    test_reducers(dggrs, 20_000);
+}
+
+#[test]
+fn igeo7_points() {
+    let dggrs = dggrs(DggrsUid::IGEO7);
+    test_zone_ids_from_points(dggrs.clone());
+    test_reducers(dggrs, 100);
+}
+
+#[test]
+fn isea3h_dggal_points() {
+    let dggrs = dggrs(DggrsUid::ISEA3HDGGAL);
+    test_zone_ids_from_points(dggrs.clone());
+    test_reducers(dggrs, 20_000);
+}
+
+#[test]
+fn empty_input_gives_empty_coverage() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let coverage = aggregate_points(Vec::<(Point, f64)>::new(), dggrs, level(4), &Sum).unwrap();
+    assert!(coverage.is_empty());
+}
+
+#[test]
+fn h3_invalid_point_is_rejected() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let points = vec![(Point::new(f64::NAN, 10.0), 1.0)];
+    assert!(aggregate_points(points, dggrs, level(4), &Count).is_err());
+}
+
+/// Verify that the ZoneIDs written by DGGRID TRANSFORM_POINTS are read in the order of the points
+#[test]
+fn dggrid_transformed_points_keep_their_order() {
+    let points = [Point::new(45.0, 10.0), Point::new(-30.0, 150.0)];
+    let zone_ids = transformed_points(
+        "0a5fffffffffffff\n\n0123ffffffffffff 150 -30\n",
+        &points,
+        ZoneIdFormat::Int,
+    )
+    .unwrap();
+    assert_eq!(
+        zone_ids,
+        vec![
+            ZoneId::new_int(0x0a5fffffffffffff),
+            ZoneId::new_int(0x0123ffffffffffff)
+        ]
+    );
+
+    assert!(matches!(
+        transformed_points("0a5fffffffffffff\n", &points, ZoneIdFormat::Text),
+        Err(DggrsError::NoZoneAtPoint(point)) if point == points[1]
+    ));
+}
@@ -3,8 +3,8 @@ use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
 use geoplegma::adapters::dggrid::isea3h::Isea3hImpl;
 use geoplegma::adapters::h3o::h3::H3Impl;
 use geoplegma::api::{DggrsApi, DggrsApiConfig};
-use geoplegma::types::{DggrsUid, RefinementLevel, Point};
-
+use geoplegma::geometry::Densification;
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, ZoneIdFormat};
 
 #[test]
 fn h3_parent_from_zone_contains_child_zone() {
@@ -34,12 +34,14 @@ fn test_parent_from_zone_contains_child_zone<T: DggrsApi>(adapter: &T) {
     let point = Point::new(52.98, 9.06);
     let base_config = DggrsApiConfig {
         area_sqm: false,
-        densify: false,
+        densification: Densification::None,
         center: false,
         region: false,
         children: false,
         neighbors: false,
         vertex_count: false,
+        preferred_id_format: ZoneIdFormat::Text,
+        ..Default::default()
     };
     let parent_config = DggrsApiConfig {
         children: true,
@@ -53,7 +55,10 @@ fn test_parent_from_zone_contains_child_zone<T: DggrsApi>(adapter: &T) {
             .unwrap()
             .zones;
 
-        let child_zone = child_zone_result.first().map(|zone| zone.id.clone()).unwrap();
+        let child_zone = child_zone_result
+            .first()
+            .map(|zone| zone.id.clone())
+            .unwrap();
 
         let parent_zone = adapter
             .primary_parent_from_zone(child_zone.clone(), Some(parent_config))
@@ -0,0 +1,158 @@
+mod common;
+
+use common::{dggrs, level, test_all, zone_at};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::structures::StructureError;
+use geoplegma::structures::coverage::Coverage;
+use geoplegma::structures::pyramid::{Aggregation, Pyramid, drill_down, roll_up};
+use geoplegma::types::{DggrsUid, Point, RelativeDepth};
+use std::sync::Arc;
+
+fn total(coverage: &Coverage<f64>) -> f64 {
+    coverage.iter().map(|(_, value)| value).sum()
+}
+
+/// All sub-zones of two zones at level 2, with distinct values
+fn coverage(dggrs: &Arc<dyn DggrsApi>) -> Coverage<f64> {
+    let mut coverage = Coverage::sparse(dggrs.clone(), level(3)).unwrap();
+    for point in [Point::new(45.0, 10.0), Point::new(-20.0, -60.0)] {
+        let parent = dggrs
+            .zone_from_point(level(2), point, None)
+            .unwrap()
+            .zones
+            .remove(0)
+            .id;
+        for (i, zone_id) in dggrs
+            .sub_zone_ids(RelativeDepth::new(1).unwrap(), parent)
+            .unwrap()
+            .into_iter()
+            .enumerate()
+        {
+            coverage.set(zone_id, 1.0 + i as f64).unwrap();
+        }
+    }
+    coverage
+}
+
+/// Verify that sums are preserved and means of constant values are constant when rolling up
+fn test_roll_up(dggrs: Arc<dyn DggrsApi>) {
+    let coverage = coverage(&dggrs);
+    let sums = roll_up(&coverage, Aggregation::Sum).unwrap();
+    assert_eq!(sums.refinement_level(), level(2));
+    assert!((total(&sums) - total(&coverage)).abs() < 1e-9);
+
+    let mut constant = Coverage::sparse(dggrs, level(3)).unwrap();
+    for zone_id in coverage.zone_ids() {
+        constant.set(zone_id.clone(), 5.0).unwrap();
+    }
+    let means = roll_up(&constant, Aggregation::Mean).unwrap();
+    assert!(!means.is_empty());
+    for (_, value) in means.iter() {
+        assert!((value - 5.0).abs() < 1e-9);
+    }
+}
+
+/// Verify that drilling down distributes sums and replicates means
+fn test_drill_down(dggrs: Arc<dyn DggrsApi>) {
+    let coverage = roll_up(&coverage(&dggrs), Aggregation::Sum).unwrap();
+    let distributed = drill_down(&coverage, Aggregation::Sum).unwrap();
+    assert_eq!(distributed.refinement_level(), level(3));
+    assert!((total(&distributed) - total(&coverage)).abs() < 1e-6);
+
+    let mut constant = Coverage::sparse(dggrs, level(2)).unwrap();
+    for zone_id in coverage.zone_ids() {
+        constant.set(zone_id.clone(), 5.0).unwrap();
+    }
+    let replicated = drill_down(&constant, Aggregation::Mean).unwrap();
+    assert!(!replicated.is_empty());
+    for (_, value) in replicated.iter() {
+        assert!((value - 5.0).abs() < 1e-9);
+    }
+}
+
+/// Verify that a pyramid holds one coverage per level with the same total
+fn test_pyramid(dggrs: Arc<dyn DggrsApi>) {
+    let coverage = coverage(&dggrs);
+    let expected = total(&coverage);
+    let pyramid = Pyramid::build(coverage, None, Aggregation::Sum).unwrap();
+    assert_eq!(pyramid.finest_level(), level(3));
+    assert_eq!(
+        pyramid.coarsest_level(),
+        dggrs.min_refinement_level().unwrap()
+    );
+    assert_eq!(
+        pyramid.levels().count() as i32,
+        3 - i32::from(dggrs.min_refinement_level().unwrap()) + 1
+    );
+    for coverage in pyramid.levels() {
+        assert!((total(coverage) - expected).abs() < 1e-6);
+    }
+    assert_eq!(pyramid.get(level(2)).unwrap().refinement_level(), level(2));
+}
+
+const TESTS: &[fn(Arc<dyn DggrsApi>)] = &[test_roll_up, test_drill_down, test_pyramid];
+
+#[test]
+fn h3_pyramid() {
+    test_all(DggrsUid::H3, TESTS);
+}
+
+#[test]
+fn isea3h_dggrid_pyramid() {
+    test_all(DggrsUid::ISEA3HDGGRID, TESTS);
+}
+
+#[test]
+fn igeo7_pyramid() {
+    test_all(DggrsUid::IGEO7, TESTS);
+}
+
+#[test]
+fn isea3h_dggal_pyramid() {
+    test_all(DggrsUid::ISEA3HDGGAL, TESTS);
+}
+
+#[test]
+fn ivea7h_pyramid() {
+    test_all(DggrsUid::IVEA7H, TESTS);
+}
+
+#[test]
+fn pyramid_below_the_coarsest_level_is_rejected() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let coverage = Coverage::sparse(dggrs, level(0)).unwrap();
+    assert!(matches!(
+        roll_up(&coverage, Aggregation::Mean),
+        Err(DggrsError::Structure(StructureError::NoCoarserLevel(_)))
+    ));
+    assert!(matches!(
+        Pyramid::build(coverage, Some(level(1)), Aggregation::Mean),
+        Err(DggrsError::Structure(
+            StructureError::LevelOutOfRange { .. }
+        ))
+    ));
+}
+
+/// Verify that the mean of a partly covered parent is weighted by the areas of its sub-zones
+#[test]
+fn h3_mean_is_weighted_by_area() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let parent = zone_at(dggrs.as_ref(), 2, Point::new(45.0, 10.0));
+    let sub_zone_ids = dggrs
+        .sub_zone_ids(RelativeDepth::new(1).unwrap(), parent.clone())
+        .unwrap();
+    let mut coverage = Coverage::sparse(dggrs.clone(), level(3)).unwrap();
+    let (mut sum, mut area) = (0.0, 0.0);
+    for (zone_id, value) in sub_zone_ids.into_iter().zip([1.0, 3.0]) {
+        let zone_area = dggrs.zone_from_id(zone_id.clone(), None).unwrap().zones[0]
+            .area_sqm
+            .unwrap();
+        sum += value * zone_area;
+        area += zone_area;
+        coverage.set(zone_id, value).unwrap();
+    }
+    let means = roll_up(&coverage, Aggregation::Mean).unwrap();
+    assert_eq!(means.len(), 1);
+    assert!((means.get(&parent).unwrap() - sum / area).abs() < 1e-12);
+}
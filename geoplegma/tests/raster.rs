@@ -0,0 +1,230 @@
+mod common;
+
+use common::{dggrs, level, test_all};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::ingest::IngestError;
+use geoplegma::ingest::raster::{
+    AffineTransform, Raster, RasterIngestConfig, Resampling, ingest_raster,
+};
+use geoplegma::types::{DggrsUid, Point};
+use std::sync::Arc;
+
+const PIXEL: f64 = 0.25;
+
+/// A linear field, bilinear interpolation reproduces it exactly
+fn field(lon: f64, lat: f64) -> f64 {
+    lat + 2.0 * lon
+}
+
+/// A raster of the field from `origin_lon` eastwards and from 50° N southwards
+fn grid(origin_lon: f64, width: usize, height: usize) -> Vec<f64> {
+    (0..height)
+        .flat_map(|row| {
+            (0..width).map(move |col| {
+                field(
+                    origin_lon + (col as f64 + 0.5) * PIXEL,
+                    50.0 - (row as f64 + 0.5) * PIXEL,
+                )
+            })
+        })
+        .collect()
+}
+
+fn config(resampling: Resampling, tile_size: f64) -> RasterIngestConfig {
+    RasterIngestConfig {
+        resampling,
+        tile_size,
+    }
+}
+
+fn center(dggrs: &dyn DggrsApi, zone_id: &geoplegma::types::ZoneId) -> Point {
+    dggrs.zone_info(zone_id).unwrap().center.unwrap()
+}
+
+/// Verify that the nearest resampling takes the pixel containing the centroid, independently of the tiles
+fn test_nearest(dggrs: Arc<dyn DggrsApi>) {
+    let values = grid(0.0, 80, 80);
+    let raster = Raster::new(
+        &values,
+        80,
+        80,
+        AffineTransform::new(0.0, PIXEL, 50.0, -PIXEL),
+        4326,
+    )
+    .unwrap();
+    let coverage = ingest_raster(
+        &raster,
+        dggrs.clone(),
+        level(3),
+        config(Resampling::Nearest, 3.0),
+    )
+    .unwrap();
+    assert!(!coverage.is_empty());
+    for (zone_id, value) in coverage.iter() {
+        let c = center(dggrs.as_ref(), zone_id);
+        let lon = ((c.lon / PIXEL).floor() + 0.5) * PIXEL;
+        let lat = 50.0 - (((50.0 - c.lat) / PIXEL).floor() + 0.5) * PIXEL;
+        assert!((value - field(lon, lat)).abs() < 1e-9);
+    }
+
+    let one_tile =
+        ingest_raster(&raster, dggrs, level(3), config(Resampling::Nearest, 100.0)).unwrap();
+    assert_eq!(
+        one_tile.iter().collect::<Vec<_>>(),
+        coverage.iter().collect::<Vec<_>>()
+    );
+}
+
+/// Verify that the bilinear resampling reproduces a linear field at the centroids
+fn test_bilinear(dggrs: Arc<dyn DggrsApi>) {
+    let values = grid(0.0, 80, 80);
+    let raster = Raster::new(
+        &values,
+        80,
+        80,
+        AffineTransform::new(0.0, PIXEL, 50.0, -PIXEL),
+        4326,
+    )
+    .unwrap();
+    let coverage = ingest_raster(
+        &raster,
+        dggrs.clone(),
+        level(3),
+        config(Resampling::Bilinear, 10.0),
+    )
+    .unwrap();
+    let mut interior = 0;
+    for (zone_id, value) in coverage.iter() {
+        let c = center(dggrs.as_ref(), zone_id);
+        if c.lon > PIXEL && c.lon < 20.0 - PIXEL && c.lat > 30.0 + PIXEL && c.lat < 50.0 - PIXEL {
+            assert!((value - field(c.lon, c.lat)).abs() < 1e-6);
+            interior += 1;
+        }
+    }
+    assert!(interior > 0);
+}
+
+/// Verify that the area-weighted mean of a constant field is the constant and that no-data pixels are skipped
+fn test_area_weighted_mean(dggrs: Arc<dyn DggrsApi>) {
+    let values: Vec<f64> = (0..80 * 80)
+        .map(|i| if i % 80 < 40 { 7.0 } else { -9999.0 })
+        .collect();
+    let raster = Raster::new(
+        &values,
+        80,
+        80,
+        AffineTransform::new(0.0, PIXEL, 50.0, -PIXEL),
+        4326,
+    )
+    .unwrap()
+    .with_nodata(-9999.0);
+    let coverage = ingest_raster(
+        &raster,
+        dggrs.clone(),
+        level(3),
+        config(Resampling::AreaWeightedMean, 10.0),
+    )
+    .unwrap();
+    assert!(!coverage.is_empty());
+    for (zone_id, value) in coverage.iter() {
+        assert!((value - 7.0).abs() < 1e-9);
+        assert!(center(dggrs.as_ref(), zone_id).lon < 10.0 + 2.0);
+    }
+}
+
+/// Verify that rasters with longitudes beyond 180° are resampled across the antimeridian of the raster
+fn test_shifted_longitudes(dggrs: Arc<dyn DggrsApi>) {
+    let values = grid(340.0, 160, 80);
+    let raster = Raster::new(
+        &values,
+        160,
+        80,
+        AffineTransform::new(340.0, PIXEL, 50.0, -PIXEL),
+        4326,
+    )
+    .unwrap();
+    let coverage = ingest_raster(
+        &raster,
+        dggrs.clone(),
+        level(3),
+        config(Resampling::Nearest, 10.0),
+    )
+    .unwrap();
+    for point in [Point::new(45.0, 5.0), Point::new(45.0, -10.0)] {
+        let zone_id = dggrs
+            .zone_from_point(level(3), point, None)
+            .unwrap()
+            .zones
+            .remove(0)
+            .id;
+        let c = center(dggrs.as_ref(), &zone_id);
+        let lon = c.lon.rem_euclid(360.0);
+        let lon = ((lon / PIXEL).floor() + 0.5) * PIXEL;
+        let lat = 50.0 - (((50.0 - c.lat) / PIXEL).floor() + 0.5) * PIXEL;
+        let value = coverage.get(&zone_id).unwrap();
+        let lon = if lon < 340.0 { lon + 360.0 } else { lon };
+        assert!((value - field(lon, lat)).abs() < 1e-9);
+    }
+}
+
+const TESTS: &[fn(Arc<dyn DggrsApi>)] = &[
+    test_nearest,
+    test_bilinear,
+    test_area_weighted_mean,
+    test_shifted_longitudes,
+];
+
+#[test]
+fn h3_raster() {
+    test_all(DggrsUid::H3, TESTS);
+}
+
+#[test]
+fn igeo7_raster() {
+    test_all(DggrsUid::IGEO7, TESTS);
+}
+
+#[test]
+fn isea3h_dggal_raster() {
+    test_all(DggrsUid::ISEA3HDGGAL, TESTS);
+}
+
+#[test]
+fn invalid_rasters_are_rejected() {
+    let values = vec![0.0; 6];
+    let transform = AffineTransform::new(0.0, 1.0, 10.0, -1.0);
+    assert!(matches!(
+        Raster::new(&values, 3, 2, transform, 3857),
+        Err(IngestError::UnsupportedCrs(3857))
+    ));
+    assert!(matches!(
+        Raster::new(&values, 4, 2, transform, 4326),
+        Err(IngestError::ShapeMismatch {
+            expected: 8,
+            found: 6,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Raster::new(
+            &values,
+            3,
+            2,
+            AffineTransform::new(0.0, 0.0, 10.0, -1.0),
+            4326
+        ),
+        Err(IngestError::SingularTransform)
+    ));
+
+    let raster = Raster::new(&values, 3, 2, transform, 4326).unwrap();
+    assert!(matches!(
+        ingest_raster(
+            &raster,
+            dggrs(DggrsUid::H3),
+            level(3),
+            config(Resampling::Nearest, 0.0)
+        ),
+        Err(DggrsError::Ingest(IngestError::InvalidTileSize(_)))
+    ));
+}
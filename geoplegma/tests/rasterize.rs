@@ -0,0 +1,169 @@
+mod common;
+
+use common::{dggrs, level, zone_at};
+use geoplegma::api::DggrsApi;
+use geoplegma::encoding::raster::{Projection, RasterGrid, rasterize, rasterize_coverage};
+use geoplegma::error::encoding::EncodingError;
+use geoplegma::structures::coverage::Coverage;
+use geoplegma::types::{DggrsUid, Point, ZoneId};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Values for the zones around central Europe, numbered in the order they are found
+fn values(dggrs: &dyn DggrsApi) -> BTreeMap<ZoneId, f64> {
+    let mut values = BTreeMap::new();
+    for lat in 40..50 {
+        for lon in 0..10 {
+            let zone_id = zone_at(dggrs, 3, Point::new(lat as f64 + 0.5, lon as f64 + 0.5));
+            let next = values.len() as f64;
+            values.entry(zone_id).or_insert(next);
+        }
+    }
+    values
+}
+
+/// Verify that every pixel takes the value of the zone containing its centre
+fn test_rasterize(dggrs: Arc<dyn DggrsApi>, grid: RasterGrid) {
+    let values = values(dggrs.as_ref());
+    let raster = rasterize(dggrs.as_ref(), level(3), &values, &grid).unwrap();
+    assert_eq!(raster.values.len(), grid.width() * grid.height());
+    let mut found = 0;
+    for row in 0..grid.height() {
+        for col in 0..grid.width() {
+            let (x, y) = grid.pixel_center(col, row);
+            let zone_id = zone_at(dggrs.as_ref(), 3, grid.projection.to_point(x, y));
+            assert_eq!(raster.get(col, row), values.get(&zone_id).copied());
+            found += raster.get(col, row).is_some() as usize;
+        }
+    }
+    assert!(found > 0);
+
+    let mut coverage = Coverage::sparse(dggrs, level(3)).unwrap();
+    for (zone_id, value) in &values {
+        coverage.set(zone_id.clone(), *value).unwrap();
+    }
+    let from_coverage = rasterize_coverage(&coverage, &grid).unwrap();
+    assert_eq!(
+        from_coverage
+            .values
+            .iter()
+            .map(|v| v.to_bits())
+            .collect::<Vec<_>>(),
+        raster
+            .values
+            .iter()
+            .map(|v| v.to_bits())
+            .collect::<Vec<_>>()
+    );
+}
+
+fn test_all(id: DggrsUid) {
+    let dggrs = dggrs(id);
+    let wgs84 = RasterGrid::new(Projection::Wgs84, 0.0, 40.0, 10.0, 50.0, 0.5).unwrap();
+    test_rasterize(dggrs.clone(), wgs84);
+    let web_mercator = RasterGrid::new(
+        Projection::WebMercator,
+        0.0,
+        4_900_000.0,
+        1_100_000.0,
+        6_400_000.0,
+        50_000.0,
+    )
+    .unwrap();
+    test_rasterize(dggrs, web_mercator);
+}
+
+#[test]
+fn h3_rasterize() {
+    test_all(DggrsUid::H3);
+}
+
+#[test]
+fn igeo7_rasterize() {
+    test_all(DggrsUid::IGEO7);
+}
+
+#[test]
+fn isea3h_dggal_rasterize() {
+    test_all(DggrsUid::ISEA3HDGGAL);
+}
+
+/// Verify that pixels are placed correctly across the tiles of a raster wider than one tile
+#[test]
+fn h3_rasterize_across_tiles() {
+    let grid = RasterGrid::new(Projection::Wgs84, 0.0, 44.4, 10.02, 45.0, 0.03).unwrap();
+    assert!(grid.width() > 256);
+    test_rasterize(dggrs(DggrsUid::H3), grid);
+}
+
+/// Verify that the centres of the last column and row of a global grid are located, though they lie beyond the antimeridian and the pole
+#[test]
+fn h3_rasterize_global_grid() {
+    let grid = RasterGrid::new(Projection::Wgs84, -180.0, -90.0, 180.0, 90.0, 6.9).unwrap();
+    let (x, y) = grid.pixel_center(grid.width() - 1, grid.height() - 1);
+    assert!(x > 180.0 && y < -90.0);
+    let point = grid.projection.to_point(x, y);
+    assert!((point.lon + 177.75).abs() < 1e-9);
+    assert_eq!(point.lat, -90.0);
+    test_rasterize(dggrs(DggrsUid::H3), grid);
+}
+
+#[test]
+fn web_mercator_is_unprojected() {
+    let point = Projection::WebMercator.to_point(1_113_194.9079327357, 1_118_889.9748579597);
+    assert!((point.lon - 10.0).abs() < 1e-9);
+    assert!((point.lat - 10.0).abs() < 1e-9);
+}
+
+/// Verify the world file, the raw values and the tags of the GeoTIFF
+#[test]
+fn rendered_raster_is_written() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let grid = RasterGrid::new(Projection::Wgs84, 0.0, 40.0, 10.0, 50.0, 0.5).unwrap();
+    let raster = rasterize(dggrs.as_ref(), level(3), &values(dggrs.as_ref()), &grid).unwrap();
+    assert_eq!(raster.world_file(), "0.5\n0\n0\n-0.5\n0.25\n49.75\n");
+
+    let dir = std::env::temp_dir().join("geoplegma_rasterize_test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let raw = dir.join("values.bin");
+    raster.write_raw(&raw).unwrap();
+    assert_eq!(std::fs::metadata(&raw).unwrap().len(), 20 * 20 * 8);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("values.wld")).unwrap(),
+        raster.world_file()
+    );
+
+    let tiff = raster.to_geotiff();
+    assert_eq!(&tiff[..4], b"II*\0");
+    assert_eq!(&tiff[8..16], &raster.values[0].to_le_bytes());
+    let ifd = u32::from_le_bytes(tiff[4..8].try_into().unwrap()) as usize;
+    let count = u16::from_le_bytes(tiff[ifd..ifd + 2].try_into().unwrap()) as usize;
+    let tag = |tag: u16| {
+        (0..count)
+            .map(|i| &tiff[ifd + 2 + i * 12..ifd + 14 + i * 12])
+            .find(|entry| u16::from_le_bytes([entry[0], entry[1]]) == tag)
+            .map(|entry| u32::from_le_bytes(entry[8..12].try_into().unwrap()))
+    };
+    assert_eq!(tag(256), Some(20));
+    assert_eq!(tag(257), Some(20));
+    assert_eq!(tag(258), Some(64));
+    assert_eq!(tag(339), Some(3));
+    assert!(tag(33922).is_some());
+    assert!(tag(34735).is_some());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn invalid_grids_are_rejected() {
+    assert!(matches!(
+        Projection::from_epsg(32633),
+        Err(EncodingError::InvalidInput { .. })
+    ));
+    assert_eq!(
+        Projection::from_epsg(3857).unwrap(),
+        Projection::WebMercator
+    );
+    assert!(RasterGrid::new(Projection::Wgs84, 0.0, 0.0, 10.0, 10.0, 0.0).is_err());
+    assert!(RasterGrid::new(Projection::Wgs84, 10.0, 0.0, 0.0, 10.0, 1.0).is_err());
+    assert!(RasterGrid::new(Projection::Wgs84, 0.0, 0.0, f64::NAN, 10.0, 1.0).is_err());
+}
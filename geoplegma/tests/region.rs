@@ -0,0 +1,104 @@
+use geoplegma::encoding::wkb::region_to_wkb;
+use geoplegma::encoding::wkt::region_to_wkt;
+use geoplegma::types::{Point, Polygon, Region};
+
+fn square(min_lon: f64, min_lat: f64, size: f64) -> Vec<Point> {
+    vec![
+        Point::new(min_lat, min_lon),
+        Point::new(min_lat, min_lon + size),
+        Point::new(min_lat + size, min_lon + size),
+        Point::new(min_lat + size, min_lon),
+    ]
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6 * a.abs().max(1.0)
+}
+
+#[test]
+fn rings_are_closed() {
+    let polygon = Polygon::new(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 1.0)]);
+    assert_eq!(polygon.exterior.len(), 5);
+    assert_eq!(polygon.interiors[0].len(), 5);
+    assert_eq!(polygon.exterior.first(), polygon.exterior.last());
+    assert_eq!(Region::Polygon(polygon).coords_count(), 10);
+}
+
+#[test]
+fn area_subtracts_holes_and_ignores_winding() {
+    let outer = Region::new(square(0.0, 0.0, 4.0));
+    let hole = Region::new(square(1.0, 1.0, 1.0));
+    let with_hole = Region::Polygon(Polygon::new(
+        square(0.0, 0.0, 4.0),
+        vec![square(1.0, 1.0, 1.0)],
+    ));
+    assert!(close(
+        with_hole.geodesic_area(),
+        outer.geodesic_area() - hole.geodesic_area()
+    ));
+
+    let mut clockwise = square(0.0, 0.0, 4.0);
+    clockwise.reverse();
+    assert!(close(
+        Region::new(clockwise).geodesic_area(),
+        outer.geodesic_area()
+    ));
+}
+
+#[test]
+fn area_of_a_zone_across_the_antimeridian() {
+    let crossing = Region::new(vec![
+        Point::new(10.0, 179.0),
+        Point::new(10.0, -179.0),
+        Point::new(12.0, -179.0),
+        Point::new(12.0, 179.0),
+    ]);
+    let half = |min_lon: f64| {
+        Polygon::new(
+            vec![
+                Point::new(10.0, min_lon),
+                Point::new(10.0, min_lon + 1.0),
+                Point::new(12.0, min_lon + 1.0),
+                Point::new(12.0, min_lon),
+            ],
+            vec![],
+        )
+    };
+    let split = Region::MultiPolygon(vec![half(179.0), half(-180.0)]);
+    let meridian = Region::new(square(-1.0, 10.0, 2.0));
+
+    assert!(close(crossing.geodesic_area(), meridian.geodesic_area()));
+    // NOTE: the halves have shorter geodesic edges, which bow less towards the pole
+    let ratio = split.geodesic_area() / meridian.geodesic_area();
+    assert!((ratio - 1.0).abs() < 1e-3, "ratio {ratio}");
+}
+
+#[test]
+fn wkt_and_wkb_of_holes_and_multipolygons() {
+    let with_hole = Region::Polygon(Polygon::new(
+        square(0.0, 0.0, 4.0),
+        vec![square(1.0, 1.0, 1.0)],
+    ));
+    assert_eq!(
+        region_to_wkt(&with_hole),
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))"
+    );
+
+    let wkb = region_to_wkb(&with_hole);
+    assert_eq!(u32::from_le_bytes(wkb[5..9].try_into().unwrap()), 2);
+    assert_eq!(wkb.len(), 1 + 4 + 4 + 2 * 4 + 10 * 16);
+
+    let multi = Region::MultiPolygon(vec![
+        Polygon::new(square(0.0, 0.0, 1.0), vec![]),
+        Polygon::new(square(2.0, 0.0, 1.0), vec![]),
+    ]);
+    assert_eq!(
+        region_to_wkt(&multi),
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)), ((2 0, 3 0, 3 1, 2 1, 2 0)))"
+    );
+
+    let wkb = region_to_wkb(&multi);
+    assert_eq!(u32::from_le_bytes(wkb[1..5].try_into().unwrap()), 6);
+    assert_eq!(u32::from_le_bytes(wkb[5..9].try_into().unwrap()), 2);
+    assert_eq!(wkb.len(), 1 + 4 + 4 + 2 * (1 + 4 + 4 + 4 + 5 * 16));
+}
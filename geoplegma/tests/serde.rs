@@ -0,0 +1,160 @@
+#![cfg(feature = "serde")]
+
+use geoplegma::api::DggrsApiConfig;
+use geoplegma::geometry::Densification;
+use geoplegma::types::{
+    BoundingBox, DggrsSpec, DggrsUid, Point, Polygon, RefinementLevel, Region, RelativeDepth, Zone,
+    ZoneId, ZoneIdFormat, Zones,
+};
+use serde_json::json;
+
+#[test]
+fn zone_id_is_tagged_by_format() {
+    let ids = [
+        (ZoneId::new_str("A4-0-A").unwrap(), r#"{"str":"A4-0-A"}"#),
+        (
+            ZoneId::new_hex("8a1fb46622dffff").unwrap(),
+            r#"{"hex":"8a1fb46622dffff"}"#,
+        ),
+        (
+            ZoneId::new_int(622236750694711295),
+            r#"{"int":622236750694711295}"#,
+        ),
+    ];
+
+    for (id, json) in ids {
+        assert_eq!(serde_json::to_string(&id).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ZoneId>(json).unwrap(), id);
+    }
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    assert!(serde_json::from_str::<ZoneId>(r#"{"hex":"not-hex"}"#).is_err());
+    assert!(serde_json::from_str::<ZoneId>(r#"{"str":""}"#).is_err());
+    assert!(serde_json::from_str::<RefinementLevel>("-1").is_err());
+    assert!(serde_json::from_str::<RelativeDepth>("-2").is_err());
+    assert_eq!(
+        serde_json::from_str::<RefinementLevel>("5").unwrap(),
+        RefinementLevel::new(5).unwrap()
+    );
+}
+
+#[test]
+fn zones_round_trip() {
+    let zones = Zones {
+        zones: vec![Zone {
+            id: ZoneId::new_hex("8a1fb46622dffff").unwrap(),
+            region: Some(Region::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(0.0, 1.0),
+                Point::new(1.0, 1.0),
+            ])),
+            center: Some(Point::new(0.5, 0.5)),
+            vertex_count: Some(3),
+            children: None,
+            neighbors: Some(vec![ZoneId::new_int(7)]),
+            area_sqm: None,
+        }],
+    };
+
+    let json = serde_json::to_string(&zones).unwrap();
+    assert!(!json.contains("children"));
+
+    let back: Zones = serde_json::from_str(&json).unwrap();
+    let (zone, back) = (&zones.zones[0], &back.zones[0]);
+    assert_eq!(back.id, zone.id);
+    assert_eq!(back.region, zone.region);
+    assert_eq!(back.center, zone.center);
+    assert_eq!(back.vertex_count, zone.vertex_count);
+    assert_eq!(back.children, None);
+    assert_eq!(back.neighbors, zone.neighbors);
+}
+
+#[test]
+fn region_is_tagged_by_kind() {
+    let ring = vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 1.0),
+        Point::new(1.0, 1.0),
+    ];
+    let closed = json!([
+        {"lat": 0.0, "lon": 0.0},
+        {"lat": 0.0, "lon": 1.0},
+        {"lat": 1.0, "lon": 1.0},
+        {"lat": 0.0, "lon": 0.0}
+    ]);
+
+    let polygon = Region::new(ring.clone());
+    assert_eq!(
+        serde_json::to_value(&polygon).unwrap(),
+        json!({"polygon": {"exterior": closed}})
+    );
+
+    let with_hole = Polygon::new(ring.clone(), vec![ring.clone()]);
+    let multi = Region::MultiPolygon(vec![with_hole, Polygon::new(ring, vec![])]);
+    let json = serde_json::to_value(&multi).unwrap();
+    assert_eq!(
+        json,
+        json!({"multipolygon": [
+            {"exterior": closed, "interiors": [closed]},
+            {"exterior": closed}
+        ]})
+    );
+    assert_eq!(serde_json::from_value::<Region>(json).unwrap(), multi);
+    assert_eq!(
+        serde_json::from_value::<Region>(json!({"polygon": {"exterior": closed}})).unwrap(),
+        polygon
+    );
+}
+
+#[test]
+fn bounding_box_round_trip() {
+    let bbox = BoundingBox::new(-10.0, 35.0, 5.0, 45.0);
+    let json = serde_json::to_string(&bbox).unwrap();
+    assert_eq!(serde_json::from_str::<BoundingBox>(&json).unwrap(), bbox);
+}
+
+#[test]
+fn config_fills_missing_fields_with_defaults() {
+    let config: DggrsApiConfig =
+        serde_json::from_str(r#"{"region":false,"preferred_id_format":"int"}"#).unwrap();
+    assert!(!config.region);
+    assert!(config.center);
+    assert_eq!(config.preferred_id_format, ZoneIdFormat::Int);
+
+    let json = serde_json::to_string(&config).unwrap();
+    let back: DggrsApiConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.region, config.region);
+    assert_eq!(back.densification, config.densification);
+}
+
+#[test]
+fn spec_is_looked_up_by_id() {
+    let json = serde_json::to_string(DggrsUid::IGEO7.spec()).unwrap();
+    assert!(json.contains(r#""id":"IGEO7""#));
+
+    let spec: DggrsSpec = serde_json::from_str(&json).unwrap();
+    assert_eq!(spec.id, DggrsUid::IGEO7);
+    assert_eq!(spec.aperture, 7);
+
+    let spec: DggrsSpec = serde_json::from_str(r#"{"id":"H3"}"#).unwrap();
+    assert_eq!(spec.id, DggrsUid::H3);
+    assert_eq!(
+        spec.max_refinement_level,
+        DggrsUid::H3.spec().max_refinement_level
+    );
+}
+
+#[test]
+fn densification_is_tagged_by_policy() {
+    let config = DggrsApiConfig {
+        densification: Densification::MaxSegmentLength(500.0),
+        ..Default::default()
+    };
+    let json = serde_json::to_value(config).unwrap();
+    assert_eq!(json["densification"], json!({"max_segment_length": 500.0}));
+
+    let none: Densification = serde_json::from_str(r#""none""#).unwrap();
+    assert_eq!(none, Densification::None);
+}
@@ -0,0 +1,153 @@
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
+use geoplegma::adapters::dggrid::isea3h::Isea3hImpl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::Densification;
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, RelativeDepth, ZoneId, ZoneIdFormat};
+
+/// Verify that the ordered sub-zones, sub_zone_from_index and sub_zone_index agree with each other
+fn test_sub_zone_index_round_trip<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(52.98, 9.06);
+    let config = DggrsApiConfig {
+        area_sqm: false,
+        densification: Densification::None,
+        center: false,
+        region: false,
+        children: false,
+        neighbors: false,
+        vertex_count: false,
+        preferred_id_format: ZoneIdFormat::Text,
+        ..Default::default()
+    };
+
+    let parent_level = RefinementLevel::new(2).unwrap();
+    let parent = adapter
+        .zone_from_point(parent_level, point, Some(config))
+        .unwrap()
+        .zones
+        .first()
+        .map(|zone| zone.id.clone())
+        .unwrap();
+
+    for rd in 1..=2 {
+        let relative_depth = RelativeDepth::new(rd).unwrap();
+        let sub_zones = adapter
+            .sub_zone_ids(relative_depth, parent.clone())
+            .unwrap();
+
+        assert!(!sub_zones.is_empty(), "Parent zone has no sub-zones");
+
+        for (index, sub_zone) in sub_zones.iter().enumerate() {
+            let from_index = adapter
+                .sub_zone_from_index(relative_depth, parent.clone(), index as u64)
+                .unwrap();
+            assert_eq!(&from_index, sub_zone);
+
+            let sub_zone_index = adapter
+                .sub_zone_index(parent.clone(), sub_zone.clone())
+                .unwrap();
+            assert_eq!(sub_zone_index, index as u64);
+        }
+
+        assert!(
+            adapter
+                .sub_zone_from_index(relative_depth, parent.clone(), sub_zones.len() as u64)
+                .is_err(),
+            "Index past the last sub-zone must be rejected"
+        );
+    }
+}
+
+#[test]
+fn h3_sub_zone_index_round_trip() {
+    let adapter = H3Impl::default();
+    test_sub_zone_index_round_trip(&adapter);
+}
+
+#[test]
+fn dggal_isea3h_sub_zone_index_round_trip() {
+    let adapter = DggalImpl::new(DggrsUid::ISEA3HDGGAL);
+    test_sub_zone_index_round_trip(&adapter);
+}
+
+#[test]
+fn dggal_ivea7h_sub_zone_index_round_trip() {
+    let adapter = DggalImpl::new(DggrsUid::IVEA7H);
+    test_sub_zone_index_round_trip(&adapter);
+}
+
+#[test]
+fn dggal_isea9r_sub_zone_index_round_trip() {
+    let adapter = DggalImpl::new(DggrsUid::ISEA9R);
+    test_sub_zone_index_round_trip(&adapter);
+}
+
+#[test]
+fn igeo7_sub_zone_index_round_trip() {
+    let adapter = Igeo7Impl::default();
+    test_sub_zone_index_round_trip(&adapter);
+}
+
+#[test]
+fn isea3h_sub_zone_index_round_trip() {
+    let adapter = Isea3hImpl::default();
+    test_sub_zone_index_round_trip(&adapter);
+}
+
+/// Verify that the sub-zones of an IGEO7 pentagon skip the deleted digit and are still indexed consecutively
+#[test]
+fn igeo7_pentagon_sub_zones_skip_the_deleted_digit() {
+    let adapter = Igeo7Impl::default();
+    let pentagon = ZoneId::new_hex("0fffffffffffffff").unwrap();
+    let relative_depth = RelativeDepth::new(2).unwrap();
+    let sub_zones = adapter
+        .sub_zone_ids(relative_depth, pentagon.clone())
+        .unwrap();
+
+    // NOTE: one pentagon with 6 sub-zones and five hexagons with 7 sub-zones each
+    assert_eq!(sub_zones.len(), 6 + 5 * 7);
+    assert_eq!(sub_zones[0], ZoneId::new_hex("003fffffffffffff").unwrap());
+    assert_eq!(sub_zones[1], ZoneId::new_hex("00bfffffffffffff").unwrap());
+    assert_eq!(sub_zones[6], ZoneId::new_hex("043fffffffffffff").unwrap());
+    for (index, sub_zone) in sub_zones.iter().enumerate() {
+        assert!(adapter.is_valid_zone(sub_zone));
+        assert_eq!(
+            adapter
+                .sub_zone_from_index(relative_depth, pentagon.clone(), index as u64)
+                .unwrap(),
+            *sub_zone
+        );
+        assert_eq!(
+            adapter
+                .sub_zone_index(pentagon.clone(), sub_zone.clone())
+                .unwrap(),
+            index as u64
+        );
+    }
+
+    let hexagon = ZoneId::new_hex("05ffffffffffffff").unwrap();
+    assert!(
+        adapter
+            .sub_zone_index(hexagon, sub_zones[0].clone())
+            .is_err()
+    );
+}
+
+/// Verify that the sub-zones of a pentagon are all Z3 digits, these are the primary-parent sub-zones and not those of `zones_from_parent`
+#[test]
+fn isea3h_sub_zones_follow_the_z3_digits() {
+    let adapter = Isea3hImpl::default();
+    let parent = ZoneId::new_hex("1fffffffffffffff").unwrap();
+    let sub_zones = adapter
+        .sub_zone_ids(RelativeDepth::new(2).unwrap(), parent.clone())
+        .unwrap();
+    assert_eq!(sub_zones.len(), 9);
+    assert_eq!(sub_zones[5], ZoneId::new_hex("16ffffffffffffff").unwrap());
+    assert_eq!(
+        adapter
+            .sub_zone_index(parent, sub_zones[5].clone())
+            .unwrap(),
+        5
+    );
+}
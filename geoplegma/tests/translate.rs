@@ -0,0 +1,85 @@
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::Densification;
+use geoplegma::translate::{TranslationMethod, translate};
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, ZoneId};
+
+fn source_zone(source: &dyn DggrsApi, level: i32) -> ZoneId {
+    let config = DggrsApiConfig {
+        region: false,
+        area_sqm: false,
+        densification: Densification::None,
+        ..Default::default()
+    };
+    source
+        .zone_from_point(
+            RefinementLevel::new(level).unwrap(),
+            Point::new(52.98, 9.06),
+            Some(config),
+        )
+        .unwrap()
+        .zones
+        .first()
+        .unwrap()
+        .id
+        .clone()
+}
+
+/// Verify the weights of the translation methods between two DGGRS
+fn test_translate(source_id: DggrsUid, source_level: i32, target_id: DggrsUid, target_level: i32) {
+    let source = geoplegma::get(source_id).unwrap();
+    let target = geoplegma::get(target_id).unwrap();
+    let zones = vec![source_zone(source.as_ref(), source_level)];
+    let target_level = RefinementLevel::new(target_level).unwrap();
+
+    let centroid = translate(
+        source.as_ref(),
+        target.as_ref(),
+        &zones,
+        target_level,
+        TranslationMethod::Centroid,
+    )
+    .unwrap();
+    assert_eq!(centroid.len(), 1);
+    assert_eq!(centroid[0].weight, 1.0);
+
+    let area_weighted = translate(
+        source.as_ref(),
+        target.as_ref(),
+        &zones,
+        target_level,
+        TranslationMethod::AreaWeighted,
+    )
+    .unwrap();
+    assert!(area_weighted.len() > 1);
+    let total: f64 = area_weighted.iter().map(|t| t.weight).sum();
+    assert!((total - 1.0).abs() < 0.01, "Weights add up to {total}");
+
+    // the centroid target zone overlaps the source zone
+    assert!(area_weighted.iter().any(|t| t.target == centroid[0].target));
+
+    let coverage = translate(
+        source.as_ref(),
+        target.as_ref(),
+        &zones,
+        target_level,
+        TranslationMethod::Coverage,
+    )
+    .unwrap();
+    assert_eq!(coverage.len(), area_weighted.len());
+    assert!(coverage.iter().all(|t| t.weight > 0.0 && t.weight <= 1.0));
+}
+
+#[test]
+fn translate_h3_to_h3() {
+    test_translate(DggrsUid::H3, 4, DggrsUid::H3, 6);
+}
+
+#[test]
+fn translate_igeo7_to_h3() {
+    test_translate(DggrsUid::IGEO7, 4, DggrsUid::H3, 6);
+}
+
+#[test]
+fn translate_h3_to_dggal() {
+    test_translate(DggrsUid::H3, 4, DggrsUid::ISEA3HDGGAL, 9);
+}
@@ -0,0 +1,101 @@
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
+use geoplegma::adapters::dggrid::isea3h::Isea3hImpl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::Densification;
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, ZoneId};
+
+/// Verify that generated zones validate with the refinement level they were generated at
+fn test_generated_zones_are_valid<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(52.98, 9.06);
+    let config = DggrsApiConfig {
+        region: false,
+        center: false,
+        area_sqm: false,
+        densification: Densification::None,
+        ..Default::default()
+    };
+
+    for rf in 1..6 {
+        let level = RefinementLevel::new(rf).unwrap();
+        let zone = adapter
+            .zone_from_point(level, point, Some(config))
+            .unwrap()
+            .zones
+            .first()
+            .unwrap()
+            .clone();
+
+        let info = adapter.validate_zone(&zone.id).unwrap();
+        assert_eq!(info.refinement_level, level);
+        assert_eq!(info.id, zone.id);
+
+        for id in zone.children.iter().chain(zone.neighbors.iter()).flatten() {
+            assert!(adapter.is_valid_zone(id), "Invalid zone {id}");
+        }
+    }
+}
+
+#[test]
+fn h3_generated_zones_are_valid() {
+    let adapter = H3Impl::default();
+    test_generated_zones_are_valid(&adapter);
+}
+
+#[test]
+fn dggal_generated_zones_are_valid() {
+    let adapter = DggalImpl::new(DggrsUid::ISEA3HDGGAL);
+    test_generated_zones_are_valid(&adapter);
+}
+
+#[test]
+fn igeo7_generated_zones_are_valid() {
+    let adapter = Igeo7Impl::default();
+    test_generated_zones_are_valid(&adapter);
+}
+
+#[test]
+fn isea3h_generated_zones_are_valid() {
+    let adapter = Isea3hImpl::default();
+    test_generated_zones_are_valid(&adapter);
+}
+
+#[test]
+fn h3_rejects_deleted_pentagon_sub_sequence() {
+    let adapter = H3Impl::default();
+
+    // base cell 4 is a pentagon, its resolution 1 child with digit 1 does not exist
+    assert!(!adapter.is_valid_zone(&ZoneId::new_hex("81087ffffffffff").unwrap()));
+    assert!(adapter.is_valid_zone(&ZoneId::new_hex("8108bffffffffff").unwrap()));
+    assert!(!adapter.is_valid_zone(&ZoneId::new_int(123)));
+}
+
+#[test]
+fn igeo7_rejects_invalid_z7() {
+    let adapter = Igeo7Impl::default();
+
+    let valid = ZoneId::new_hex("80ffffffffffffff").unwrap();
+    assert_eq!(
+        adapter.validate_zone(&valid).unwrap().refinement_level,
+        RefinementLevel::new(2).unwrap()
+    );
+
+    // digit after a padding digit
+    assert!(!adapter.is_valid_zone(&ZoneId::new_hex("80fbffffffffffff").unwrap()));
+    // the sub-zone with digit 1 of a pentagon does not exist, at any refinement level below the base cell
+    assert!(!adapter.is_valid_zone(&ZoneId::new_hex("83ffffffffffffff").unwrap()));
+    assert!(!adapter.is_valid_zone(&ZoneId::new_hex("807fffffffffffff").unwrap()));
+    assert!(adapter.is_valid_zone(&ZoneId::new_hex("85ffffffffffffff").unwrap()));
+    assert!(adapter.is_valid_zone(&ZoneId::new_hex("847fffffffffffff").unwrap()));
+    // base cell 12 does not exist
+    assert!(!adapter.is_valid_zone(&ZoneId::new_hex("c0ffffffffffffff").unwrap()));
+    // not hexadecimal
+    assert!(!adapter.is_valid_zone(&ZoneId::new_str("zz").unwrap()));
+}
+
+#[test]
+fn dggal_rejects_unknown_zone() {
+    let adapter = DggalImpl::new(DggrsUid::IVEA3H);
+    assert!(!adapter.is_valid_zone(&ZoneId::new_str("NOT-A-ZONE").unwrap()));
+}
@@ -0,0 +1,220 @@
+mod common;
+
+use common::{level, test_all, zone_at};
+use geo::{
+    Geometry, GeometryCollection, LineString, MultiPoint, Polygon, coord, line_string, point,
+    polygon,
+};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::structures::StructureError;
+use geoplegma::structures::vector::{AttributeValue, DggsFeature, DggsGeometry, DggsVector};
+use geoplegma::types::{DggrsUid, Point, ZoneId};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+fn id(s: &str) -> ZoneId {
+    ZoneId::new_str(s).unwrap()
+}
+
+#[test]
+fn dedup_removes_consecutive_zones() {
+    let line = DggsGeometry::LineString(vec![id("a"), id("a"), id("b"), id("b"), id("a")]);
+    assert_eq!(
+        line.dedup(),
+        DggsGeometry::LineString(vec![id("a"), id("b"), id("a")])
+    );
+}
+
+#[test]
+fn dedup_collapses_degenerate_geometries() {
+    let line = DggsGeometry::LineString(vec![id("a"), id("a")]);
+    assert_eq!(line.dedup(), DggsGeometry::Point(id("a")));
+
+    let polygon = DggsGeometry::Polygon {
+        exterior: vec![id("a"), id("b"), id("b"), id("a")],
+        interiors: vec![],
+    };
+    assert_eq!(
+        polygon.dedup(),
+        DggsGeometry::LineString(vec![id("a"), id("b")])
+    );
+
+    let polygon = DggsGeometry::Polygon {
+        exterior: vec![id("a"), id("b"), id("c"), id("a")],
+        interiors: vec![vec![id("d"), id("d"), id("d")]],
+    };
+    assert_eq!(
+        polygon.dedup(),
+        DggsGeometry::Polygon {
+            exterior: vec![id("a"), id("b"), id("c"), id("a")],
+            interiors: vec![],
+        }
+    );
+}
+
+#[test]
+fn features_carry_attributes() {
+    let feature = DggsFeature::new(DggsGeometry::Point(id("a")))
+        .with_attribute("name", "station")
+        .with_attribute("count", 3i64)
+        .with_attribute("mean", 1.5)
+        .with_attribute("active", true);
+    assert_eq!(
+        feature.attributes["name"],
+        AttributeValue::Text("station".to_string())
+    );
+    assert_eq!(feature.attributes["count"], AttributeValue::Int(3));
+    assert_eq!(feature.attributes["mean"], AttributeValue::Float(1.5));
+    assert_eq!(feature.attributes["active"], AttributeValue::Bool(true));
+}
+
+/// Verify that geometries are quantised into zones and converted back via the zone centroids
+fn test_quantise(dggrs: Arc<dyn DggrsApi>) {
+    let mut vector = DggsVector::new(dggrs.clone(), level(5)).unwrap();
+    let line: Geometry<f64> =
+        line_string![(x: 10.0, y: 45.0), (x: 10.001, y: 45.001), (x: 12.0, y: 46.0)].into();
+    let mut attributes = BTreeMap::new();
+    attributes.insert("name".to_string(), AttributeValue::from("road"));
+    assert_eq!(vector.push_geo(&line, attributes).unwrap(), 1);
+
+    let DggsGeometry::LineString(zone_ids) = &vector.features()[0].geometry else {
+        panic!("a line is quantised as a line string");
+    };
+    assert_eq!(zone_ids.len(), 3);
+    assert_eq!(zone_ids[0], zone_ids[1]);
+    let expected = dggrs
+        .zone_from_point(level(5), Point::new(46.0, 12.0), None)
+        .unwrap()
+        .zones[0]
+        .id
+        .clone();
+    assert_eq!(zone_ids[2], expected);
+
+    vector.dedup();
+    let Geometry::LineString(snapped) = vector.to_geo(&vector.features()[0].geometry).unwrap()
+    else {
+        panic!("a line string converts to a line string");
+    };
+    assert_eq!(snapped.0.len(), 2);
+    assert!((snapped.0[0].x - 10.0).abs() < 1.0 && (snapped.0[0].y - 45.0).abs() < 1.0);
+}
+
+/// Verify that multi-part geometries are added as one feature per part and polygons keep their rings
+fn test_multi_part(dggrs: Arc<dyn DggrsApi>) {
+    let mut vector = DggsVector::new(dggrs, level(3)).unwrap();
+    let points: Geometry<f64> = MultiPoint::from(vec![(10.0, 45.0), (-70.0, -30.0)]).into();
+    assert_eq!(vector.push_geo(&points, BTreeMap::new()).unwrap(), 2);
+
+    let polygon: Geometry<f64> = polygon![
+        (x: 0.0, y: 0.0), (x: 20.0, y: 0.0), (x: 20.0, y: 20.0), (x: 0.0, y: 20.0)
+    ]
+    .into();
+    vector.push_geo(&polygon, BTreeMap::new()).unwrap();
+    let DggsGeometry::Polygon { exterior, .. } = &vector.features()[2].geometry else {
+        panic!("a polygon is quantised as a polygon");
+    };
+    assert_eq!(exterior.first(), exterior.last());
+    assert_eq!(vector.len(), 3);
+
+    // NOTE: the coordinates of all parts are located at once and handed back part by part
+    let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+        point!(x: 10.0, y: 45.0).into(),
+        Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (20.0, 0.0), (10.0, 20.0), (0.0, 0.0)]),
+            vec![LineString::from(vec![
+                (8.0, 4.0),
+                (12.0, 4.0),
+                (10.0, 8.0),
+                (8.0, 4.0),
+            ])],
+        )
+        .into(),
+        line_string![(x: -70.0, y: -30.0), (x: -60.0, y: -25.0)].into(),
+    ]));
+    let quantised = vector.quantise(&collection).unwrap();
+    let at = |x: f64, y: f64| zone_at(vector.dggrs(), 3, Point::new(y, x));
+    assert_eq!(quantised.len(), 3);
+    assert_eq!(quantised[0], DggsGeometry::Point(at(10.0, 45.0)));
+    let DggsGeometry::Polygon {
+        exterior,
+        interiors,
+    } = &quantised[1]
+    else {
+        panic!("a polygon is quantised as a polygon");
+    };
+    assert_eq!(exterior.len(), 4);
+    assert_eq!(exterior[2], at(10.0, 20.0));
+    assert_eq!(interiors.len(), 1);
+    assert_eq!(interiors[0][1], at(12.0, 4.0));
+    assert_eq!(
+        quantised[2],
+        DggsGeometry::LineString(vec![at(-70.0, -30.0), at(-60.0, -25.0)])
+    );
+}
+
+/// Verify that snapped geometries lie on zone centroids
+fn test_snap(dggrs: Arc<dyn DggrsApi>) {
+    let vector = DggsVector::new(dggrs.clone(), level(4)).unwrap();
+    let point: Geometry<f64> = point!(x: 10.0, y: 45.0).into();
+    let snapped = vector.snap(&point).unwrap();
+    let Geometry::Point(snapped) = snapped[0] else {
+        panic!("a point snaps to a point");
+    };
+    let zone = dggrs
+        .zone_from_point(level(4), Point::new(45.0, 10.0), None)
+        .unwrap()
+        .zones
+        .remove(0);
+    let center = zone.center.unwrap();
+    assert!((snapped.x() - center.lon).abs() < 1e-9);
+    assert!((snapped.y() - center.lat).abs() < 1e-9);
+
+    let short: Geometry<f64> = LineString::new(vec![
+        coord! { x: 10.0, y: 45.0 },
+        coord! { x: 10.0001, y: 45.0001 },
+    ])
+    .into();
+    assert!(matches!(
+        vector.snap(&short).unwrap()[0],
+        Geometry::Point(_)
+    ));
+}
+
+/// Verify that features with zones at another refinement level are rejected
+fn test_level_mismatch(dggrs: Arc<dyn DggrsApi>) {
+    let mut vector = DggsVector::new(dggrs.clone(), level(3)).unwrap();
+    let zone_id = dggrs
+        .zone_from_point(level(2), Point::new(45.0, 10.0), None)
+        .unwrap()
+        .zones
+        .remove(0)
+        .id;
+    assert!(matches!(
+        vector.push(DggsFeature::new(DggsGeometry::Point(zone_id))),
+        Err(DggrsError::Structure(StructureError::LevelMismatch { .. }))
+    ));
+    assert!(vector.is_empty());
+}
+
+const TESTS: &[fn(Arc<dyn DggrsApi>)] = &[
+    test_quantise,
+    test_multi_part,
+    test_snap,
+    test_level_mismatch,
+];
+
+#[test]
+fn h3_vector() {
+    test_all(DggrsUid::H3, TESTS);
+}
+
+#[test]
+fn igeo7_vector() {
+    test_all(DggrsUid::IGEO7, TESTS);
+}
+
+#[test]
+fn isea3h_dggal_vector() {
+    test_all(DggrsUid::ISEA3HDGGAL, TESTS);
+}
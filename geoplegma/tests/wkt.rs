@@ -0,0 +1,111 @@
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::encoding::csv;
+use geoplegma::encoding::wkb::{SRID_WGS84, zone_to_ewkb, zone_to_wkb};
+use geoplegma::encoding::wkt::{ZoneGeometry, point_to_wkt, region_to_wkt, zone_to_wkt};
+use geoplegma::types::{BoundingBox, Point, RefinementLevel, Region, Zones};
+
+fn zones<T: DggrsApi>(adapter: &T) -> Zones {
+    adapter
+        .zones_from_bbox(
+            RefinementLevel::new(4).unwrap(),
+            Some(BoundingBox::new(8.0, 52.0, 9.0, 53.0)),
+            Some(DggrsApiConfig::default()),
+        )
+        .unwrap()
+}
+
+fn f64_at(bytes: &[u8], offset: usize) -> f64 {
+    f64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+/// Verify that the WKT, WKB and EWKB of a zone carry the coordinates of its region and centroid
+fn test_zone_geometries<T: DggrsApi>(adapter: &T) {
+    let zones = zones(adapter);
+    let zone = zones.zones.first().unwrap();
+    let region = zone.region.as_ref().unwrap();
+    let first = region.polygons()[0].exterior[0];
+
+    let wkt = zone_to_wkt(zone, ZoneGeometry::Region).unwrap();
+    assert!(wkt.starts_with(&format!("POLYGON (({} {}, ", first.lon, first.lat)));
+    assert_eq!(wkt.matches(',').count(), region.polygons()[0].exterior.len() - 1);
+
+    let wkb = zone_to_wkb(zone, ZoneGeometry::Region).unwrap();
+    assert_eq!(wkb.len(), 1 + 4 + 4 + 4 + region.polygons()[0].exterior.len() * 16);
+    assert_eq!(wkb[0], 1);
+    assert_eq!(u32_at(&wkb, 1), 3);
+    assert_eq!(u32_at(&wkb, 5), 1);
+    assert_eq!(u32_at(&wkb, 9) as usize, region.polygons()[0].exterior.len());
+    assert_eq!(f64_at(&wkb, 13), first.lon);
+    assert_eq!(f64_at(&wkb, 21), first.lat);
+
+    let ewkb = zone_to_ewkb(zone, ZoneGeometry::Region).unwrap();
+    assert_eq!(ewkb.len(), wkb.len() + 4);
+    assert_eq!(u32_at(&ewkb, 1), 0x2000_0003);
+    assert_eq!(u32_at(&ewkb, 5), SRID_WGS84);
+    assert_eq!(&ewkb[9..], &wkb[5..]);
+
+    let center = zone.center.unwrap();
+    assert_eq!(
+        zone_to_wkt(zone, ZoneGeometry::Center).unwrap(),
+        point_to_wkt(&center)
+    );
+    let wkb = zone_to_ewkb(zone, ZoneGeometry::Center).unwrap();
+    assert_eq!(wkb.len(), 25);
+    assert_eq!(u32_at(&wkb, 1), 0x2000_0001);
+    assert_eq!(f64_at(&wkb, 9), center.lon);
+    assert_eq!(f64_at(&wkb, 17), center.lat);
+}
+
+/// Verify that the CSV export has a header and one quoted WKT line per zone
+fn test_csv<T: DggrsApi>(adapter: &T) {
+    let zones = zones(adapter);
+    let mut buffer = Vec::new();
+    csv::write_zones(&mut buffer, &zones, adapter, ZoneGeometry::Region).unwrap();
+
+    let text = String::from_utf8(buffer).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], csv::CSV_HEADER);
+    assert_eq!(lines.len(), zones.zones.len() + 1);
+
+    let zone = &zones.zones[0];
+    let expected = format!(
+        "{},4,\"{}\",{}",
+        zone.id,
+        zone_to_wkt(zone, ZoneGeometry::Region).unwrap(),
+        zone.area_sqm.unwrap()
+    );
+    assert_eq!(lines[1], expected);
+}
+
+#[test]
+fn h3_zone_geometries() {
+    let adapter = H3Impl::default();
+    test_zone_geometries(&adapter);
+}
+
+#[test]
+fn h3_csv() {
+    let adapter = H3Impl::default();
+    test_csv(&adapter);
+}
+
+#[test]
+fn wkt_of_points_and_regions() {
+    assert_eq!(point_to_wkt(&Point::new(52.5, 9.25)), "POINT (9.25 52.5)");
+
+    let region = Region::new(vec![
+        Point::new(52.0, 9.0),
+        Point::new(52.0, 10.0),
+        Point::new(53.0, 10.0),
+    ]);
+    assert_eq!(
+        region_to_wkt(&region),
+        "POLYGON ((9 52, 10 52, 10 53, 9 52))"
+    );
+    assert_eq!(region_to_wkt(&Region::default()), "POLYGON EMPTY");
+}
@@ -0,0 +1,198 @@
+#![cfg(feature = "json")]
+
+mod common;
+
+use common::{dggrs, level, store_dir};
+use geoplegma::abstraction::zarr::{ZarrCoverageStore, ZoneOrdering};
+use geoplegma::api::DggrsApi;
+use geoplegma::error::DggrsError;
+use geoplegma::error::structures::StructureError;
+use geoplegma::structures::coverage::{Coverage, CoverageStorage};
+use geoplegma::types::{BoundingBox, DggrsUid, Point, ZoneIdFormat};
+use std::sync::Arc;
+
+fn sparse_coverage(dggrs: &Arc<dyn DggrsApi>, format: ZoneIdFormat) -> Coverage<f64> {
+    let mut coverage = Coverage::sparse(dggrs.clone(), level(3))
+        .unwrap()
+        .with_blocks(level(1))
+        .unwrap();
+    for (i, (lat, lon)) in [(45.0, 10.0), (45.5, 10.5), (-30.0, 150.0), (60.0, -100.0)]
+        .into_iter()
+        .enumerate()
+    {
+        let zone_id = dggrs
+            .zone_from_point(level(3), Point::new(lat, lon), None)
+            .unwrap()
+            .zones
+            .remove(0)
+            .id;
+        let zone_id = dggrs.format_zone_id(&zone_id, format).unwrap();
+        coverage.set(zone_id, i as f64 * 0.5).unwrap();
+    }
+    coverage
+}
+
+/// Verify that a sparse coverage is written with one row per parent zone and read back
+fn test_sparse_round_trip(dggrs: Arc<dyn DggrsApi>, format: ZoneIdFormat, name: &str) {
+    let coverage = sparse_coverage(&dggrs, format);
+    let dir = store_dir(name);
+    ZarrCoverageStore::create(&dir)
+        .unwrap()
+        .write(&coverage, f64::NAN)
+        .unwrap();
+
+    let store = ZarrCoverageStore::open(&dir);
+    let attributes = store.attributes().unwrap();
+    assert_eq!(attributes.dggrs, dggrs.uid());
+    assert_eq!(attributes.refinement_level, level(3));
+    assert_eq!(attributes.parent_level, level(1));
+    assert_eq!(attributes.zone_ordering, ZoneOrdering::SubZoneIds);
+    assert_eq!(attributes.zone_id_format, format);
+
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(dir.join("values/zarr.json")).unwrap()).unwrap();
+    assert_eq!(metadata["zarr_format"], 3);
+    assert_eq!(metadata["data_type"], "float64");
+    assert_eq!(metadata["fill_value"], "NaN");
+    let blocks = coverage.blocks().count() as u64;
+    assert_eq!(metadata["shape"][0], blocks);
+    let columns = metadata["shape"][1].as_u64().unwrap();
+    assert_eq!(
+        metadata["chunk_grid"]["configuration"]["chunk_shape"],
+        serde_json::json!([1, columns])
+    );
+    assert_eq!(
+        std::fs::metadata(dir.join("values/c/0/0")).unwrap().len(),
+        columns * 8
+    );
+
+    let back: Coverage<f64> = store.read().unwrap();
+    assert_eq!(back.storage(), CoverageStorage::Sparse);
+    assert_eq!(back.block_level(), Some(level(1)));
+    assert_eq!(
+        back.iter().collect::<Vec<_>>(),
+        coverage.iter().collect::<Vec<_>>()
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// Verify that a dense coverage keeps its extent and that values equal to the fill value are left unset
+fn test_dense_round_trip(dggrs: Arc<dyn DggrsApi>, name: &str) {
+    let extent = BoundingBox::new(5.0, 40.0, 15.0, 50.0);
+    let mut coverage: Coverage<u32> = Coverage::dense(dggrs.clone(), level(3), Some(extent))
+        .unwrap()
+        .with_blocks(level(2))
+        .unwrap();
+    let ids: Vec<_> = coverage.zone_ids().step_by(2).cloned().collect();
+    for (i, id) in ids.into_iter().enumerate() {
+        coverage.set(id, i as u32 + 1).unwrap();
+    }
+
+    let dir = store_dir(name);
+    ZarrCoverageStore::create(&dir)
+        .unwrap()
+        .write(&coverage, 0)
+        .unwrap();
+    let back: Coverage<u32> = ZarrCoverageStore::open(&dir).read().unwrap();
+    assert_eq!(back.storage(), CoverageStorage::Dense);
+    assert_eq!(back.extent(), Some(extent));
+    assert_eq!(
+        back.iter().collect::<Vec<_>>(),
+        coverage.iter().collect::<Vec<_>>()
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn h3_sparse_round_trip() {
+    test_sparse_round_trip(dggrs(DggrsUid::H3), ZoneIdFormat::Text, "h3-sparse");
+}
+
+#[test]
+fn h3_sparse_int_ids_round_trip() {
+    test_sparse_round_trip(dggrs(DggrsUid::H3), ZoneIdFormat::Int, "h3-sparse-int");
+}
+
+#[test]
+fn h3_dense_round_trip() {
+    test_dense_round_trip(dggrs(DggrsUid::H3), "h3-dense");
+}
+
+#[test]
+fn isea3h_dggal_sparse_round_trip() {
+    test_sparse_round_trip(
+        dggrs(DggrsUid::ISEA3HDGGAL),
+        ZoneIdFormat::Text,
+        "isea3h-dggal-sparse",
+    );
+}
+
+#[test]
+fn igeo7_sparse_round_trip() {
+    test_sparse_round_trip(dggrs(DggrsUid::IGEO7), ZoneIdFormat::Text, "igeo7-sparse");
+}
+
+/// Verify that writing to an existing store leaves no rows of the coverage written before
+#[test]
+fn rewritten_store_has_no_stale_rows() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let dir = store_dir("h3-rewritten");
+    let mut store = ZarrCoverageStore::create(&dir).unwrap();
+    store
+        .write(&sparse_coverage(&dggrs, ZoneIdFormat::Text), f64::NAN)
+        .unwrap();
+
+    let mut coverage = Coverage::sparse(dggrs.clone(), level(3))
+        .unwrap()
+        .with_blocks(level(1))
+        .unwrap();
+    let zone_id = dggrs
+        .zone_from_point(level(3), Point::new(60.0, -100.0), None)
+        .unwrap()
+        .zones
+        .remove(0)
+        .id;
+    coverage.set(zone_id, 7.0).unwrap();
+    ZarrCoverageStore::open(&dir)
+        .write(&coverage, f64::NAN)
+        .unwrap();
+
+    assert!(!dir.join("values/c/1").exists());
+    let back: Coverage<f64> = ZarrCoverageStore::open(&dir).read().unwrap();
+    assert_eq!(
+        back.iter().collect::<Vec<_>>(),
+        coverage.iter().collect::<Vec<_>>()
+    );
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn unpartitioned_coverage_is_rejected() {
+    let dggrs = dggrs(DggrsUid::H3);
+    let coverage: Coverage<f64> = Coverage::sparse(dggrs, level(3)).unwrap();
+    let dir = store_dir("unpartitioned");
+    assert!(matches!(
+        ZarrCoverageStore::create(&dir)
+            .unwrap()
+            .write(&coverage, 0.0),
+        Err(DggrsError::Structure(StructureError::NotPartitioned))
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn mismatching_data_type_is_rejected() {
+    let h3 = dggrs(DggrsUid::H3);
+    let dir = store_dir("mismatch");
+    ZarrCoverageStore::create(&dir)
+        .unwrap()
+        .write(&sparse_coverage(&h3, ZoneIdFormat::Text), f64::NAN)
+        .unwrap();
+    assert!(matches!(
+        ZarrCoverageStore::open(&dir).read::<f32>(),
+        Err(DggrsError::Structure(
+            StructureError::DataTypeMismatch { .. }
+        ))
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,85 @@
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::Densification;
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, ZoneId, ZoneIdFormat};
+
+/// Verify that zones requested as integers convert losslessly to textual ZoneIds and back
+fn test_zone_id_format_round_trip<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(52.98, 9.06);
+    let config = DggrsApiConfig {
+        region: false,
+        center: false,
+        area_sqm: false,
+        densification: Densification::None,
+        preferred_id_format: ZoneIdFormat::Int,
+        ..Default::default()
+    };
+
+    for rf in 1..6 {
+        let level = RefinementLevel::new(rf).unwrap();
+        let zone = adapter
+            .zone_from_point(level, point, Some(config))
+            .unwrap()
+            .zones
+            .first()
+            .unwrap()
+            .clone();
+
+        assert!(matches!(zone.id, ZoneId::IntId(_)));
+        for id in zone.children.iter().chain(zone.neighbors.iter()).flatten() {
+            assert!(matches!(id, ZoneId::IntId(_)));
+        }
+
+        let text_id = adapter
+            .format_zone_id(&zone.id, ZoneIdFormat::Text)
+            .unwrap();
+        assert!(!matches!(text_id, ZoneId::IntId(_)));
+
+        let parsed = adapter.parse_zone_id(&text_id.to_string()).unwrap();
+        assert_eq!(parsed, text_id);
+
+        let int_id = adapter.format_zone_id(&parsed, ZoneIdFormat::Int).unwrap();
+        assert_eq!(int_id, zone.id);
+    }
+}
+
+#[test]
+fn h3_zone_id_format_round_trip() {
+    let adapter = H3Impl::default();
+    test_zone_id_format_round_trip(&adapter);
+}
+
+#[test]
+fn dggal_zone_id_format_round_trip() {
+    let adapter = DggalImpl::new(DggrsUid::IVEA3H);
+    test_zone_id_format_round_trip(&adapter);
+}
+
+#[test]
+fn h3_parse_zone_id_is_not_guessed() {
+    let adapter = H3Impl::default();
+
+    // A decimal looking string is still a H3 hexadecimal index
+    assert!(adapter.parse_zone_id("123").is_err());
+    assert_eq!(
+        adapter.parse_zone_id("8a1fb46622dffff").unwrap(),
+        ZoneId::new_hex("8a1fb46622dffff").unwrap()
+    );
+}
+
+#[test]
+fn igeo7_format_zone_id_round_trip() {
+    let adapter = Igeo7Impl::default();
+
+    // base cell 8, digits 0 and 3, padded with 7
+    let text_id = adapter.parse_zone_id("80ffffffffffffff").unwrap();
+    let int_id = adapter.format_zone_id(&text_id, ZoneIdFormat::Int).unwrap();
+
+    assert_eq!(int_id, ZoneId::new_int(0x80ff_ffff_ffff_ffff));
+    assert_eq!(
+        adapter.format_zone_id(&int_id, ZoneIdFormat::Text).unwrap(),
+        text_id
+    );
+}
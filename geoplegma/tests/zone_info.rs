@@ -0,0 +1,108 @@
+use geoplegma::adapters::dggal::grids::DggalImpl;
+use geoplegma::adapters::dggrid::igeo7::Igeo7Impl;
+use geoplegma::adapters::h3o::h3::H3Impl;
+use geoplegma::api::{DggrsApi, DggrsApiConfig};
+use geoplegma::geometry::Densification;
+use geoplegma::types::{DggrsUid, Point, RefinementLevel, ZoneId};
+
+/// Verify that the zone info agrees with the zone and primary parent generated from geometry
+fn test_zone_info_matches_zone<T: DggrsApi>(adapter: &T) {
+    let point = Point::new(52.98, 9.06);
+    let config = DggrsApiConfig {
+        region: false,
+        area_sqm: false,
+        densification: Densification::None,
+        ..Default::default()
+    };
+
+    let level = RefinementLevel::new(3).unwrap();
+    let zone = adapter
+        .zone_from_point(level, point, Some(config))
+        .unwrap()
+        .zones
+        .first()
+        .unwrap()
+        .clone();
+
+    let info = adapter.zone_info(&zone.id).unwrap();
+    assert_eq!(info.refinement_level, level);
+    assert_eq!(info.vertex_count, 6);
+    assert!(!info.is_pentagon);
+    assert!(info.center.is_some());
+
+    let parent = adapter
+        .primary_parent_from_zone(zone.id.clone(), Some(config))
+        .unwrap()
+        .zones
+        .first()
+        .map(|z| z.id.clone());
+    assert_eq!(info.primary_parent, parent);
+
+    let root = adapter.validate_zone(&info.root_zone).unwrap();
+    assert_eq!(root.refinement_level, RefinementLevel::new(0).unwrap());
+    assert_eq!(root.primary_parent, None);
+    assert_eq!(root.root_zone, info.root_zone);
+    assert_eq!(root.center, None);
+}
+
+#[test]
+fn h3_zone_info_matches_zone() {
+    let adapter = H3Impl::default();
+    test_zone_info_matches_zone(&adapter);
+}
+
+#[test]
+fn dggal_zone_info_matches_zone() {
+    let adapter = DggalImpl::new(DggrsUid::IVEA7H);
+    test_zone_info_matches_zone(&adapter);
+}
+
+#[test]
+fn igeo7_zone_info_matches_zone() {
+    let adapter = Igeo7Impl::default();
+    test_zone_info_matches_zone(&adapter);
+}
+
+#[test]
+fn h3_zone_info_pentagon() {
+    let adapter = H3Impl::default();
+
+    // hexagon child of the pentagon base cell 4, digit 2
+    let info = adapter
+        .validate_zone(&ZoneId::new_hex("8108bffffffffff").unwrap())
+        .unwrap();
+    assert!(!info.is_pentagon);
+    assert_eq!(info.vertex_count, 6);
+
+    // centroid child of the pentagon base cell 4
+    let info = adapter
+        .validate_zone(&ZoneId::new_hex("81083ffffffffff").unwrap())
+        .unwrap();
+    assert!(info.is_pentagon);
+    assert_eq!(info.vertex_count, 5);
+    assert_eq!(info.root_zone, ZoneId::new_hex("8009fffffffffff").unwrap());
+}
+
+#[test]
+fn igeo7_zone_info_from_digits() {
+    let adapter = Igeo7Impl::default();
+
+    // base cell 8, digits 0 and 3
+    let info = adapter
+        .validate_zone(&ZoneId::new_int(0x80ff_ffff_ffff_ffff))
+        .unwrap();
+    assert_eq!(info.refinement_level, RefinementLevel::new(2).unwrap());
+    assert_eq!(
+        info.primary_parent,
+        Some(ZoneId::new_int(0x81ff_ffff_ffff_ffff))
+    );
+    assert_eq!(info.root_zone, ZoneId::new_int(0x8fff_ffff_ffff_ffff));
+    assert!(!info.is_pentagon);
+
+    // base cell 8, digits 0 and 0
+    let info = adapter
+        .validate_zone(&ZoneId::new_hex("803fffffffffffff").unwrap())
+        .unwrap();
+    assert!(info.is_pentagon);
+    assert_eq!(info.vertex_count, 5);
+}